@@ -0,0 +1,40 @@
+//! Matrix product benchmarks, run with `cargo bench`.
+//!
+//! `naive` is the column walking product the `*` operator used to compute for all sizes,
+//! it is kept here as a baseline for the blocked kernel used for large matrices.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use matrix::Matrix;
+
+fn naive<const ROWS: usize, const COLS: usize, const Q: usize>(
+    left: &Matrix<f64, ROWS, Q>,
+    right: &Matrix<f64, Q, COLS>,
+) -> Matrix<f64, ROWS, COLS> {
+    Matrix::from_fn(|row, col| (0..Q).map(|k| left[(row, k)] * right[(k, col)]).sum())
+}
+
+fn bench_size<const SIZE: usize>(c: &mut Criterion) {
+    let left = Box::new(Matrix::<f64, SIZE, SIZE>::from_fn(|row, col| {
+        (row * 3 + col) as f64
+    }));
+    let right = Box::new(Matrix::<f64, SIZE, SIZE>::from_fn(|row, col| {
+        (row + col * 7) as f64
+    }));
+    let mut group = c.benchmark_group(format!("mul {}x{}", SIZE, SIZE));
+    group.bench_function("naive", |b| {
+        b.iter(|| naive(black_box(&*left), black_box(&*right)))
+    });
+    group.bench_function("operator", |b| {
+        b.iter(|| black_box(&*left) * black_box(&*right))
+    });
+    group.finish();
+}
+
+fn bench_mul(c: &mut Criterion) {
+    bench_size::<16>(c);
+    bench_size::<128>(c);
+    bench_size::<256>(c);
+}
+
+criterion_group!(benches, bench_mul);
+criterion_main!(benches);
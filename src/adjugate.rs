@@ -0,0 +1,113 @@
+//! Cofactors, adjugate and Cramer's rule, computed without dividing coefficients so that they are exact for integers.
+
+use crate::{Error, Matrix};
+use core::ops::Neg;
+use num::traits::Num;
+
+impl<C, const SIZE: usize> Matrix<C, SIZE, SIZE>
+where
+    C: Num + Neg<Output = C> + Clone,
+{
+    ///Returns the matrix of cofactors, the cofactor `(i, j)` being `(-1)ⁱ⁺ʲ` times the determinant of the matrix without row `i` and column `j`.
+    ///
+    ///Determinants are computed by the fraction-free Bareiss algorithm, whose divisions are exact,
+    ///so the cofactors of integer matrices are exact. This takes `O(SIZE⁵)` operations, which is only suited to small matrices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let a = Matrix::from([[1, 2, 3], [0, 4, 5], [1, 0, 6]]);
+    /// assert_eq!(a.cofactor_matrix(), Matrix::from([[24, 5, -4], [-12, 3, 2], [-2, -5, 4]]));
+    /// ```
+    pub fn cofactor_matrix(&self) -> Self {
+        //the cofactor (i, j) is the determinant of the matrix whose row i is replaced by the unit row j
+        Matrix::from_fn(|i, j| {
+            let mut a = self.data.clone();
+            a[i] = core::array::from_fn(|col| if col == j { C::one() } else { C::zero() });
+            fraction_free_determinant(a)
+        })
+    }
+
+    ///Returns the adjugate of the matrix, that is the transpose of its cofactor matrix.
+    ///
+    ///The adjugate satisfies `A * adj(A) = det(A) * I`, so it is the inverse up to the determinant even for singular matrices.
+    ///See [`cofactor_matrix`] for how it is computed.
+    ///
+    /// [`cofactor_matrix`]: #method.cofactor_matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let a = Matrix::from([[2, 1], [7, 4]]);
+    /// assert_eq!(a.adjugate(), Matrix::from([[4, -1], [-7, 2]]));
+    /// assert_eq!(&a * &a.adjugate(), Matrix::identity());
+    /// ```
+    pub fn adjugate(&self) -> Self {
+        self.cofactor_matrix().transpose()
+    }
+
+    ///Solves the linear system `self * x = b` through Cramer's rule.
+    ///
+    ///Each unknown is the determinant of the matrix whose column is replaced by `b`, divided by the determinant of the matrix.
+    ///Determinants are computed without division, so the result is exact for rational coefficients
+    ///and only one division is rounded per unknown for integers. Returns Singular if the determinant is nil.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let a = Matrix::from([[2, 1, 1], [1, 3, 2], [1, 0, 0]]);
+    /// let x = a.cramer_solve(Matrix::from([[4], [5], [6]])).unwrap();
+    /// assert_eq!(x, Matrix::from([[6], [15], [-23]]));
+    /// ```
+    pub fn cramer_solve(&self, b: Matrix<C, SIZE, 1>) -> Result<Matrix<C, SIZE, 1>, Error> {
+        let determinant = fraction_free_determinant(self.data.clone());
+        if determinant.is_zero() {
+            return Err(Error::Singular);
+        }
+        Ok(Matrix::from_fn(|i, _| {
+            let mut a = self.data.clone();
+            for (line, b) in a.iter_mut().zip(b.data.iter()) {
+                line[i] = b[0].clone();
+            }
+            fraction_free_determinant(a) / determinant.clone()
+        }))
+    }
+}
+
+//Returns the determinant of `a` through the Bareiss algorithm, whose divisions are all exact in an integral domain.
+fn fraction_free_determinant<C, const SIZE: usize>(mut a: [[C; SIZE]; SIZE]) -> C
+where
+    C: Num + Neg<Output = C> + Clone,
+{
+    let mut negated = false;
+    let mut previous = C::one();
+    for k in 0..SIZE {
+        let pivot = match (k..SIZE).find(|&row| !a[row][k].is_zero()) {
+            Some(pivot) => pivot,
+            None => return C::zero(),
+        };
+        if pivot != k {
+            a.swap(pivot, k);
+            negated = !negated;
+        }
+        let (top, bottom) = a.split_at_mut(k + 1);
+        let pivot_line = &top[k];
+        for line in bottom.iter_mut() {
+            for col in k + 1..SIZE {
+                line[col] = (line[col].clone() * pivot_line[k].clone()
+                    - line[k].clone() * pivot_line[col].clone())
+                    / previous.clone();
+            }
+        }
+        previous = pivot_line[k].clone();
+    }
+    //`previous` is the last pivot, which is the determinant
+    if negated {
+        -previous
+    } else {
+        previous
+    }
+}
@@ -0,0 +1,77 @@
+//! Zero-copy deserialization through `rkyv`, enabled by the `rkyv` feature.
+//!
+//! `Archive`, `Serialize` and `Deserialize` are derived on [`Matrix`], this adds the accessors of [`ArchivedMatrix`]
+//! so that coefficients can be read in place, without deserializing the whole matrix.
+
+use crate::{ArchivedMatrix, Matrix};
+use rkyv::{Archive, Archived};
+use std::fmt;
+use std::ops::Index;
+
+impl<C, const ROWS: usize, const COLS: usize> ArchivedMatrix<C, ROWS, COLS>
+where
+    C: Archive,
+{
+    ///Returns a reference to a single archived coefficient or `None` if either `row` or `col` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let bytes = rkyv::to_bytes::<_, 256>(&Matrix::from([[1.0, 2.0], [3.0, 4.0]])).unwrap();
+    /// let archived = rkyv::check_archived_root::<Matrix<f64, 2, 2>>(&bytes).unwrap();
+    /// assert_eq!(archived.get(1, 0), Some(&3.0));
+    /// assert_eq!(archived.get(2, 0), None);
+    /// ```
+    pub fn get(&self, row: usize, col: usize) -> Option<&Archived<C>> {
+        self.data.get(row)?.get(col)
+    }
+
+    ///Returns an iterator of all archived coefficients, row after row.
+    pub fn iter(&self) -> impl Iterator<Item = &Archived<C>> {
+        self.data.iter().flatten()
+    }
+
+    ///Returns all archived coefficients as a contiguous slice, row after row.
+    pub fn as_slice(&self) -> &[Archived<C>] {
+        //SAFETY: nested arrays are laid out contiguously without padding, so `data` holds exactly `ROWS * COLS` coefficients.
+        unsafe { std::slice::from_raw_parts(self.data.as_ptr() as *const Archived<C>, ROWS * COLS) }
+    }
+}
+
+///Indexing of archived coefficients by `(row, col)`, panics if out of bounds like [`Matrix`] does.
+impl<C, const ROWS: usize, const COLS: usize> Index<(usize, usize)>
+    for ArchivedMatrix<C, ROWS, COLS>
+where
+    C: Archive,
+{
+    type Output = Archived<C>;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        &self.data[row][col]
+    }
+}
+
+///Comparison of an archived matrix with a live one, coefficient by coefficient.
+impl<C, const ROWS: usize, const COLS: usize> PartialEq<Matrix<C, ROWS, COLS>>
+    for ArchivedMatrix<C, ROWS, COLS>
+where
+    C: Archive,
+    Archived<C>: PartialEq<C>,
+{
+    fn eq(&self, other: &Matrix<C, ROWS, COLS>) -> bool {
+        self.iter().zip(other.iter()).all(|(a, b)| a == b)
+    }
+}
+
+impl<C, const ROWS: usize, const COLS: usize> fmt::Debug for ArchivedMatrix<C, ROWS, COLS>
+where
+    C: Archive,
+    Archived<C>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArchivedMatrix")
+            .field("data", &self.data)
+            .finish()
+    }
+}
@@ -0,0 +1,239 @@
+//! Band matrices, whose non-nil coefficients are all close to the diagonal.
+
+use crate::{Error, Matrix};
+use core::ops::{Index, IndexMut, Mul};
+use num::traits::{Num, Zero};
+
+///Square matrix of size `SIZE` whose coefficients are nil more than `LOWER` places below or `UPPER` places above the diagonal.
+///
+///Only the `LOWER + UPPER + 1` diagonals of the band are stored, which makes products and linear systems take `O(SIZE)` operations
+///for a fixed bandwidth instead of `O(SIZE²)` and `O(SIZE³)`.
+///The tridiagonal matrices of finite-difference discretizations are the most common case, see [`Tridiagonal`].
+///
+/// # Example
+///
+/// ```
+///# use matrix::{BandMatrix, Matrix};
+/// // the discrete laplacian
+/// let laplacian = BandMatrix::<f64, 4, 1, 1>::from_fn(|row, col| if row == col { 2.0 } else { -1.0 });
+/// assert_eq!(
+///     laplacian.to_matrix(),
+///     Matrix::from([
+///         [2.0, -1.0, 0.0, 0.0],
+///         [-1.0, 2.0, -1.0, 0.0],
+///         [0.0, -1.0, 2.0, -1.0],
+///         [0.0, 0.0, -1.0, 2.0],
+///     ])
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BandMatrix<C, const SIZE: usize, const LOWER: usize, const UPPER: usize> {
+    //`lower[k - 1][row]` is the coefficient at (`row`, `row - k`) and `upper[k - 1][row]` the one at (`row`, `row + k`)
+    //the slots falling outside of the matrix are always nil
+    lower: [[C; SIZE]; LOWER],
+    diagonal: [C; SIZE],
+    upper: [[C; SIZE]; UPPER],
+}
+
+///Tridiagonal matrix, whose linear systems are solved by the Thomas algorithm.
+///
+/// # Example
+///
+/// ```
+///# use matrix::{Matrix, Tridiagonal};
+/// let a = Tridiagonal::new([[0.0, 1.0, 1.0]], [4.0, 4.0, 4.0], [[1.0, 1.0, 0.0]]);
+/// let x = Matrix::from([[1.0], [-2.0], [3.0]]);
+/// assert!(a.solve(&a * &x).unwrap().approx_eq(&x, 1e-12));
+/// ```
+pub type Tridiagonal<C, const SIZE: usize> = BandMatrix<C, SIZE, 1, 1>;
+
+impl<C, const SIZE: usize, const LOWER: usize, const UPPER: usize> BandMatrix<C, SIZE, LOWER, UPPER>
+where
+    C: Zero,
+{
+    ///Returns the band matrix made of the given diagonals.
+    ///
+    ///`lower[k - 1]` is the `k`-th diagonal below the main one indexed by row, so that its first `k` coefficients are ignored,
+    ///and `upper[k - 1]` the `k`-th diagonal above the main one, so that its last `k` coefficients are ignored.
+    pub fn new(
+        mut lower: [[C; SIZE]; LOWER],
+        diagonal: [C; SIZE],
+        mut upper: [[C; SIZE]; UPPER],
+    ) -> Self {
+        for (k, band) in lower.iter_mut().enumerate() {
+            band.iter_mut().take(k + 1).for_each(|c| *c = C::zero());
+        }
+        for (k, band) in upper.iter_mut().enumerate() {
+            band.iter_mut()
+                .rev()
+                .take(k + 1)
+                .for_each(|c| *c = C::zero());
+        }
+        BandMatrix {
+            lower,
+            diagonal,
+            upper,
+        }
+    }
+
+    ///Builds a band matrix by calling `f(row, col)` for each coefficient of the band.
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> C,
+    {
+        let in_bounds =
+            |row: usize, col: Option<usize>| col.filter(|&col| row < SIZE && col < SIZE);
+        let mut band = |offset: usize, below: bool| {
+            core::array::from_fn(|row| {
+                let col = if below {
+                    row.checked_sub(offset)
+                } else {
+                    Some(row + offset)
+                };
+                match in_bounds(row, col) {
+                    Some(col) => f(row, col),
+                    None => C::zero(),
+                }
+            })
+        };
+        let lower = core::array::from_fn(|k| band(k + 1, true));
+        let diagonal = band(0, false);
+        let upper = core::array::from_fn(|k| band(k + 1, false));
+        BandMatrix {
+            lower,
+            diagonal,
+            upper,
+        }
+    }
+
+    ///Returns the dense matrix.
+    pub fn to_matrix(&self) -> Matrix<C, SIZE, SIZE>
+    where
+        C: Clone,
+    {
+        Matrix::from_fn(|row, col| self.get(row, col).cloned().unwrap_or_else(C::zero))
+    }
+}
+
+impl<C, const SIZE: usize, const LOWER: usize, const UPPER: usize>
+    BandMatrix<C, SIZE, LOWER, UPPER>
+{
+    ///Returns the coefficient at (`row`, `col`), or `None` if it is out of bounds or outside of the band.
+    pub fn get(&self, row: usize, col: usize) -> Option<&C> {
+        if row >= SIZE || col >= SIZE {
+            return None;
+        }
+        if col > row {
+            self.upper.get(col - row - 1).map(|band| &band[row])
+        } else if row > col {
+            self.lower.get(row - col - 1).map(|band| &band[row])
+        } else {
+            Some(&self.diagonal[row])
+        }
+    }
+
+    ///Returns a mutable reference to the coefficient at (`row`, `col`), or `None` if it is out of bounds or outside of the band.
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut C> {
+        if row >= SIZE || col >= SIZE {
+            return None;
+        }
+        if col > row {
+            self.upper.get_mut(col - row - 1).map(|band| &mut band[row])
+        } else if row > col {
+            self.lower.get_mut(row - col - 1).map(|band| &mut band[row])
+        } else {
+            Some(&mut self.diagonal[row])
+        }
+    }
+}
+
+impl<C, const SIZE: usize, const LOWER: usize, const UPPER: usize> BandMatrix<C, SIZE, LOWER, UPPER>
+where
+    C: Num + Clone,
+{
+    ///Solves the linear system `self * x = b`.
+    ///
+    ///The system is solved through gaussian elimination restricted to the band followed by back substitution,
+    ///which is the Thomas algorithm for tridiagonal matrices and takes `O(SIZE * LOWER * UPPER)` operations.
+    ///Since rows aren't swapped the band is preserved, but this returns Singular as soon as a pivot is nil,
+    ///which can't happen for diagonally dominant matrices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{BandMatrix, Matrix};
+    /// let a = BandMatrix::<f64, 3, 1, 2>::from_fn(|row, col| 1.0 + (row + 3 * col) as f64);
+    /// let b = Matrix::from([[1.0], [2.0], [3.0]]);
+    /// let x = a.solve(b.clone()).unwrap();
+    /// assert!(x.approx_eq(&a.to_matrix().solve(b).unwrap(), 1e-12));
+    /// ```
+    pub fn solve(&self, b: Matrix<C, SIZE, 1>) -> Result<Matrix<C, SIZE, 1>, Error> {
+        let mut a = self.clone();
+        let mut x = b;
+        for k in 0..SIZE {
+            let pivot = a[(k, k)].clone();
+            if pivot.is_zero() {
+                return Err(Error::Singular);
+            }
+            for row in k + 1..SIZE.min(k + LOWER + 1) {
+                let factor = a[(row, k)].clone() / pivot.clone();
+                for col in k..SIZE.min(k + UPPER + 1) {
+                    a[(row, col)] = a[(row, col)].clone() - factor.clone() * a[(k, col)].clone();
+                }
+                x.data[row][0] = x.data[row][0].clone() - factor * x.data[k][0].clone();
+            }
+        }
+
+        for row in (0..SIZE).rev() {
+            let acc = (row + 1..SIZE.min(row + UPPER + 1))
+                .fold(x.data[row][0].clone(), |acc, k| {
+                    acc - a[(row, k)].clone() * x.data[k][0].clone()
+                });
+            x.data[row][0] = acc / a[(row, row)].clone();
+        }
+        Ok(x)
+    }
+}
+
+///Access to the coefficients of the band with a `(row, col)` pair.
+///
+/// # Panics
+///
+/// If the coefficient is out of bounds or outside of the band.
+impl<C, const SIZE: usize, const LOWER: usize, const UPPER: usize> Index<(usize, usize)>
+    for BandMatrix<C, SIZE, LOWER, UPPER>
+{
+    type Output = C;
+
+    fn index(&self, (row, col): (usize, usize)) -> &C {
+        self.get(row, col)
+            .expect("invalid row or column: out of the band")
+    }
+}
+
+///Mutable access to the coefficients of the band, see the `Index` implementation.
+impl<C, const SIZE: usize, const LOWER: usize, const UPPER: usize> IndexMut<(usize, usize)>
+    for BandMatrix<C, SIZE, LOWER, UPPER>
+{
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut C {
+        self.get_mut(row, col)
+            .expect("invalid row or column: out of the band")
+    }
+}
+
+///Product with a dense matrix, only the coefficients of the band being multiplied.
+impl<C, const SIZE: usize, const LOWER: usize, const UPPER: usize, const COLS: usize>
+    Mul<&Matrix<C, SIZE, COLS>> for &BandMatrix<C, SIZE, LOWER, UPPER>
+where
+    C: Num + Clone,
+{
+    type Output = Matrix<C, SIZE, COLS>;
+
+    fn mul(self, other: &Matrix<C, SIZE, COLS>) -> Self::Output {
+        Matrix::from_fn(|row, col| {
+            (row.saturating_sub(LOWER)..SIZE.min(row + UPPER + 1)).fold(C::zero(), |acc, k| {
+                acc + self[(row, k)].clone() * other.data[k][col].clone()
+            })
+        })
+    }
+}
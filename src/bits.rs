@@ -0,0 +1,315 @@
+//! Boolean matrices over GF(2), the field with two elements, stored one bit per coefficient.
+
+use crate::{Error, Matrix};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{Add, AddAssign, Index, Mul};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+///Matrix of `ROWS` rows and `COLS` columns over GF(2), packing each row in 64-bit words.
+///
+///Addition is a XOR of the words and the product of a row and a column is the parity of the bits they have in common,
+///which makes this both 8 times smaller and much faster than a `Matrix<bool, ROWS, COLS>` or `Matrix<u8, ROWS, COLS>`.
+///
+/// # Example
+///
+/// ```
+///# use matrix::{BitMatrix, Matrix};
+/// let a = BitMatrix::from(&Matrix::from([[true, true], [false, true]]));
+/// // a is its own inverse since 1 + 1 = 0
+/// assert_eq!(&a * &a, BitMatrix::identity());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitMatrix<const ROWS: usize, const COLS: usize> {
+    //row `i` is made of the words at indices `i * WORDS..(i + 1) * WORDS`, bit `j % 64` of word `j / 64` being column `j`
+    //the bits past the last column are always unset
+    words: Vec<u64>,
+}
+
+impl<const ROWS: usize, const COLS: usize> BitMatrix<ROWS, COLS> {
+    //Number of words each row is stored in.
+    const WORDS: usize = COLS.div_ceil(WORD_BITS);
+
+    ///Returns the matrix whose coefficients are all unset.
+    pub fn new() -> Self {
+        BitMatrix {
+            words: vec![0; ROWS * Self::WORDS],
+        }
+    }
+
+    ///Builds a matrix by calling `f(row, col)` for each coefficient.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::BitMatrix;
+    /// let checkerboard = BitMatrix::<3, 3>::from_fn(|row, col| (row + col) % 2 == 0);
+    /// assert_eq!(checkerboard.count_ones(), 5);
+    /// ```
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> bool,
+    {
+        let mut m = Self::new();
+        for row in 0..ROWS {
+            for col in 0..COLS {
+                if f(row, col) {
+                    m.flip(row, col);
+                }
+            }
+        }
+        m
+    }
+
+    ///Returns the coefficient at (`row`, `col`) or `None` if either is out of bounds.
+    pub fn get(&self, row: usize, col: usize) -> Option<bool> {
+        if row >= ROWS || col >= COLS {
+            return None;
+        }
+        Some(self.row(row)[col / WORD_BITS] >> (col % WORD_BITS) & 1 == 1)
+    }
+
+    ///Sets the coefficient at (`row`, `col`) to `value`.
+    ///
+    /// # Panics
+    ///
+    /// If either `row` or `col` is out of bounds.
+    pub fn set(&mut self, row: usize, col: usize, value: bool) {
+        if self[(row, col)] != value {
+            self.flip(row, col);
+        }
+    }
+
+    ///Flips the coefficient at (`row`, `col`), that is adds 1 to it.
+    ///
+    /// # Panics
+    ///
+    /// If either `row` or `col` is out of bounds.
+    pub fn flip(&mut self, row: usize, col: usize) {
+        assert!(
+            row < ROWS && col < COLS,
+            "invalid row or column: out of bounds"
+        );
+        self.row_mut(row)[col / WORD_BITS] ^= 1 << (col % WORD_BITS);
+    }
+
+    ///Returns the number of set coefficients.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    ///Returns the transpose of the matrix.
+    pub fn transpose(&self) -> BitMatrix<COLS, ROWS> {
+        BitMatrix::from_fn(|row, col| self[(col, row)])
+    }
+
+    ///Converts the matrix to a matrix of `bool`, see the `From` implementation for the other direction.
+    pub fn to_matrix(&self) -> Matrix<bool, ROWS, COLS> {
+        Matrix::from_fn(|row, col| self[(row, col)])
+    }
+
+    fn row(&self, row: usize) -> &[u64] {
+        &self.words[row * Self::WORDS..(row + 1) * Self::WORDS]
+    }
+
+    fn row_mut(&mut self, row: usize) -> &mut [u64] {
+        &mut self.words[row * Self::WORDS..(row + 1) * Self::WORDS]
+    }
+
+    //Adds row `source` to row `target`, which must be different.
+    fn add_row(&mut self, source: usize, target: usize) {
+        for i in 0..Self::WORDS {
+            self.words[target * Self::WORDS + i] ^= self.words[source * Self::WORDS + i];
+        }
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for i in 0..Self::WORDS {
+            self.words.swap(a * Self::WORDS + i, b * Self::WORDS + i);
+        }
+    }
+
+    ///Puts the matrix in reduced row echelon form through gaussian elimination and returns its rank.
+    ///
+    ///Over GF(2) every non-nil pivot is 1 and eliminating a coefficient is adding the pivot row, so no division is involved.
+    pub fn reduce(&mut self) -> usize {
+        let mut pivot_row = 0;
+        for col in 0..COLS {
+            if pivot_row == ROWS {
+                break;
+            }
+            let pivot = match (pivot_row..ROWS).find(|&row| self[(row, col)]) {
+                Some(pivot) => pivot,
+                None => continue,
+            };
+            self.swap_rows(pivot_row, pivot);
+            for row in 0..ROWS {
+                if row != pivot_row && self[(row, col)] {
+                    self.add_row(pivot_row, row);
+                }
+            }
+            pivot_row += 1;
+        }
+        pivot_row
+    }
+
+    ///Returns the reduced row echelon form of the matrix, see [`reduce`] for the in-place variant.
+    ///
+    /// [`reduce`]: #method.reduce
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{BitMatrix, Matrix};
+    /// let m = BitMatrix::from(&Matrix::from([[true, true, false], [true, false, true]]));
+    /// assert_eq!(m.rref().to_matrix(), Matrix::from([[true, false, true], [false, true, true]]));
+    /// ```
+    pub fn rref(&self) -> Self {
+        let mut m = self.clone();
+        m.reduce();
+        m
+    }
+
+    ///Returns the rank of the matrix over GF(2).
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::BitMatrix;
+    /// // every row is the sum of the two others
+    /// let m = BitMatrix::<3, 2>::from_fn(|row, col| row == col || row == 2);
+    /// assert_eq!(m.rank(), 2);
+    /// ```
+    pub fn rank(&self) -> usize {
+        self.clone().reduce()
+    }
+}
+
+///Linear algebra for square matrices over GF(2)
+impl<const SIZE: usize> BitMatrix<SIZE, SIZE> {
+    ///Returns the identity matrix.
+    pub fn identity() -> Self {
+        BitMatrix::from_fn(|row, col| row == col)
+    }
+
+    ///Returns the inverse of the matrix over GF(2).
+    ///
+    ///The inverse is computed through Gauss-Jordan elimination. Returns Singular if the matrix has no inverse.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::BitMatrix;
+    /// let m = BitMatrix::<3, 3>::from_fn(|row, col| row <= col);
+    /// assert_eq!(&m * &m.inverse().unwrap(), BitMatrix::identity());
+    /// ```
+    pub fn inverse(&self) -> Result<Self, Error> {
+        let mut a = self.clone();
+        let mut inverse = Self::identity();
+        for col in 0..SIZE {
+            let pivot = (col..SIZE)
+                .find(|&row| a[(row, col)])
+                .ok_or(Error::Singular)?;
+            a.swap_rows(col, pivot);
+            inverse.swap_rows(col, pivot);
+            for row in 0..SIZE {
+                if row != col && a[(row, col)] {
+                    a.add_row(col, row);
+                    inverse.add_row(col, row);
+                }
+            }
+        }
+        Ok(inverse)
+    }
+
+    ///Solves the linear system `self * x = b` over GF(2).
+    ///
+    ///Returns Singular if the matrix has no inverse.
+    pub fn solve(&self, b: &BitMatrix<SIZE, 1>) -> Result<BitMatrix<SIZE, 1>, Error> {
+        Ok(&self.inverse()? * b)
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize> Default for BitMatrix<ROWS, COLS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+///Indexing by `(row, col)`, panics if either is out of bounds.
+impl<const ROWS: usize, const COLS: usize> Index<(usize, usize)> for BitMatrix<ROWS, COLS> {
+    type Output = bool;
+
+    fn index(&self, (row, col): (usize, usize)) -> &bool {
+        //bits can't be borrowed, so references to constants are returned instead
+        match self.get(row, col) {
+            Some(true) => &true,
+            Some(false) => &false,
+            None => panic!("invalid row or column: out of bounds"),
+        }
+    }
+}
+
+///Packs a matrix of `bool`, `true` being 1.
+impl<const ROWS: usize, const COLS: usize> From<&Matrix<bool, ROWS, COLS>>
+    for BitMatrix<ROWS, COLS>
+{
+    fn from(matrix: &Matrix<bool, ROWS, COLS>) -> Self {
+        BitMatrix::from_fn(|row, col| matrix.data[row][col])
+    }
+}
+
+///Addition over GF(2), which is the exclusive or of the coefficients.
+impl<const ROWS: usize, const COLS: usize> AddAssign<&BitMatrix<ROWS, COLS>>
+    for BitMatrix<ROWS, COLS>
+{
+    fn add_assign(&mut self, other: &BitMatrix<ROWS, COLS>) {
+        self.words
+            .iter_mut()
+            .zip(other.words.iter())
+            .for_each(|(a, b)| *a ^= b);
+    }
+}
+
+///Addition over GF(2), which is the exclusive or of the coefficients.
+///
+/// # Example
+///
+/// ```
+///# use matrix::BitMatrix;
+/// let m = BitMatrix::<2, 70>::from_fn(|row, col| (row + col) % 3 == 0);
+/// assert_eq!(&m + &m, BitMatrix::new());
+/// ```
+impl<const ROWS: usize, const COLS: usize> Add for &BitMatrix<ROWS, COLS> {
+    type Output = BitMatrix<ROWS, COLS>;
+
+    fn add(self, other: Self) -> Self::Output {
+        let mut m = self.clone();
+        m += other;
+        m
+    }
+}
+
+///Matrix product over GF(2).
+///
+///Each coefficient is the parity of the number of bits set in both a row of `self` and a column of `other`,
+///which is computed a word at a time with a logical and followed by a population count.
+impl<const ROWS: usize, const COLS: usize, const Q: usize> Mul<&BitMatrix<Q, COLS>>
+    for &BitMatrix<ROWS, Q>
+{
+    type Output = BitMatrix<ROWS, COLS>;
+
+    fn mul(self, other: &BitMatrix<Q, COLS>) -> Self::Output {
+        let columns = other.transpose();
+        BitMatrix::from_fn(|row, col| {
+            let ones: u32 = self
+                .row(row)
+                .iter()
+                .zip(columns.row(col))
+                .map(|(a, b)| (a & b).count_ones())
+                .sum();
+            ones % 2 == 1
+        })
+    }
+}
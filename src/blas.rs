@@ -0,0 +1,119 @@
+//! Products and linear systems of floating point matrices computed by BLAS and LAPACK, enabled by the `blas` feature.
+//!
+//! Only the bindings are provided: a BLAS/LAPACKE implementation has to be linked by the final crate,
+//! for instance through `openblas-src` or `intel-mkl-src`.
+//! The arithmetic operators keep using the pure Rust implementation since they are generic over the coefficients.
+
+use crate::{Error, Matrix};
+use cblas_sys::{CblasNoTrans, CblasRowMajor};
+use lapacke_sys::LAPACK_ROW_MAJOR;
+use std::convert::TryFrom;
+use std::os::raw::c_int;
+
+//BLAS takes dimensions as C integers, leading dimensions can't be 0 even for empty matrices.
+fn dim(size: usize) -> c_int {
+    c_int::try_from(size).expect("matrix dimension too large for BLAS")
+}
+
+fn leading_dim(size: usize) -> c_int {
+    dim(size.max(1))
+}
+
+//The bindings are the same for `f32` and `f64` except for the `s`/`d` prefix of the routines.
+macro_rules! impl_blas {
+    ($t:ty, $gemm:ident, $gesv:ident) => {
+        ///Operations computed by BLAS and LAPACK
+        impl<const ROWS: usize, const COLS: usize> Matrix<$t, ROWS, COLS> {
+            ///Matrix product computed by the BLAS `gemm` routine.
+            ///
+            #[doc = concat!("The result is the same as with the `*` operator up to rounding errors, the order in which `", stringify!($t), "` terms are summed depending on the BLAS implementation.")]
+            ///
+            ///A BLAS implementation has to be linked by the final crate, so the example isn't run by the crate's tests.
+            ///
+            /// # Example
+            ///
+            /// ```ignore
+            ///# use matrix::Matrix;
+            #[doc = concat!("let left = Matrix::<", stringify!($t), ", 2, 3>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);")]
+            /// let right = Matrix::from([[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]);
+            /// assert_eq!(left.blas_mul(&right), Matrix::from([[4.0, 5.0], [10.0, 11.0]]));
+            /// ```
+            pub fn blas_mul<const Q: usize>(&self, other: &Matrix<$t, COLS, Q>) -> Matrix<$t, ROWS, Q> {
+                let mut result = Matrix::from_fn(|_, _| 0.0);
+                // SAFETY: the matrices are contiguous row-major arrays whose dimensions are the ones given to BLAS
+                unsafe {
+                    cblas_sys::$gemm(
+                        CblasRowMajor,
+                        CblasNoTrans,
+                        CblasNoTrans,
+                        dim(ROWS),
+                        dim(Q),
+                        dim(COLS),
+                        1.0,
+                        self.as_slice().as_ptr(),
+                        leading_dim(COLS),
+                        other.as_slice().as_ptr(),
+                        leading_dim(Q),
+                        0.0,
+                        result.as_mut_slice().as_mut_ptr(),
+                        leading_dim(Q),
+                    );
+                }
+                result
+            }
+        }
+
+        ///Linear systems solved by LAPACK
+        impl<const SIZE: usize> Matrix<$t, SIZE, SIZE> {
+            ///Solves the linear system `self * x = b` with the LAPACK `gesv` routine, each column of `b` being a right-hand side.
+            ///
+            ///As with [`Matrix::solve`] the system goes through gaussian elimination with partial pivoting.
+            ///Returns Singular if the matrix has no inverse.
+            ///
+            ///A LAPACKE implementation has to be linked by the final crate, so the example isn't run by the crate's tests.
+            ///
+            /// # Example
+            ///
+            /// ```ignore
+            ///# use matrix::Matrix;
+            #[doc = concat!("let a = Matrix::<", stringify!($t), ", 2, 2>::from([[2.0, 1.0], [1.0, 3.0]]);")]
+            /// let x = a.blas_solve([[5.0], [10.0]].into()).unwrap();
+            /// assert!((x[(0, 0)] - 1.0).abs() < 1e-6 && (x[(1, 0)] - 3.0).abs() < 1e-6);
+            /// ```
+            pub fn blas_solve<const N: usize>(
+                &self,
+                b: Matrix<$t, SIZE, N>,
+            ) -> Result<Matrix<$t, SIZE, N>, Error> {
+                if SIZE == 0 || N == 0 {
+                    return Ok(b);
+                }
+                let mut a = self.clone();
+                let mut x = b;
+                let mut pivots = vec![0; SIZE];
+                // SAFETY: the matrices are contiguous row-major arrays whose dimensions are the ones given to LAPACK,
+                // `pivots` has room for one index per row
+                let info = unsafe {
+                    lapacke_sys::$gesv(
+                        LAPACK_ROW_MAJOR,
+                        dim(SIZE),
+                        dim(N),
+                        a.as_mut_slice().as_mut_ptr(),
+                        leading_dim(SIZE),
+                        pivots.as_mut_ptr(),
+                        x.as_mut_slice().as_mut_ptr(),
+                        leading_dim(N),
+                    )
+                };
+                match info {
+                    0 => Ok(x),
+                    //a positive value is the index of a zero pivot, negative ones point at invalid arguments
+                    i if i > 0 => Err(Error::Singular),
+                    i => unreachable!("invalid argument {} given to gesv", -i),
+                }
+            }
+        }
+    };
+}
+
+impl_blas!(f32, cblas_sgemm, LAPACKE_sgesv);
+impl_blas!(f64, cblas_dgemm, LAPACKE_dgesv);
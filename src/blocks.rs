@@ -0,0 +1,262 @@
+//! Changing the shape of matrices: assembling and extracting blocks and minors, concatenating and reshaping.
+
+use crate::{Error, Matrix};
+
+//Referencing `FITS` fails to compile unless blocks of `R1 + R2` rows and `C1 + C2` columns make up a `ROWS` by `COLS` matrix.
+struct Partition<
+    const R1: usize,
+    const R2: usize,
+    const C1: usize,
+    const C2: usize,
+    const ROWS: usize,
+    const COLS: usize,
+>;
+
+impl<
+        const R1: usize,
+        const R2: usize,
+        const C1: usize,
+        const C2: usize,
+        const ROWS: usize,
+        const COLS: usize,
+    > Partition<R1, R2, C1, C2, ROWS, COLS>
+{
+    const FITS: () = assert!(
+        R1 + R2 == ROWS && C1 + C2 == COLS,
+        "the dimensions of the blocks don't add up to the ones of the matrix"
+    );
+}
+
+//Referencing `SAME_SIZE` fails to compile unless both shapes hold the same number of coefficients.
+struct Reshape<const ROWS: usize, const COLS: usize, const R2: usize, const C2: usize>;
+
+impl<const ROWS: usize, const COLS: usize, const R2: usize, const C2: usize>
+    Reshape<ROWS, COLS, R2, C2>
+{
+    const SAME_SIZE: () = assert!(
+        ROWS * COLS == R2 * C2,
+        "the matrices don't have the same number of coefficients"
+    );
+}
+
+//Referencing `ONE_LESS` fails to compile unless `R2` and `C2` are one less than `ROWS` and `COLS`.
+struct Minor<const ROWS: usize, const COLS: usize, const R2: usize, const C2: usize>;
+
+impl<const ROWS: usize, const COLS: usize, const R2: usize, const C2: usize>
+    Minor<ROWS, COLS, R2, C2>
+{
+    const ONE_LESS: () = assert!(
+        R2 + 1 == ROWS && C2 + 1 == COLS,
+        "a minor has one row and one column less than the matrix"
+    );
+}
+
+//Returns whether `len` rows or columns starting at `start` fit in `size` of them, without overflowing on huge starts.
+pub(crate) fn block_fits(start: usize, len: usize, size: usize) -> bool {
+    start.checked_add(len).is_some_and(|end| end <= size)
+}
+
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS> {
+    ///Returns a matrix of `R2` rows and `C2` columns with the same coefficients in row-major order.
+    ///
+    ///Both shapes must have the same number of coefficients, which is checked at compile time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{Matrix, RowVector};
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(mat.clone().reshape::<3, 2>(), Matrix::from([[1, 2], [3, 4], [5, 6]]));
+    /// assert_eq!(mat.reshape::<1, 6>(), RowVector::from([[1, 2, 3, 4, 5, 6]]));
+    /// ```
+    ///
+    /// ```compile_fail
+    ///# use matrix::Matrix;
+    /// Matrix::from([[1, 2, 3], [4, 5, 6]]).reshape::<2, 2>();
+    /// ```
+    pub fn reshape<const R2: usize, const C2: usize>(self) -> Matrix<C, R2, C2> {
+        #[allow(clippy::let_unit_value)]
+        let () = Reshape::<ROWS, COLS, R2, C2>::SAME_SIZE;
+        Matrix::try_from_iter(self)
+            .unwrap_or_else(|_| unreachable!("the sizes are checked at compile time"))
+    }
+}
+
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS>
+where
+    C: Clone,
+{
+    ///Assembles a matrix from a 2×2 grid of blocks.
+    ///
+    ///The blocks of a same row must have the same number of rows, and the blocks of a same column the same number of columns.
+    ///This is checked at compile time along with the dimensions of the resulting matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// //a saddle point system [[A, B], [Bᵀ, 0]]
+    /// let a = Matrix::from([[2, 0], [0, 3]]);
+    /// let b = Matrix::from([[1], [1]]);
+    /// let mat = Matrix::from_blocks(&a, &b, &b.transpose(), &Matrix::from([[0]]));
+    /// assert_eq!(mat, Matrix::from([[2, 0, 1], [0, 3, 1], [1, 1, 0]]));
+    /// ```
+    ///
+    /// Blocks which don't fit don't compile.
+    /// ```compile_fail
+    ///# use matrix::Matrix;
+    /// let a = Matrix::from([[1, 2], [3, 4]]);
+    /// let mat: Matrix<i32, 4, 3> = Matrix::from_blocks(&a, &a, &a, &a);
+    /// ```
+    pub fn from_blocks<const R1: usize, const R2: usize, const C1: usize, const C2: usize>(
+        top_left: &Matrix<C, R1, C1>,
+        top_right: &Matrix<C, R1, C2>,
+        bottom_left: &Matrix<C, R2, C1>,
+        bottom_right: &Matrix<C, R2, C2>,
+    ) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Partition::<R1, R2, C1, C2, ROWS, COLS>::FITS;
+        Matrix::from_fn(|row, col| match (row < R1, col < C1) {
+            (true, true) => top_left.data[row][col].clone(),
+            (true, false) => top_right.data[row][col - C1].clone(),
+            (false, true) => bottom_left.data[row - R1][col].clone(),
+            (false, false) => bottom_right.data[row - R1][col - C1].clone(),
+        })
+    }
+
+    ///Returns the minor of the matrix without row `row` and column `col`, as a matrix of `R2` rows and `C2` columns.
+    ///
+    ///Since the dimensions of the result can't be computed on stable, they are checked at compile time instead:
+    ///they must be one less than the ones of the matrix. Returns OutOfBounds if either `row` or `col` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// assert_eq!(mat.minor(1, 0), Ok(Matrix::from([[2, 3], [8, 9]])));
+    /// // cofactor expansion along the first row
+    /// let det: i32 = (0..3)
+    ///     .map(|col| {
+    ///         let m: Matrix<i32, 2, 2> = mat.minor(0, col).unwrap();
+    ///         let sign = if col % 2 == 0 { 1 } else { -1 };
+    ///         sign * mat[(0, col)] * (m[(0, 0)] * m[(1, 1)] - m[(0, 1)] * m[(1, 0)])
+    ///     })
+    ///     .sum();
+    /// assert_eq!(det, 0);
+    /// ```
+    ///
+    /// ```compile_fail
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let minor: Matrix<i32, 2, 3> = mat.minor(1, 0).unwrap();
+    /// ```
+    pub fn minor<const R2: usize, const C2: usize>(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> Result<Matrix<C, R2, C2>, Error> {
+        #[allow(clippy::let_unit_value)]
+        let () = Minor::<ROWS, COLS, R2, C2>::ONE_LESS;
+        if row >= ROWS || col >= COLS {
+            return Err(Error::OutOfBounds);
+        }
+        Ok(Matrix::from_fn(|i, j| {
+            self.data[i + usize::from(i >= row)][j + usize::from(j >= col)].clone()
+        }))
+    }
+
+    ///Returns a copy of the `R2` by `C2` block whose top left coefficient is at `(start_row, start_col)`.
+    ///
+    ///Returns OutOfBounds if the block doesn't fit in the matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{Error, Matrix};
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// assert_eq!(mat.submatrix::<2, 2>(1, 0), Ok(Matrix::from([[4, 5], [7, 8]])));
+    /// assert_eq!(mat.submatrix::<1, 3>(0, 1), Err(Error::OutOfBounds));
+    /// ```
+    pub fn submatrix<const R2: usize, const C2: usize>(
+        &self,
+        start_row: usize,
+        start_col: usize,
+    ) -> Result<Matrix<C, R2, C2>, Error> {
+        if !block_fits(start_row, R2, ROWS) || !block_fits(start_col, C2, COLS) {
+            return Err(Error::OutOfBounds);
+        }
+        Ok(Matrix::from_fn(|row, col| {
+            self.data[start_row + row][start_col + col].clone()
+        }))
+    }
+}
+
+///Concatenations, whose result dimensions are computed from the operands' ones.
+///
+///These rely on `generic_const_exprs` and are only available with the `nightly` feature.
+///On stable, [`from_blocks`] can be used with blocks that have no rows or no columns.
+///
+/// [`from_blocks`]: #method.from_blocks
+#[cfg(feature = "nightly")]
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS>
+where
+    C: Clone,
+{
+    ///Returns the matrix made of the columns of `self` followed by the columns of `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# #![allow(incomplete_features)]
+    ///# #![feature(generic_const_exprs)]
+    ///# use matrix::Matrix;
+    /// //the augmented matrix [A | b]
+    /// let a = Matrix::from([[1, 2], [3, 4]]);
+    /// let b = Matrix::from([[5], [6]]);
+    /// assert_eq!(a.hcat(&b), Matrix::from([[1, 2, 5], [3, 4, 6]]));
+    /// ```
+    pub fn hcat<const C2: usize>(
+        &self,
+        other: &Matrix<C, ROWS, C2>,
+    ) -> Matrix<C, ROWS, { COLS + C2 }>
+    where
+        [(); COLS + C2]:,
+    {
+        Matrix::from_fn(|row, col| {
+            if col < COLS {
+                self.data[row][col].clone()
+            } else {
+                other.data[row][col - COLS].clone()
+            }
+        })
+    }
+
+    ///Returns the matrix made of the rows of `self` followed by the rows of `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# #![allow(incomplete_features)]
+    ///# #![feature(generic_const_exprs)]
+    ///# use matrix::Matrix;
+    /// let a = Matrix::from([[1, 2], [3, 4]]);
+    /// let b = Matrix::from([[5, 6]]);
+    /// assert_eq!(a.vcat(&b), Matrix::from([[1, 2], [3, 4], [5, 6]]));
+    /// ```
+    pub fn vcat<const R2: usize>(
+        &self,
+        other: &Matrix<C, R2, COLS>,
+    ) -> Matrix<C, { ROWS + R2 }, COLS>
+    where
+        [(); ROWS + R2]:,
+    {
+        Matrix::from_fn(|row, col| {
+            if row < ROWS {
+                self.data[row][col].clone()
+            } else {
+                other.data[row - ROWS][col].clone()
+            }
+        })
+    }
+}
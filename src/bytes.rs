@@ -0,0 +1,70 @@
+//! Conversions of matrices to and from bytes through `bytemuck`, enabled by the `bytemuck` feature.
+//!
+//! `Pod` can't be implemented since it requires `Copy`, which matrices don't implement so that large arrays aren't copied behind the user's back.
+//! The methods of this module cover what it would be used for: viewing the coefficients as bytes, for instance to fill a GPU buffer, and reading them back.
+
+use crate::{Error, Matrix};
+use bytemuck::{AnyBitPattern, NoUninit, Pod, Zeroable};
+
+//SAFETY: `Matrix` is a transparent wrapper around a nested array, which is zeroable when its coefficients are.
+unsafe impl<C, const ROWS: usize, const COLS: usize> Zeroable for Matrix<C, ROWS, COLS> where
+    C: Zeroable
+{
+}
+
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS> {
+    ///Returns the bytes of the coefficients, row after row, in native endianness.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1u16, 2], [3, 4]]);
+    /// let bytes: Vec<u8> = [1u16, 2, 3, 4].iter().flat_map(|c| c.to_ne_bytes()).collect();
+    /// assert_eq!(mat.as_bytes(), &bytes[..]);
+    /// ```
+    pub fn as_bytes(&self) -> &[u8]
+    where
+        C: NoUninit,
+    {
+        bytemuck::cast_slice(self.as_slice())
+    }
+
+    ///Returns the bytes of the coefficients mutably, see [`as_bytes`].
+    ///
+    ///Any byte pattern being a valid coefficient is required, so that writing the bytes can't create an invalid value.
+    ///
+    /// [`as_bytes`]: #method.as_bytes
+    pub fn as_bytes_mut(&mut self) -> &mut [u8]
+    where
+        C: Pod,
+    {
+        bytemuck::cast_slice_mut(self.as_mut_slice())
+    }
+
+    ///Builds a matrix from the bytes of its coefficients, row after row, in native endianness.
+    ///
+    ///The bytes don't need to be aligned. Returns WrongLength unless there are exactly as many bytes as the coefficients take.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1.5f32, -2.0, 0.25]]);
+    /// assert_eq!(Matrix::from_bytes(mat.as_bytes()), Ok(mat.clone()));
+    /// assert!(Matrix::<f32, 1, 3>::from_bytes(&mat.as_bytes()[1..]).is_err());
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error>
+    where
+        C: AnyBitPattern,
+    {
+        let size = core::mem::size_of::<C>();
+        if bytes.len() != ROWS * COLS * size {
+            return Err(Error::WrongLength);
+        }
+        Ok(Matrix::from_fn(|row, col| {
+            let start = (row * COLS + col) * size;
+            bytemuck::pod_read_unaligned(&bytes[start..start + size])
+        }))
+    }
+}
@@ -0,0 +1,86 @@
+//! Approximate comparisons, needed as soon as the coefficients are floating point numbers.
+
+use crate::Matrix;
+use num::traits::Signed;
+
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS>
+where
+    C: Signed + PartialOrd + Clone,
+{
+    ///Returns whether every coefficient is within `epsilon` of the corresponding one of `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[0.1 + 0.2, 1.0]]);
+    /// assert_ne!(mat, Matrix::from([[0.3, 1.0]]));
+    /// assert!(mat.approx_eq(&Matrix::from([[0.3, 1.0]]), 1e-12));
+    /// ```
+    pub fn approx_eq(&self, other: &Self, epsilon: C) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(a, b)| (a.clone() - b.clone()).abs() <= epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+mod approx_impls {
+    use crate::Matrix;
+    use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+    impl<C, const ROWS: usize, const COLS: usize> AbsDiffEq for Matrix<C, ROWS, COLS>
+    where
+        C: AbsDiffEq,
+        C::Epsilon: Clone,
+    {
+        type Epsilon = C::Epsilon;
+
+        fn default_epsilon() -> Self::Epsilon {
+            C::default_epsilon()
+        }
+
+        fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+            self.iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon.clone()))
+        }
+    }
+
+    impl<C, const ROWS: usize, const COLS: usize> RelativeEq for Matrix<C, ROWS, COLS>
+    where
+        C: RelativeEq,
+        C::Epsilon: Clone,
+    {
+        fn default_max_relative() -> Self::Epsilon {
+            C::default_max_relative()
+        }
+
+        fn relative_eq(
+            &self,
+            other: &Self,
+            epsilon: Self::Epsilon,
+            max_relative: Self::Epsilon,
+        ) -> bool {
+            self.iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.relative_eq(b, epsilon.clone(), max_relative.clone()))
+        }
+    }
+
+    impl<C, const ROWS: usize, const COLS: usize> UlpsEq for Matrix<C, ROWS, COLS>
+    where
+        C: UlpsEq,
+        C::Epsilon: Clone,
+    {
+        fn default_max_ulps() -> u32 {
+            C::default_max_ulps()
+        }
+
+        fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+            self.iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.ulps_eq(b, epsilon.clone(), max_ulps))
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//! Matrices of complex numbers: conjugate transpose, Hermitian and unitary matrices, linear algebra.
+//!
+//! The arithmetic of [`Matrix`] works as is with `num::Complex` coefficients, but the linear algebra of real matrices
+//! needs [`Signed`](num::traits::Signed) coefficients to choose its pivots, which complex numbers aren't.
+//! It is provided by the [`FieldLinearAlgebra`](crate::FieldLinearAlgebra) trait instead, complex pivots being chosen by modulus.
+
+use crate::{Matrix, Pivot};
+use core::ops::Neg;
+use num::traits::{Float, Num};
+use num::Complex;
+
+impl<T, const ROWS: usize, const COLS: usize> Matrix<Complex<T>, ROWS, COLS>
+where
+    T: Clone + Num + Neg<Output = T>,
+{
+    ///Returns the matrix whose coefficients are the complex conjugates of the ones of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// use num::Complex;
+    /// let mat = Matrix::from([[Complex::new(1, 2), Complex::new(3, -4)]]);
+    /// assert_eq!(mat.conjugate(), Matrix::from([[Complex::new(1, -2), Complex::new(3, 4)]]));
+    /// ```
+    pub fn conjugate(&self) -> Self {
+        Matrix::from_fn(|row, col| self.data[row][col].conj())
+    }
+
+    ///Returns the conjugate transpose of the matrix, often written Aᴴ or A*.
+    ///
+    ///It plays the role of the transpose for complex matrices: (AB)ᴴ = BᴴAᴴ and ⟨Ax, y⟩ = ⟨x, Aᴴy⟩.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// use num::Complex;
+    /// let mat = Matrix::from([[Complex::new(1, 2), Complex::new(3, -4)]]);
+    /// assert_eq!(
+    ///     mat.hermitian_transpose(),
+    ///     Matrix::from([[Complex::new(1, -2)], [Complex::new(3, 4)]])
+    /// );
+    /// ```
+    pub fn hermitian_transpose(&self) -> Matrix<Complex<T>, COLS, ROWS> {
+        Matrix::from_fn(|row, col| self.data[col][row].conj())
+    }
+
+    ///Returns the conjugate transpose of the matrix, see [`hermitian_transpose`].
+    ///
+    /// [`hermitian_transpose`]: #method.hermitian_transpose
+    pub fn adjoint(&self) -> Matrix<Complex<T>, COLS, ROWS> {
+        self.hermitian_transpose()
+    }
+}
+
+impl<T, const SIZE: usize> Matrix<Complex<T>, SIZE, SIZE>
+where
+    T: Float,
+{
+    ///Returns whether the matrix is Hermitian, that is equal to its conjugate transpose, up to `tolerance`.
+    ///
+    ///Each coefficient must be within `tolerance` of the conjugate of its mirror, so the diagonal must be real.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// use num::Complex;
+    /// let i = Complex::i();
+    /// let pauli_y = Matrix::from([[Complex::new(0.0, 0.0), -i], [i, Complex::new(0.0, 0.0)]]);
+    /// assert!(pauli_y.is_hermitian(&1e-12));
+    /// assert!(!(pauli_y * i).is_hermitian(&1e-12));
+    /// ```
+    pub fn is_hermitian(&self, tolerance: &T) -> bool {
+        (0..SIZE).all(|row| {
+            (row..SIZE)
+                .all(|col| (self.data[row][col] - self.data[col][row].conj()).norm() <= *tolerance)
+        })
+    }
+
+    ///Returns whether the matrix is unitary, that is whether AᴴA is the identity, up to `tolerance`.
+    ///
+    ///Every coefficient of AᴴA must be within `tolerance` of the one of the identity.
+    ///Unitary matrices preserve the norm of the vectors they're multiplied with, their inverse being their conjugate transpose.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// use num::Complex;
+    /// let h = std::f64::consts::FRAC_1_SQRT_2;
+    /// let hadamard = Matrix::from([[h, h], [h, -h]]).map(Complex::from);
+    /// assert!(hadamard.is_unitary(&1e-12));
+    /// assert!(!(hadamard * Complex::new(2.0, 0.0)).is_unitary(&1e-12));
+    /// ```
+    pub fn is_unitary(&self, tolerance: &T) -> bool {
+        let product = &self.hermitian_transpose() * self;
+        product.get_lines().enumerate().all(|(row, line)| {
+            line.iter().enumerate().all(|(col, c)| {
+                let expected = if row == col { T::one() } else { T::zero() };
+                (c - expected).norm() <= *tolerance
+            })
+        })
+    }
+}
+
+///Complex pivots are chosen by modulus, compared through its square which needs no square root.
+impl<T> Pivot for Complex<T>
+where
+    T: Clone + Num + PartialOrd,
+{
+    type Magnitude = T;
+
+    fn magnitude(&self) -> T {
+        self.norm_sqr()
+    }
+}
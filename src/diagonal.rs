@@ -0,0 +1,150 @@
+//! Diagonal matrices, storing only their diagonal.
+
+use crate::{Error, Matrix};
+use core::iter::Product;
+use core::ops::Mul;
+use num::traits::{Num, One, Zero};
+
+///Square matrix of size `SIZE` whose coefficients outside the diagonal are nil, only the diagonal being stored.
+///
+///Multiplying a matrix by a diagonal one scales its rows or columns, which takes `SIZE` times fewer operations than a dense product.
+///
+/// # Example
+///
+/// ```
+///# use matrix::{Diagonal, Matrix};
+/// let scaling = Diagonal::new([2, 3]);
+/// let a = Matrix::from([[1, 1, 1], [1, 2, 3]]);
+/// assert_eq!(&scaling * &a, Matrix::from([[2, 2, 2], [3, 6, 9]]));
+/// assert_eq!(&scaling * &a, &scaling.to_matrix() * &a);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Diagonal<C, const SIZE: usize> {
+    diagonal: [C; SIZE],
+}
+
+impl<C, const SIZE: usize> Diagonal<C, SIZE> {
+    ///Returns the diagonal matrix whose diagonal is `diagonal`.
+    pub fn new(diagonal: [C; SIZE]) -> Self {
+        Diagonal { diagonal }
+    }
+
+    ///Returns the coefficients of the diagonal.
+    pub fn diagonal(&self) -> &[C; SIZE] {
+        &self.diagonal
+    }
+
+    ///Returns the diagonal, consuming the matrix.
+    pub fn into_inner(self) -> [C; SIZE] {
+        self.diagonal
+    }
+}
+
+impl<C, const SIZE: usize> Diagonal<C, SIZE>
+where
+    C: Zero + One,
+{
+    ///Returns the identity matrix of size `SIZE`.
+    pub fn identity() -> Self {
+        Diagonal::new(core::array::from_fn(|_| C::one()))
+    }
+}
+
+impl<C, const SIZE: usize> Diagonal<C, SIZE>
+where
+    C: Clone,
+{
+    ///Returns the dense matrix, see [`Matrix::from_diagonal`].
+    pub fn to_matrix(&self) -> Matrix<C, SIZE, SIZE>
+    where
+        C: Zero,
+    {
+        Matrix::from_diagonal(self.diagonal.clone())
+    }
+
+    ///Returns the determinant of the matrix, that is the product of its diagonal.
+    pub fn determinant(&self) -> C
+    where
+        C: Product,
+    {
+        self.diagonal.iter().cloned().product()
+    }
+}
+
+impl<C, const SIZE: usize> Diagonal<C, SIZE>
+where
+    C: Num + Clone,
+{
+    ///Returns the inverse of the matrix, whose diagonal is made of the inverses of the coefficients.
+    ///
+    ///Returns Singular if a coefficient is nil.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Diagonal;
+    /// assert_eq!(Diagonal::new([2.0, 4.0]).inverse(), Ok(Diagonal::new([0.5, 0.25])));
+    /// assert!(Diagonal::new([2.0, 0.0]).inverse().is_err());
+    /// ```
+    pub fn inverse(&self) -> Result<Self, Error> {
+        if self.diagonal.iter().any(C::is_zero) {
+            return Err(Error::Singular);
+        }
+        Ok(Diagonal::new(self.diagonal.clone().map(|c| C::one() / c)))
+    }
+}
+
+impl<C, const SIZE: usize> From<Diagonal<C, SIZE>> for Matrix<C, SIZE, SIZE>
+where
+    C: Clone + Zero,
+{
+    fn from(diagonal: Diagonal<C, SIZE>) -> Self {
+        diagonal.to_matrix()
+    }
+}
+
+///Product of diagonal matrices, which is the product of their diagonals.
+impl<C, const SIZE: usize> Mul for &Diagonal<C, SIZE>
+where
+    C: Mul<Output = C> + Clone,
+{
+    type Output = Diagonal<C, SIZE>;
+
+    fn mul(self, other: Self) -> Self::Output {
+        Diagonal::new(core::array::from_fn(|i| {
+            self.diagonal[i].clone() * other.diagonal[i].clone()
+        }))
+    }
+}
+
+///Scaling of the rows of `matrix`, row `i` being multiplied by the `i`-th coefficient of the diagonal.
+impl<C, const ROWS: usize, const COLS: usize> Mul<&Matrix<C, ROWS, COLS>> for &Diagonal<C, ROWS>
+where
+    C: Mul<Output = C> + Clone,
+{
+    type Output = Matrix<C, ROWS, COLS>;
+
+    fn mul(self, matrix: &Matrix<C, ROWS, COLS>) -> Self::Output {
+        Matrix::from_fn(|row, col| self.diagonal[row].clone() * matrix.data[row][col].clone())
+    }
+}
+
+///Scaling of the columns of `self`, column `j` being multiplied by the `j`-th coefficient of the diagonal.
+///
+/// # Example
+///
+/// ```
+///# use matrix::{Diagonal, Matrix};
+/// let a = Matrix::from([[1, 1, 1], [1, 2, 3]]);
+/// assert_eq!(&a * &Diagonal::new([1, 0, -1]), Matrix::from([[1, 0, -1], [1, 0, -3]]));
+/// ```
+impl<C, const ROWS: usize, const COLS: usize> Mul<&Diagonal<C, COLS>> for &Matrix<C, ROWS, COLS>
+where
+    C: Mul<Output = C> + Clone,
+{
+    type Output = Matrix<C, ROWS, COLS>;
+
+    fn mul(self, diagonal: &Diagonal<C, COLS>) -> Self::Output {
+        Matrix::from_fn(|row, col| self.data[row][col].clone() * diagonal.diagonal[col].clone())
+    }
+}
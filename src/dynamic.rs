@@ -0,0 +1,432 @@
+//! Heap-allocated matrices whose dimensions are only known at runtime.
+
+use crate::{Error, Matrix};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub};
+use num::traits::{One, Signed, Zero};
+
+///Matrix whose dimensions are chosen at runtime, its coefficients are stored row after row on the heap.
+///
+///Operations mirror the ones of [`Matrix`], but dimension mismatches can only be detected at runtime:
+///the arithmetic operators return a `Result` which is DimensionMismatch if the operands don't fit together.
+///For the same reason the compound assignment operators such as `AddAssign`, which can't return an error, are left out:
+///use `a = (&a + &b)?` instead.
+///
+/// # Example
+///
+/// ```
+///# use matrix::{DynMatrix, Matrix};
+///# use std::convert::TryFrom;
+/// let a = DynMatrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+/// let b = DynMatrix::from(Matrix::from([[1], [0], [-1]]));
+/// let product = (&a * &b).unwrap();
+/// assert_eq!(Matrix::try_from(product), Ok(Matrix::from([[-2], [-2]])));
+/// ```
+///
+/// [`Matrix`]: struct.Matrix.html
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DynMatrix<C> {
+    rows: usize,
+    cols: usize,
+    data: Vec<C>,
+}
+
+impl<C> DynMatrix<C> {
+    ///Builds a matrix of `rows` rows and `cols` columns from its coefficients in row-major order.
+    ///
+    ///Returns WrongLength if there aren't exactly `rows * cols` coefficients.
+    pub fn new(rows: usize, cols: usize, data: Vec<C>) -> Result<Self, Error> {
+        if rows.checked_mul(cols) != Some(data.len()) {
+            return Err(Error::WrongLength);
+        }
+        Ok(DynMatrix { rows, cols, data })
+    }
+
+    ///Builds a matrix from its rows, which must all have the same length.
+    ///
+    ///Returns DimensionMismatch if the rows have different lengths, like the conversion from `Vec<Vec<C>>` to [`Matrix`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::DynMatrix;
+    /// let mat = DynMatrix::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+    /// assert_eq!((mat.rows(), mat.cols()), (3, 2));
+    /// assert!(DynMatrix::from_rows(vec![vec![1, 2], vec![3]]).is_err());
+    /// ```
+    ///
+    /// [`Matrix`]: struct.Matrix.html
+    pub fn from_rows(rows: Vec<Vec<C>>) -> Result<Self, Error> {
+        let cols = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != cols) {
+            return Err(Error::DimensionMismatch);
+        }
+        Ok(DynMatrix {
+            rows: rows.len(),
+            cols,
+            data: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    ///Builds a matrix whose coefficients are the results of `f(row, col)`, like [`Matrix::from_fn`].
+    ///
+    /// [`Matrix::from_fn`]: struct.Matrix.html#method.from_fn
+    pub fn from_fn<F>(rows: usize, cols: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> C,
+    {
+        DynMatrix {
+            rows,
+            cols,
+            data: (0..rows * cols).map(|i| f(i / cols, i % cols)).collect(),
+        }
+    }
+
+    ///Returns the nil matrix of the given dimensions.
+    pub fn nil(rows: usize, cols: usize) -> Self
+    where
+        C: Zero,
+    {
+        Self::from_fn(rows, cols, |_, _| C::zero())
+    }
+
+    ///Returns the identity matrix of the given size.
+    pub fn identity(size: usize) -> Self
+    where
+        C: Zero + One,
+    {
+        Self::from_fn(
+            size,
+            size,
+            |row, col| if row == col { C::one() } else { C::zero() },
+        )
+    }
+
+    ///Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    ///Returns the number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    ///Returns a reference to the coefficient at `(row, col)`, or None if it is out of bounds.
+    pub fn get(&self, row: usize, col: usize) -> Option<&C> {
+        if row < self.rows && col < self.cols {
+            self.data.get(row * self.cols + col)
+        } else {
+            None
+        }
+    }
+
+    ///Returns a mutable reference to the coefficient at `(row, col)`, or None if it is out of bounds.
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut C> {
+        if row < self.rows && col < self.cols {
+            self.data.get_mut(row * self.cols + col)
+        } else {
+            None
+        }
+    }
+
+    ///Returns a row, or None if it is out of bounds.
+    pub fn get_line(&self, row: usize) -> Option<&[C]> {
+        if row < self.rows {
+            Some(&self.data[row * self.cols..(row + 1) * self.cols])
+        } else {
+            None
+        }
+    }
+
+    ///Returns an iterator over the coefficients in row-major order.
+    pub fn iter(&self) -> core::slice::Iter<'_, C> {
+        self.data.iter()
+    }
+
+    ///Returns the transpose of the matrix.
+    pub fn transpose(&self) -> Self
+    where
+        C: Clone,
+    {
+        Self::from_fn(self.cols, self.rows, |row, col| {
+            self.data[col * self.cols + row].clone()
+        })
+    }
+
+    /// Permutes two rows.
+    ///
+    /// Returns OutOfBounds if either `source` of `target` is out of bounds.
+    pub fn permute(&mut self, source: usize, target: usize) -> Result<(), Error> {
+        if source >= self.rows || target >= self.rows {
+            return Err(Error::OutOfBounds);
+        }
+        for col in 0..self.cols {
+            self.data
+                .swap(source * self.cols + col, target * self.cols + col);
+        }
+        Ok(())
+    }
+
+    /// Dilates a row, that is multiplies all its coefficients by `factor`.
+    ///
+    /// Returns OutOfBounds if `row` is out of bounds.
+    pub fn dilate(&mut self, row: usize, factor: &C) -> Result<(), Error>
+    where
+        for<'a> C: MulAssign<&'a C>,
+    {
+        if row >= self.rows {
+            return Err(Error::OutOfBounds);
+        }
+        self.data[row * self.cols..(row + 1) * self.cols]
+            .iter_mut()
+            .for_each(|c| *c *= factor);
+        Ok(())
+    }
+
+    /// Adds the `other` row to the `source` row, like [`Matrix::transvect`].
+    ///
+    /// Returns OutOfBounds if either row is out of bounds and WrongOperation if they are the same row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::DynMatrix;
+    /// let mut mat = DynMatrix::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// mat.transvect(0, 1).unwrap();
+    /// assert_eq!(mat.get_line(0), Some(&[4, 6][..]));
+    ///```
+    ///
+    /// [`Matrix::transvect`]: struct.Matrix.html#method.transvect
+    pub fn transvect(&mut self, source: usize, other: usize) -> Result<(), Error>
+    where
+        C: Clone + AddAssign,
+    {
+        if source >= self.rows || other >= self.rows {
+            return Err(Error::OutOfBounds);
+        } else if source == other {
+            return Err(Error::WrongOperation);
+        }
+        for col in 0..self.cols {
+            let value = self.data[other * self.cols + col].clone();
+            self.data[source * self.cols + col] += value;
+        }
+        Ok(())
+    }
+
+    /// Adds `factor` times the `other` row to the `source` row.
+    ///
+    /// Returns OutOfBounds if either row is out of bounds and WrongOperation if they are the same row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::DynMatrix;
+    /// let mut mat = DynMatrix::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// mat.transvect_scaled(1, 0, &-3).unwrap();
+    /// assert_eq!(mat.get_line(1), Some(&[0, -2][..]));
+    ///```
+    pub fn transvect_scaled(&mut self, source: usize, other: usize, factor: &C) -> Result<(), Error>
+    where
+        C: Clone + Mul<Output = C> + AddAssign,
+    {
+        if source >= self.rows || other >= self.rows {
+            return Err(Error::OutOfBounds);
+        } else if source == other {
+            return Err(Error::WrongOperation);
+        }
+        for col in 0..self.cols {
+            let value = factor.clone() * self.data[other * self.cols + col].clone();
+            self.data[source * self.cols + col] += value;
+        }
+        Ok(())
+    }
+
+    //Returns an error unless both matrices have the same dimensions.
+    fn check_same_dimensions<D>(&self, other: &DynMatrix<D>) -> Result<(), Error> {
+        if (self.rows, self.cols) == (other.rows, other.cols) {
+            Ok(())
+        } else {
+            Err(Error::DimensionMismatch)
+        }
+    }
+}
+
+impl<C> DynMatrix<C>
+where
+    C: Signed + PartialOrd + Clone,
+{
+    /// Solves the linear system `self * x = b`, `b` being a column vector.
+    ///
+    /// The system is solved through gaussian elimination with partial pivoting, like [`Matrix::solve`].
+    /// Returns DimensionMismatch if the matrix isn't square or `b` doesn't have as many rows as it,
+    /// and Singular if the matrix has no inverse.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::DynMatrix;
+    /// let a = DynMatrix::new(2, 2, vec![2.0, 1.0, 1.0, 3.0]).unwrap();
+    /// let b = DynMatrix::new(2, 1, vec![5.0, 10.0]).unwrap();
+    /// assert_eq!(a.solve(&b), DynMatrix::new(2, 1, vec![1.0, 3.0]));
+    ///```
+    ///
+    /// [`Matrix::solve`]: struct.Matrix.html#method.solve
+    pub fn solve(&self, b: &Self) -> Result<Self, Error> {
+        let size = self.rows;
+        if self.cols != size || b.rows != size || b.cols != 1 {
+            return Err(Error::DimensionMismatch);
+        }
+        let mut a = self.clone();
+        let mut x = b.clone();
+        for col in 0..size {
+            let pivot = (col..size)
+                .max_by(|&i, &j| {
+                    a[(i, col)]
+                        .abs()
+                        .partial_cmp(&a[(j, col)].abs())
+                        .unwrap_or(core::cmp::Ordering::Equal)
+                })
+                .unwrap_or(col);
+            if a[(pivot, col)].is_zero() {
+                return Err(Error::Singular);
+            }
+            a.permute(col, pivot)?;
+            x.permute(col, pivot)?;
+            for row in col + 1..size {
+                let factor = a[(row, col)].clone() / a[(col, col)].clone();
+                for k in col..size {
+                    a[(row, k)] = a[(row, k)].clone() - factor.clone() * a[(col, k)].clone();
+                }
+                x[(row, 0)] = x[(row, 0)].clone() - factor * x[(col, 0)].clone();
+            }
+        }
+        for row in (0..size).rev() {
+            let acc = (row + 1..size).fold(x[(row, 0)].clone(), |acc, k| {
+                acc - a[(row, k)].clone() * x[(k, 0)].clone()
+            });
+            x[(row, 0)] = acc / a[(row, row)].clone();
+        }
+        Ok(x)
+    }
+}
+
+impl<C, const ROWS: usize, const COLS: usize> From<Matrix<C, ROWS, COLS>> for DynMatrix<C> {
+    fn from(matrix: Matrix<C, ROWS, COLS>) -> Self {
+        DynMatrix {
+            rows: ROWS,
+            cols: COLS,
+            data: matrix.into_iter().collect(),
+        }
+    }
+}
+
+///Conversion to a matrix of fixed dimensions, which fails with DimensionMismatch if the dimensions differ.
+impl<C, const ROWS: usize, const COLS: usize> TryFrom<DynMatrix<C>> for Matrix<C, ROWS, COLS> {
+    type Error = Error;
+    fn try_from(matrix: DynMatrix<C>) -> Result<Self, Error> {
+        if (matrix.rows, matrix.cols) != (ROWS, COLS) {
+            return Err(Error::DimensionMismatch);
+        }
+        Matrix::try_from_iter(matrix.data)
+    }
+}
+
+impl<C> Index<(usize, usize)> for DynMatrix<C> {
+    type Output = C;
+    fn index(&self, (row, col): (usize, usize)) -> &C {
+        self.get(row, col).expect("index out of bounds")
+    }
+}
+
+impl<C> IndexMut<(usize, usize)> for DynMatrix<C> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut C {
+        self.get_mut(row, col).expect("index out of bounds")
+    }
+}
+
+///Matrix addition, which fails with DimensionMismatch if the dimensions differ.
+impl<C> Add<&DynMatrix<C>> for &DynMatrix<C>
+where
+    C: Add<Output = C> + Clone,
+{
+    type Output = Result<DynMatrix<C>, Error>;
+    fn add(self, other: &DynMatrix<C>) -> Self::Output {
+        self.check_same_dimensions(other)?;
+        Ok(DynMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self
+                .iter()
+                .zip(other.iter())
+                .map(|(a, b)| a.clone() + b.clone())
+                .collect(),
+        })
+    }
+}
+
+///Matrix subtraction, which fails with DimensionMismatch if the dimensions differ.
+impl<C> Sub<&DynMatrix<C>> for &DynMatrix<C>
+where
+    C: Sub<Output = C> + Clone,
+{
+    type Output = Result<DynMatrix<C>, Error>;
+    fn sub(self, other: &DynMatrix<C>) -> Self::Output {
+        self.check_same_dimensions(other)?;
+        Ok(DynMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self
+                .iter()
+                .zip(other.iter())
+                .map(|(a, b)| a.clone() - b.clone())
+                .collect(),
+        })
+    }
+}
+
+///Matrix product, which fails with DimensionMismatch if `self` doesn't have as many columns as `other` has rows.
+impl<C> Mul<&DynMatrix<C>> for &DynMatrix<C>
+where
+    C: Mul<Output = C> + Sum + Clone,
+{
+    type Output = Result<DynMatrix<C>, Error>;
+    fn mul(self, other: &DynMatrix<C>) -> Self::Output {
+        if self.cols != other.rows {
+            return Err(Error::DimensionMismatch);
+        }
+        Ok(DynMatrix::from_fn(self.rows, other.cols, |row, col| {
+            (0..self.cols)
+                .map(|k| self[(row, k)].clone() * other[(k, col)].clone())
+                .sum()
+        }))
+    }
+}
+
+///Multiplication by a scalar.
+impl<C> Mul<C> for DynMatrix<C>
+where
+    for<'a> C: MulAssign<&'a C>,
+{
+    type Output = Self;
+    fn mul(mut self, scalar: C) -> Self {
+        self.data.iter_mut().for_each(|c| *c *= &scalar);
+        self
+    }
+}
+
+impl<C> Neg for DynMatrix<C>
+where
+    C: Neg<Output = C>,
+{
+    type Output = Self;
+    fn neg(self) -> Self {
+        DynMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.into_iter().map(Neg::neg).collect(),
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! Single eigenpairs of floating point matrices found by power iteration, much cheaper than a full eigendecomposition.
+
+use crate::{ColVector, Error, Matrix};
+use core::iter::Sum;
+use num::traits::Float;
+
+impl<C, const SIZE: usize> Matrix<C, SIZE, SIZE>
+where
+    C: Float + Sum,
+{
+    ///Returns the eigenvalue of largest absolute value together with a unit eigenvector, found by power iteration.
+    ///
+    ///Starting from a fixed vector without any symmetry, the vector is repeatedly multiplied by the matrix and normalized,
+    ///the eigenvalue being estimated by the Rayleigh quotient. The iteration stops once `‖Av - λv‖` is no bigger than `tolerance`,
+    ///and returns NoConvergence if this doesn't happen within `max_iters` products.
+    ///The convergence rate is the ratio of the two largest eigenvalues in absolute value, so it fails if they are equal,
+    ///for instance with a pair of complex eigenvalues. Returns NilVector for empty matrices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let a = Matrix::from([[2.0f64, 1.0], [1.0, 2.0]]);
+    /// let (lambda, v) = a.dominant_eigenpair(100, 1e-12).unwrap();
+    /// assert!((lambda - 3.0).abs() < 1e-12);
+    /// assert!((&a * &v).approx_eq(&(v.clone() * 3.0), 1e-12));
+    /// ```
+    pub fn dominant_eigenpair(
+        &self,
+        max_iters: usize,
+        tolerance: C,
+    ) -> Result<(C, ColVector<C, SIZE>), Error> {
+        self.power_iteration(|_, av| av, max_iters, tolerance)
+    }
+
+    ///Returns the eigenvalue closest to `shift` together with a unit eigenvector, found by shifted inverse iteration.
+    ///
+    ///This is power iteration on `(A - shift * I)⁻¹`, whose dominant eigenvalue comes from the eigenvalue of the matrix closest to `shift`,
+    ///so it converges quickly when `shift` is a good estimate. See [`dominant_eigenpair`] for the stopping criterion.
+    ///Returns Singular if `shift` is exactly an eigenvalue.
+    ///
+    /// [`dominant_eigenpair`]: #method.dominant_eigenpair
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let a = Matrix::from([[4.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 2.0]]);
+    /// let (lambda, v) = a.eigenpair_near(1.0, 100, 1e-12).unwrap();
+    /// assert!((lambda - (3.0 - 3f64.sqrt())).abs() < 1e-12);
+    /// assert!((&a * &v).approx_eq(&(v.clone() * lambda), 1e-12));
+    /// ```
+    pub fn eigenpair_near(
+        &self,
+        shift: C,
+        max_iters: usize,
+        tolerance: C,
+    ) -> Result<(C, ColVector<C, SIZE>), Error> {
+        let shifted = Self::from_fn(|row, col| {
+            if row == col {
+                self.data[row][col] - shift
+            } else {
+                self.data[row][col]
+            }
+        });
+        let inverse = shifted.inverse_by(|c| c.abs())?;
+        self.power_iteration(
+            |v: &ColVector<C, SIZE>, _| &inverse * v,
+            max_iters,
+            tolerance,
+        )
+    }
+
+    //Iterates `v ← step(v, Av)` normalized until `v` is an eigenvector of the matrix up to `tolerance`.
+    fn power_iteration<F>(
+        &self,
+        mut step: F,
+        max_iters: usize,
+        tolerance: C,
+    ) -> Result<(C, ColVector<C, SIZE>), Error>
+    where
+        F: FnMut(&ColVector<C, SIZE>, ColVector<C, SIZE>) -> ColVector<C, SIZE>,
+    {
+        //a start such as the vector of ones is an eigenvector of many matrices, like the ones whose rows all have the same sum,
+        //so the coefficients follow the fractional parts of the multiples of the golden ratio instead
+        let two = C::one() + C::one();
+        let golden = ((two + two + C::one()).sqrt() - C::one()) / two;
+        let mut fraction = C::zero();
+        let mut v = ColVector::from_fn(|_, _| {
+            fraction = (fraction + golden).fract();
+            C::one() + fraction
+        })
+        .normalized()?;
+        for _ in 0..max_iters {
+            let av = self * &v;
+            let lambda = v.dot(&av);
+            if av.zip_map(&v, |a, b| *a - *b * lambda).norm() <= tolerance {
+                return Ok((lambda, v));
+            }
+            v = step(&v, av).normalized()?;
+        }
+        Err(Error::NoConvergence)
+    }
+}
@@ -0,0 +1,102 @@
+//! Linear algebra over the fields which aren't ordered, such as complex numbers and integers modulo a prime.
+//!
+//! The linear algebra of [`Matrix`] needs [`Signed`](num::traits::Signed) coefficients to choose its pivots by absolute value,
+//! which only makes sense for ordered fields. Other fields tell how to choose pivots through the [`Pivot`] trait
+//! and get the same methods from the [`FieldLinearAlgebra`] trait.
+
+use crate::{Error, Matrix};
+use core::ops::Neg;
+use num::traits::Num;
+
+///Coefficients of a field which isn't ordered, together with the magnitude choosing the pivots of the elimination.
+///
+///The remaining coefficient of biggest magnitude is chosen as pivot, only nil coefficients being unsuitable.
+///For inexact coefficients such as complex floats the magnitude should grow with the modulus to keep the elimination stable,
+///for exact ones any non-nil coefficient is suitable.
+pub trait Pivot {
+    ///Type of the magnitude, ordered so that the biggest one can be found.
+    type Magnitude: PartialOrd;
+
+    ///Returns the magnitude of the coefficient, used to choose the pivots.
+    fn magnitude(&self) -> Self::Magnitude;
+}
+
+///Linear algebra for square matrices over a field whose coefficients implement [`Pivot`].
+///
+///These are the methods of the same name of real matrices, the pivot of each column being the coefficient with the biggest magnitude.
+///The trait needs to be in scope to call them.
+///
+/// # Example
+///
+/// ```
+///# use matrix::Matrix;
+/// use matrix::{FieldLinearAlgebra, Mod};
+/// use num::Complex;
+/// let i = Complex::i();
+/// let a = Matrix::from([[i, Complex::new(1.0, 0.0)], [Complex::new(2.0, 0.0), -i]]);
+/// assert_eq!(a.determinant(), Complex::new(-1.0, 0.0));
+/// assert_eq!(&a * &a.inverse().unwrap(), Matrix::identity());
+///
+/// let b = Matrix::from([[2, 3], [1, 4]]).map(Mod::<7>::new);
+/// assert_eq!(b.determinant(), Mod::new(5));
+/// assert_eq!(b.rank(), 2);
+/// ```
+pub trait FieldLinearAlgebra: Sized {
+    ///Type of the coefficients of the matrix.
+    type Coefficient;
+    ///Type of the column vectors the matrix can be multiplied with.
+    type Vector;
+
+    ///Returns the determinant of the matrix, see [`Matrix::determinant`].
+    fn determinant(&self) -> Self::Coefficient;
+
+    ///Solves the linear system `self * x = b`, see [`Matrix::solve`].
+    ///
+    ///Returns Singular if the matrix has no inverse.
+    fn solve(&self, b: Self::Vector) -> Result<Self::Vector, Error>;
+
+    ///Returns the inverse of the matrix, see [`Matrix::inverse`].
+    ///
+    ///Returns Singular if the matrix has no inverse.
+    fn inverse(&self) -> Result<Self, Error>;
+
+    ///Returns the reduced row echelon form of the matrix, see [`Matrix::rref`].
+    fn rref(&self) -> Self;
+
+    ///Returns the rank of the matrix, see [`Matrix::rank`].
+    fn rank(&self) -> usize;
+}
+
+impl<C, const SIZE: usize> FieldLinearAlgebra for Matrix<C, SIZE, SIZE>
+where
+    C: Pivot + Num + Neg<Output = C> + Clone,
+{
+    type Coefficient = C;
+    type Vector = Matrix<C, SIZE, 1>;
+
+    fn determinant(&self) -> C {
+        self.determinant_by(C::magnitude)
+    }
+
+    fn solve(&self, b: Self::Vector) -> Result<Self::Vector, Error> {
+        self.solve_by(b, C::magnitude)
+    }
+
+    fn inverse(&self) -> Result<Self, Error> {
+        self.inverse_by(C::magnitude)
+    }
+
+    fn rref(&self) -> Self {
+        let mut m = self.clone();
+        m.reduce_pivots_by(C::is_zero, C::magnitude);
+        m
+    }
+
+    fn rank(&self) -> usize {
+        self.clone()
+            .reduce_pivots_by(C::is_zero, C::magnitude)
+            .iter()
+            .filter(|&&pivot| pivot)
+            .count()
+    }
+}
@@ -0,0 +1,210 @@
+//! Matrix functions, that is the extension of scalar functions such as the exponential, square root and logarithm to square matrices.
+
+use crate::{Error, Matrix};
+use core::iter::Sum;
+use num::traits::Float;
+
+//Degree of the Padé approximant, 6 is enough for double precision once the matrix is scaled down.
+const PADE_DEGREE: i32 = 6;
+
+//Bound on the number of square roots taken by the logarithm, each one halving the logarithm.
+const MAX_SQUARE_ROOTS: usize = 64;
+
+//Bound on the number of Denman-Beavers iterations and of terms of the logarithm series, a handful is enough in practice.
+const MAX_ITERATIONS: usize = 100;
+
+impl<C, const SIZE: usize> Matrix<C, SIZE, SIZE>
+where
+    C: Float + Sum,
+{
+    ///Returns the exponential of the matrix.
+    ///
+    ///This is computed by scaling and squaring: the matrix is divided by a power of two until its norm is small enough
+    ///for a diagonal Padé approximant to be accurate and the result is then squared back.
+    ///The exponential of a matrix with an infinite or NaN coefficient is a matrix of NaN,
+    ///while the one of a huge matrix may overflow to non-finite coefficients.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1.0, 0.0], [0.0, 2.0]]);
+    /// let exp = mat.expm();
+    /// assert!((exp.get(0, 0).unwrap() - 1f64.exp()).abs() < 1e-12);
+    /// assert!((exp.get(1, 1).unwrap() - 2f64.exp()).abs() < 1e-12);
+    /// ```
+    pub fn expm(&self) -> Self {
+        let norm = self.norm_inf();
+        if !norm.is_finite() {
+            return Self::from_fn(|_, _| C::nan());
+        }
+        let int = |n: i32| C::from(n).expect("small integers are representable by floats");
+        //power of two bringing the norm below 1/2, the norm being finite its exponent fits in an `i32`
+        let squarings = match norm.log2().floor().to_i32() {
+            Some(exponent) if norm > int(2).recip() => exponent.saturating_add(2),
+            _ => 0,
+        };
+        //scaled in two steps since `2^-squarings` may not be representable when the norm is close to the biggest float
+        let half = squarings / 2;
+        let a = self.clone() * int(2).powi(-half) * int(2).powi(half - squarings);
+
+        let identity = Self::identity();
+        let mut c = C::one();
+        let mut x = identity.clone();
+        let mut numerator = identity.clone();
+        let mut denominator = identity;
+        for k in 1..=PADE_DEGREE {
+            c = c * int(PADE_DEGREE - k + 1) / int(k * (2 * PADE_DEGREE - k + 1));
+            x = &a * &x;
+            let sign = if k % 2 == 0 { c } else { -c };
+            numerator = numerator.zip_map(&x, |n, x| *n + *x * c);
+            denominator = denominator.zip_map(&x, |d, x| *d + *x * sign);
+        }
+
+        //the approximant is denominator⁻¹ * numerator, solved one column at a time
+        let mut result = numerator.clone();
+        for (col, target) in numerator.get_columns().zip(result.get_mut_columns()) {
+            let b = Matrix::from(col.map(|c| [*c]));
+            let solution = denominator
+                .solve_by(b, |c| c.abs())
+                .expect("the Padé denominator of a scaled matrix is never singular");
+            for (t, s) in IntoIterator::into_iter(target).zip(solution.data.iter()) {
+                *t = s[0];
+            }
+        }
+
+        for _ in 0..squarings {
+            result = &result * &result;
+        }
+        result
+    }
+}
+
+impl<C, const SIZE: usize> Matrix<C, SIZE, SIZE>
+where
+    C: Float,
+{
+    ///Returns the square root of a symmetric positive semi-definite matrix,
+    ///that is the only symmetric positive semi-definite `S` such that `S * S = self`.
+    ///
+    ///The root is computed from the eigendecomposition `A = VΛVᵀ` as `V√ΛVᵀ`, only the symmetric part `(A + Aᵀ)/2` of the matrix being used.
+    ///Eigenvalues no smaller than `-SIZE * ε * ‖A‖` are considered nil to allow for rounding errors,
+    ///more negative ones make this return NotPositiveDefinite.
+    ///The inverse of the root of a covariance matrix is the whitening transform of the data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let a = Matrix::from([[5.0, 4.0], [4.0, 5.0]]);
+    /// let root = a.sqrtm().unwrap();
+    /// assert!(root.approx_eq(&Matrix::from([[2.0, 1.0], [1.0, 2.0]]), 1e-12));
+    /// ```
+    pub fn sqrtm(&self) -> Result<Self, Error> {
+        let two = C::one() + C::one();
+        let symmetric = Self::from_fn(|row, col| (self.data[row][col] + self.data[col][row]) / two);
+        //for a symmetric matrix the right singular vectors are eigenvectors, `W = AV` holding the eigenvalues times them
+        let (w, v) = symmetric.jacobi_svd();
+        let eigenvalues: [C; SIZE] = core::array::from_fn(|k| {
+            (0..SIZE).fold(C::zero(), |acc, row| acc + v.data[row][k] * w.data[row][k])
+        });
+        let largest = eigenvalues
+            .iter()
+            .fold(C::zero(), |acc, l| acc.max(l.abs()));
+        let tolerance = C::from(SIZE).unwrap_or_else(C::infinity) * C::epsilon() * largest;
+        if eigenvalues.iter().any(|&l| l < -tolerance) {
+            return Err(Error::NotPositiveDefinite);
+        }
+        let roots = eigenvalues.map(|l| l.max(C::zero()).sqrt());
+        Ok(Self::from_fn(|row, col| {
+            (0..SIZE).fold(C::zero(), |acc, k| {
+                acc + v.data[row][k] * v.data[col][k] * roots[k]
+            })
+        }))
+    }
+}
+
+impl<C, const SIZE: usize> Matrix<C, SIZE, SIZE>
+where
+    C: Float + Sum,
+{
+    ///Returns the principal logarithm of the matrix, that is the only `L` whose eigenvalues have an imaginary part in `]-π, π[`
+    ///such that `L.expm() == self`.
+    ///
+    ///This is computed by inverse scaling and squaring: square roots are taken until the matrix is close to the identity,
+    ///where the logarithm is given by a quickly converging series, and the result is then multiplied back by two for each root.
+    ///The logarithm is only real if no eigenvalue is real and negative, this is the case for matrices whose eigenvalues are positive.
+    ///Returns Singular if the matrix is singular and NoConvergence if a coefficient is infinite or NaN.
+    ///A negative eigenvalue makes the square roots diverge, which returns NoConvergence or Singular if an iterate happens to be singular.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let a = Matrix::from([[1.0, 0.5], [-0.5, 2.0]]);
+    /// assert!(a.logm().unwrap().expm().approx_eq(&a, 1e-12));
+    ///
+    /// // interpolation between transformations: the rotation by a quarter turn is the square of the rotation by an eighth
+    /// let quarter = Matrix::from([[0.0, -1.0], [1.0, 0.0]]);
+    /// let eighth = (quarter.logm().unwrap() * 0.5).expm();
+    /// assert!((&eighth * &eighth).approx_eq(&quarter, 1e-12));
+    /// ```
+    pub fn logm(&self) -> Result<Self, Error> {
+        //NaN would skip the square roots and never meet the stopping criterion of the series
+        if self.iter().any(|c| !c.is_finite()) {
+            return Err(Error::NoConvergence);
+        }
+        let identity = Self::identity();
+        let quarter = (C::one() + C::one()).powi(-2);
+        let mut a = self.clone();
+        let mut roots = 0;
+        while a.zip_map(&identity, |a, i| *a - *i).norm_inf() > quarter {
+            if roots == MAX_SQUARE_ROOTS {
+                return Err(Error::NoConvergence);
+            }
+            a = a.denman_beavers()?;
+            roots += 1;
+        }
+
+        //log(A) = 2 atanh(Z) = 2(Z + Z³/3 + Z⁵/5 + …) with Z = (A - I)(A + I)⁻¹, whose norm is here below 1/7
+        let numerator = a.zip_map(&identity, |a, i| *a - *i);
+        let denominator = a.zip_map(&identity, |a, i| *a + *i);
+        let z = &numerator * &denominator.inverse_by(|c| c.abs())?;
+        let z_squared = &z * &z;
+        let mut power = z;
+        let mut sum = Self::nil();
+        let mut k = C::one();
+        for _ in 0..MAX_ITERATIONS {
+            let term = power.clone().map(|c| c / k);
+            sum = sum.zip_map(&term, |s, t| *s + *t);
+            if term.norm_inf() <= C::epsilon() * sum.norm_inf() {
+                let scale = (C::one() + C::one()).powi(roots as i32 + 1);
+                return Ok(sum.map(|c| c * scale));
+            }
+            power = &power * &z_squared;
+            k = k + C::one() + C::one();
+        }
+        Err(Error::NoConvergence)
+    }
+
+    //Returns the principal square root of the matrix computed by the Denman-Beavers iteration,
+    //`Y ← (Y + Z⁻¹)/2` and `Z ← (Z + Y⁻¹)/2` from `Y = A` and `Z = I`, `Y` converging to the root and `Z` to its inverse.
+    fn denman_beavers(&self) -> Result<Self, Error> {
+        let half = (C::one() + C::one()).recip();
+        let mut y = self.clone();
+        let mut z = Self::identity();
+        for _ in 0..MAX_ITERATIONS {
+            let y_inverse = y.inverse_by(|c| c.abs())?;
+            let z_inverse = z.inverse_by(|c| c.abs())?;
+            let next = y.zip_map(&z_inverse, |y, z| (*y + *z) * half);
+            z = z.zip_map(&y_inverse, |z, y| (*z + *y) * half);
+            //convergence is quadratic, so the error of `next` is about the square of the change
+            let change = next.zip_map(&y, |a, b| *a - *b).norm_inf();
+            y = next;
+            if change <= C::epsilon().sqrt() * y.norm_inf() {
+                return Ok(y);
+            }
+        }
+        Err(Error::NoConvergence)
+    }
+}
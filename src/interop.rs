@@ -0,0 +1,108 @@
+//! Conversions to and from the types of other linear algebra crates, each behind the feature named after the crate.
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impls {
+    use crate::Matrix;
+    use nalgebra::{SMatrix, Scalar};
+
+    ///Conversion to a `nalgebra` matrix, column and row vectors becoming `SVector` and `RowSVector`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+    /// let converted: nalgebra::SMatrix<i32, 2, 3> = mat.into();
+    /// assert_eq!(converted, nalgebra::Matrix2x3::new(1, 2, 3, 4, 5, 6));
+    /// ```
+    impl<C, const ROWS: usize, const COLS: usize> From<Matrix<C, ROWS, COLS>> for SMatrix<C, ROWS, COLS>
+    where
+        C: Scalar,
+    {
+        fn from(matrix: Matrix<C, ROWS, COLS>) -> Self {
+            SMatrix::from_fn(|row, col| matrix.data[row][col].clone())
+        }
+    }
+
+    ///Conversion from a `nalgebra` matrix, `SVector` and `RowSVector` becoming column and row vectors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{ColVector, Matrix};
+    /// let v = nalgebra::SVector::<f64, 3>::new(1.0, 2.0, 3.0);
+    /// assert_eq!(ColVector::from(v), Matrix::from([[1.0], [2.0], [3.0]]));
+    /// ```
+    impl<C, const ROWS: usize, const COLS: usize> From<SMatrix<C, ROWS, COLS>> for Matrix<C, ROWS, COLS>
+    where
+        C: Scalar,
+    {
+        fn from(matrix: SMatrix<C, ROWS, COLS>) -> Self {
+            Matrix::from_fn(|row, col| matrix[(row, col)].clone())
+        }
+    }
+}
+
+#[cfg(feature = "ndarray")]
+mod ndarray_impls {
+    use crate::{Error, Matrix};
+    use ndarray::{Array2, ArrayView2};
+    use std::convert::TryFrom;
+
+    ///Conversion to a two-dimensional `ndarray` array in standard (row-major) layout.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(ndarray::Array2::from(mat), ndarray::arr2(&[[1, 2, 3], [4, 5, 6]]));
+    /// ```
+    impl<C, const ROWS: usize, const COLS: usize> From<Matrix<C, ROWS, COLS>> for Array2<C> {
+        fn from(matrix: Matrix<C, ROWS, COLS>) -> Self {
+            let coefficients: Vec<C> = matrix.into_iter().collect();
+            Array2::from_shape_vec((ROWS, COLS), coefficients)
+                .unwrap_or_else(|_| unreachable!("the matrix has ROWS * COLS coefficients"))
+        }
+    }
+
+    ///Conversion from a view of a two-dimensional `ndarray` array, whatever its memory layout.
+    ///
+    ///Returns DimensionMismatch if the shape of the array isn't `(ROWS, COLS)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    ///# use std::convert::TryFrom;
+    /// let array = ndarray::arr2(&[[1, 2], [3, 4], [5, 6]]);
+    /// assert_eq!(Matrix::<i32, 2, 3>::try_from(array.t()), Ok(Matrix::from([[1, 3, 5], [2, 4, 6]])));
+    /// assert!(Matrix::<i32, 2, 3>::try_from(array.view()).is_err());
+    /// ```
+    impl<'a, C, const ROWS: usize, const COLS: usize> TryFrom<ArrayView2<'a, C>>
+        for Matrix<C, ROWS, COLS>
+    where
+        C: Clone,
+    {
+        type Error = Error;
+
+        fn try_from(array: ArrayView2<'a, C>) -> Result<Self, Self::Error> {
+            if array.dim() != (ROWS, COLS) {
+                return Err(Error::DimensionMismatch);
+            }
+            Ok(Matrix::from_fn(|row, col| array[(row, col)].clone()))
+        }
+    }
+
+    ///Conversion from a two-dimensional `ndarray` array, see the implementation for `ArrayView2`.
+    impl<C, const ROWS: usize, const COLS: usize> TryFrom<Array2<C>> for Matrix<C, ROWS, COLS>
+    where
+        C: Clone,
+    {
+        type Error = Error;
+
+        fn try_from(array: Array2<C>) -> Result<Self, Self::Error> {
+            Matrix::try_from(array.view())
+        }
+    }
+}
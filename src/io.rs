@@ -0,0 +1,343 @@
+//! Reading and writing matrices from and to text formats.
+
+use crate::{Error, Matrix};
+use num::traits::Zero;
+use std::io::{self, BufRead};
+use std::{fmt::Display, str::FromStr};
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Io(error.to_string())
+    }
+}
+
+///Layout of the entries of a [Matrix Market](https://math.nist.gov/MatrixMarket/formats.html) file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatrixMarketFormat {
+    ///Every coefficient is written, in column-major order.
+    Array,
+    ///Only the non-nil coefficients are written, along with their 1-based position.
+    Coordinate,
+}
+
+///Matrix Market support.
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS> {
+    ///Reads a matrix from a Matrix Market file.
+    ///
+    ///Both the `array` and `coordinate` formats are supported, for `real` and `integer` fields with either a `general` or `symmetric` structure.
+    ///Returns WrongLength if the dimensions of the file aren't `ROWS` and `COLS`, if a `symmetric` file is read into a non-square matrix
+    ///or if it doesn't have as many entries as announced,
+    ///OutOfBounds if a coordinate isn't within the matrix, Parse if the file is malformed and Io if it can't be read.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let data = "%%MatrixMarket matrix coordinate real general
+    /// % a comment
+    /// 2 3 2
+    /// 1 1 4.5
+    /// 2 3 -1
+    /// ";
+    /// let mat: Matrix<f64, 2, 3> = Matrix::from_matrix_market(data.as_bytes()).unwrap();
+    /// assert_eq!(mat, Matrix::from([[4.5, 0.0, 0.0], [0.0, 0.0, -1.0]]));
+    /// ```
+    pub fn from_matrix_market<R: io::Read>(reader: R) -> Result<Self, Error>
+    where
+        C: FromStr + Zero + Clone,
+        C::Err: Display,
+    {
+        let mut lines = io::BufReader::new(reader).lines();
+        let header = lines
+            .next()
+            .ok_or_else(|| Error::Parse("missing Matrix Market header".to_string()))??
+            .to_lowercase();
+        let header: Vec<&str> = header.split_whitespace().collect();
+        if header.len() != 5 || header[0] != "%%matrixmarket" || header[1] != "matrix" {
+            return Err(Error::Parse("invalid Matrix Market header".to_string()));
+        }
+        let format = match header[2] {
+            "array" => MatrixMarketFormat::Array,
+            "coordinate" => MatrixMarketFormat::Coordinate,
+            other => return Err(Error::Parse(format!("unknown format {}", other))),
+        };
+        if !matches!(header[3], "real" | "integer") {
+            return Err(Error::Parse(format!("unsupported field {}", header[3])));
+        }
+        let symmetric = match header[4] {
+            "general" => false,
+            "symmetric" => true,
+            other => return Err(Error::Parse(format!("unsupported structure {}", other))),
+        };
+
+        let mut entries = lines.filter(|line| match line {
+            Ok(line) => !line.trim().is_empty() && !line.trim_start().starts_with('%'),
+            Err(_) => true,
+        });
+        let mut next_entry = move || -> Result<Vec<String>, Error> {
+            match entries.next() {
+                None => Err(Error::WrongLength),
+                Some(line) => Ok(line?.split_whitespace().map(String::from).collect()),
+            }
+        };
+
+        let size = next_entry()?;
+        let expected_tokens = match format {
+            MatrixMarketFormat::Array => 2,
+            MatrixMarketFormat::Coordinate => 3,
+        };
+        let size = parse_tokens::<usize>(&size, expected_tokens)?;
+        //a symmetric file can only fill a square matrix
+        if size[0] != ROWS || size[1] != COLS || (symmetric && ROWS != COLS) {
+            return Err(Error::WrongLength);
+        }
+
+        let mut m = Matrix::from_fn(|_, _| C::zero());
+        match format {
+            MatrixMarketFormat::Array => {
+                for col in 0..COLS {
+                    //symmetric matrices only store their lower triangle
+                    let first_row = if symmetric { col } else { 0 };
+                    for row in first_row..ROWS {
+                        let value = parse_value::<C>(&next_entry()?)?;
+                        if symmetric {
+                            m.data[col][row] = value.clone();
+                        }
+                        m.data[row][col] = value;
+                    }
+                }
+            }
+            MatrixMarketFormat::Coordinate => {
+                for _ in 0..size[2] {
+                    let entry = next_entry()?;
+                    let position = parse_tokens::<usize>(&entry[..entry.len().min(2)], 2)?;
+                    let (row, col) = match (position[0], position[1]) {
+                        (row, col) if (1..=ROWS).contains(&row) && (1..=COLS).contains(&col) => {
+                            (row - 1, col - 1)
+                        }
+                        _ => return Err(Error::OutOfBounds),
+                    };
+                    let value = parse_value::<C>(&entry[2..])?;
+                    if symmetric {
+                        m.data[col][row] = value.clone();
+                    }
+                    m.data[row][col] = value;
+                }
+            }
+        }
+        match next_entry() {
+            Err(Error::WrongLength) => Ok(m),
+            Err(e) => Err(e),
+            Ok(_) => Err(Error::WrongLength),
+        }
+    }
+
+    ///Writes the matrix as a Matrix Market file with a `real` field and a `general` structure.
+    ///
+    ///The field is `real` whatever the coefficient type: integer coefficients are written as they are,
+    ///which is valid `real` data, and read back by [`from_matrix_market`] into either integer or floating point matrices.
+    ///
+    /// [`from_matrix_market`]: #method.from_matrix_market
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{Matrix, MatrixMarketFormat};
+    /// let mut data = Vec::new();
+    /// let mat = Matrix::from([[1, 0], [0, 2]]);
+    /// mat.to_matrix_market(&mut data, MatrixMarketFormat::Coordinate).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(data).unwrap(),
+    ///     "%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1\n2 2 2\n"
+    /// );
+    /// ```
+    pub fn to_matrix_market<W: io::Write>(
+        &self,
+        mut writer: W,
+        format: MatrixMarketFormat,
+    ) -> Result<(), Error>
+    where
+        C: Display + Zero,
+    {
+        match format {
+            MatrixMarketFormat::Array => {
+                writeln!(writer, "%%MatrixMarket matrix array real general")?;
+                writeln!(writer, "{} {}", ROWS, COLS)?;
+                for col in 0..COLS {
+                    for line in self.data.iter() {
+                        writeln!(writer, "{}", line[col])?;
+                    }
+                }
+            }
+            MatrixMarketFormat::Coordinate => {
+                let entries = self.data.iter().flatten().filter(|c| !c.is_zero()).count();
+                writeln!(writer, "%%MatrixMarket matrix coordinate real general")?;
+                writeln!(writer, "{} {} {}", ROWS, COLS, entries)?;
+                for (row, line) in self.data.iter().enumerate() {
+                    for (col, c) in line.iter().enumerate().filter(|(_, c)| !c.is_zero()) {
+                        writeln!(writer, "{} {} {}", row + 1, col + 1, c)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+///LaTeX output.
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS>
+where
+    C: Display,
+{
+    ///Returns the LaTeX code of the matrix in a `pmatrix` environment.
+    ///See [`to_latex_with`] to use another environment or to set the precision of the coefficients.
+    ///
+    /// [`to_latex_with`]: #method.to_latex_with
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1, 2], [3, 4]]);
+    /// assert_eq!(
+    ///     mat.to_latex(),
+    ///     "\\begin{pmatrix}\n1 & 2 \\\\\n3 & 4\n\\end{pmatrix}"
+    /// );
+    /// ```
+    pub fn to_latex(&self) -> String {
+        self.to_latex_with("pmatrix", None)
+    }
+
+    ///Returns the LaTeX code of the matrix in the given environment, such as `bmatrix` or `vmatrix`.
+    ///
+    ///If `precision` is set, every coefficient is written with that many decimal digits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[0.5, 1.0 / 3.0]]);
+    /// assert_eq!(
+    ///     mat.to_latex_with("bmatrix", Some(2)),
+    ///     "\\begin{bmatrix}\n0.50 & 0.33\n\\end{bmatrix}"
+    /// );
+    /// ```
+    pub fn to_latex_with(&self, environment: &str, precision: Option<usize>) -> String {
+        let rows: Vec<String> = self
+            .data
+            .iter()
+            .map(|line| {
+                line.iter()
+                    .map(|c| match precision {
+                        Some(precision) => format!("{:.*}", precision, c),
+                        None => c.to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" & ")
+            })
+            .collect();
+        format!(
+            "\\begin{{{env}}}\n{}\n\\end{{{env}}}",
+            rows.join(" \\\\\n"),
+            env = environment
+        )
+    }
+}
+
+//Parses exactly `count` tokens.
+fn parse_tokens<T>(tokens: &[String], count: usize) -> Result<Vec<T>, Error>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    if tokens.len() != count {
+        return Err(Error::Parse(format!(
+            "expected {} values, found {}",
+            count,
+            tokens.len()
+        )));
+    }
+    tokens
+        .iter()
+        .map(|t| t.parse().map_err(|e| Error::Parse(format!("{}: {}", t, e))))
+        .collect()
+}
+
+//Parses a single coefficient.
+fn parse_value<T>(tokens: &[String]) -> Result<T, Error>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    //exactly one token is parsed so the vector can't be empty
+    Ok(parse_tokens(tokens, 1)?.pop().unwrap())
+}
+
+///CSV support, available with the `csv` feature.
+#[cfg(feature = "csv")]
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS> {
+    ///Reads a matrix from CSV data without headers, one record per row.
+    ///
+    ///Returns WrongLength if the data doesn't have exactly `ROWS` records of `COLS` fields,
+    ///Parse if a field isn't a valid coefficient or the data isn't valid UTF-8, and Io if the data can't be read.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let data = "1,2,3\n4,5,6\n";
+    /// let mat: Matrix<i32, 2, 3> = Matrix::from_csv(data.as_bytes()).unwrap();
+    /// assert_eq!(mat, Matrix::from([[1, 2, 3], [4, 5, 6]]));
+    /// ```
+    pub fn from_csv<R: io::Read>(reader: R) -> Result<Self, Error>
+    where
+        C: FromStr,
+        C::Err: Display,
+    {
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .trim(csv::Trim::All)
+            .from_reader(reader);
+        let mut coefficients = Vec::with_capacity(ROWS * COLS);
+        for (row, record) in reader.records().enumerate() {
+            let record = record.map_err(|e| match e.kind() {
+                csv::ErrorKind::Io(_) => Error::Io(e.to_string()),
+                _ => Error::Parse(e.to_string()),
+            })?;
+            if row >= ROWS || record.len() != COLS {
+                return Err(Error::WrongLength);
+            }
+            for (col, field) in record.iter().enumerate() {
+                let c = field
+                    .parse()
+                    .map_err(|e| Error::Parse(format!("row {}, column {}: {}", row, col, e)))?;
+                coefficients.push(c);
+            }
+        }
+        Matrix::try_from_iter(coefficients)
+    }
+
+    ///Writes the matrix as CSV data without headers, one record per row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mut data = Vec::new();
+    /// Matrix::from([[1, 2, 3], [4, 5, 6]]).to_csv(&mut data).unwrap();
+    /// assert_eq!(data, b"1,2,3\n4,5,6\n");
+    /// ```
+    pub fn to_csv<W: io::Write>(&self, writer: W) -> Result<(), Error>
+    where
+        C: Display,
+    {
+        let mut writer = csv::Writer::from_writer(writer);
+        for line in self.data.iter() {
+            writer
+                .write_record(line.iter().map(|c| c.to_string()))
+                .map_err(|e| Error::Io(e.to_string()))?;
+        }
+        writer.flush().map_err(|e| Error::Io(e.to_string()))
+    }
+}
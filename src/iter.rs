@@ -0,0 +1,113 @@
+//! Iterators over the matrix that can't be expressed with the slice iterators of the underlying storage.
+
+use core::array::IntoIter;
+use core::iter::Flatten;
+use core::slice::{Iter, IterMut};
+
+///Iterator over all the coefficients of a matrix in row-major order.
+///This is created by [`Matrix::iter`] or by iterating over a reference to a matrix.
+///
+/// [`Matrix::iter`]: crate::Matrix::iter
+pub type Coefficients<'a, C, const COLS: usize> = Flatten<Iter<'a, [C; COLS]>>;
+
+///Mutable iterator over all the coefficients of a matrix in row-major order.
+///This is created by [`Matrix::iter_mut`] or by iterating over a mutable reference to a matrix.
+///
+/// [`Matrix::iter_mut`]: crate::Matrix::iter_mut
+pub type CoefficientsMut<'a, C, const COLS: usize> = Flatten<IterMut<'a, [C; COLS]>>;
+
+///Owning iterator over all the coefficients of a matrix in row-major order.
+///This is created by iterating over a matrix by value.
+pub type IntoCoefficients<C, const ROWS: usize, const COLS: usize> =
+    Flatten<IntoIter<[C; COLS], ROWS>>;
+
+///Iterator over the columns of a matrix.
+///
+///Each column is yielded as an array of references to its coefficients, from top to bottom.
+///This struct is created by [`Matrix::get_columns`].
+///
+/// [`Matrix::get_columns`]: crate::Matrix::get_columns
+pub struct Columns<'a, C, const ROWS: usize> {
+    lines: [Iter<'a, C>; ROWS],
+    remaining: usize,
+}
+
+impl<'a, C, const ROWS: usize> Columns<'a, C, ROWS> {
+    pub(crate) fn new(lines: [Iter<'a, C>; ROWS], remaining: usize) -> Self {
+        Columns { lines, remaining }
+    }
+}
+
+impl<'a, C, const ROWS: usize> Iterator for Columns<'a, C, ROWS> {
+    type Item = [&'a C; ROWS];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        //every line has exactly `remaining` coefficients left so this never fails
+        Some(core::array::from_fn(|i| self.lines[i].next().unwrap()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, C, const ROWS: usize> DoubleEndedIterator for Columns<'a, C, ROWS> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        Some(core::array::from_fn(|i| self.lines[i].next_back().unwrap()))
+    }
+}
+
+impl<'a, C, const ROWS: usize> ExactSizeIterator for Columns<'a, C, ROWS> {}
+
+///Mutable iterator over the columns of a matrix.
+///
+///Each column is yielded as an array of mutable references to its coefficients, from top to bottom.
+///This struct is created by [`Matrix::get_mut_columns`].
+///
+/// [`Matrix::get_mut_columns`]: crate::Matrix::get_mut_columns
+pub struct ColumnsMut<'a, C, const ROWS: usize> {
+    lines: [IterMut<'a, C>; ROWS],
+    remaining: usize,
+}
+
+impl<'a, C, const ROWS: usize> ColumnsMut<'a, C, ROWS> {
+    pub(crate) fn new(lines: [IterMut<'a, C>; ROWS], remaining: usize) -> Self {
+        ColumnsMut { lines, remaining }
+    }
+}
+
+impl<'a, C, const ROWS: usize> Iterator for ColumnsMut<'a, C, ROWS> {
+    type Item = [&'a mut C; ROWS];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        Some(core::array::from_fn(|i| self.lines[i].next().unwrap()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, C, const ROWS: usize> DoubleEndedIterator for ColumnsMut<'a, C, ROWS> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        Some(core::array::from_fn(|i| self.lines[i].next_back().unwrap()))
+    }
+}
+
+impl<'a, C, const ROWS: usize> ExactSizeIterator for ColumnsMut<'a, C, ROWS> {}
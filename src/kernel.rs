@@ -0,0 +1,71 @@
+//! Basis of the null space of a matrix, computed from its reduced row echelon form.
+
+use crate::{ColVector, Matrix};
+use alloc::vec::Vec;
+use num::traits::Signed;
+
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS>
+where
+    C: Signed + PartialOrd + Clone,
+{
+    ///Returns a basis of the null space of the matrix, that is of the solutions of `self * x = 0`.
+    ///
+    ///There is one vector per column without a pivot in the reduced row echelon form, that is `COLS - rank` of them.
+    ///Each has a one in its free column, zeros in the other free columns and is computed exactly for exact coefficients.
+    ///Because of rounding errors this is not reliable for floating point coefficients, use [`null_space_with_tolerance`] instead.
+    ///
+    /// [`null_space_with_tolerance`]: #method.null_space_with_tolerance
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let a = Matrix::from([[1, 2, 3], [2, 4, 6]]).map(f64::from);
+    /// let basis = a.null_space();
+    /// assert_eq!(basis, vec![Matrix::from([[-2.0], [1.0], [0.0]]), Matrix::from([[-3.0], [0.0], [1.0]])]);
+    /// ```
+    pub fn null_space(&self) -> Vec<ColVector<C, COLS>> {
+        self.null_space_with_tolerance(&C::zero())
+    }
+
+    ///Returns a basis of the null space of the matrix, considering any coefficient no bigger than `tolerance` in absolute value as nil.
+    ///
+    ///See [`null_space`] for details.
+    ///
+    /// [`null_space`]: #method.null_space
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let a = Matrix::from([[1.0, 2.0], [1.0, 2.0 + 1e-12]]);
+    /// assert!(a.null_space().is_empty());
+    /// let basis = a.null_space_with_tolerance(&1e-9);
+    /// assert_eq!(basis.len(), 1);
+    /// assert!((&a * &basis[0]).approx_eq(&Matrix::default(), 1e-9));
+    /// ```
+    pub fn null_space_with_tolerance(&self, tolerance: &C) -> Vec<ColVector<C, COLS>> {
+        let mut rref = self.clone();
+        let pivots = rref.reduce_pivots(tolerance);
+        //row of the pivot of each pivot column
+        let mut pivot_rows = [None; COLS];
+        let mut row = 0;
+        for (col, &pivot) in pivots.iter().enumerate() {
+            if pivot {
+                pivot_rows[col] = Some(row);
+                row += 1;
+            }
+        }
+
+        (0..COLS)
+            .filter(|&free| !pivots[free])
+            .map(|free| {
+                ColVector::from_fn(|col, _| match pivot_rows[col] {
+                    Some(row) => -rref.data[row][free].clone(),
+                    None if col == free => C::one(),
+                    None => C::zero(),
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,117 @@
+//! Column-major storage, for interoperability with BLAS, LAPACK and GPU APIs expecting Fortran order.
+
+use crate::Matrix;
+use core::ops::{Index, IndexMut};
+
+///Matrix of `ROWS` rows and `COLS` columns stored column after column.
+///
+///[`Matrix`] stores its coefficients row after row, which is the order used by most Rust code.
+///This type only differs by its layout in memory: it is meant to hand data to APIs expecting column-major order,
+///conversions from and to [`Matrix`] are provided for computations.
+///
+/// # Example
+///
+/// ```
+///# use matrix::{ColMajorMatrix, Matrix};
+/// let mat = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+/// let col_major = ColMajorMatrix::from(&mat);
+/// assert_eq!(col_major.as_slice(), &[1, 4, 2, 5, 3, 6]);
+/// assert_eq!(col_major[(0, 2)], 3);
+/// assert_eq!(Matrix::from(&col_major), mat);
+/// ```
+///
+/// [`Matrix`]: struct.Matrix.html
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ColMajorMatrix<C, const ROWS: usize, const COLS: usize> {
+    data: [[C; ROWS]; COLS],
+}
+
+impl<C, const ROWS: usize, const COLS: usize> ColMajorMatrix<C, ROWS, COLS> {
+    ///Builds a matrix from its columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{ColMajorMatrix, Matrix};
+    /// let col_major = ColMajorMatrix::from_columns([[1, 4], [2, 5], [3, 6]]);
+    /// assert_eq!(Matrix::from(&col_major), Matrix::from([[1, 2, 3], [4, 5, 6]]));
+    /// ```
+    pub fn from_columns(columns: [[C; ROWS]; COLS]) -> Self {
+        ColMajorMatrix { data: columns }
+    }
+
+    ///Returns the nested array of columns the matrix is made of.
+    pub fn into_inner(self) -> [[C; ROWS]; COLS] {
+        self.data
+    }
+
+    ///Returns a reference to the coefficient at `(row, col)` or `None` if either is out of bounds.
+    pub fn get(&self, row: usize, col: usize) -> Option<&C> {
+        self.data.get(col).and_then(|column| column.get(row))
+    }
+
+    ///Returns a mutable reference to the coefficient at `(row, col)` or `None` if either is out of bounds.
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut C> {
+        self.data
+            .get_mut(col)
+            .and_then(|column| column.get_mut(row))
+    }
+
+    ///Returns all coefficients as a contiguous slice, column after column.
+    pub fn as_slice(&self) -> &[C] {
+        //SAFETY: nested arrays are laid out contiguously without padding, so `data` holds exactly `ROWS * COLS` coefficients.
+        unsafe { core::slice::from_raw_parts(self.data.as_ptr() as *const C, ROWS * COLS) }
+    }
+
+    ///Returns all coefficients as a contiguous mutable slice, column after column.
+    pub fn as_mut_slice(&mut self) -> &mut [C] {
+        //SAFETY: see `as_slice`, the slice borrows `self` mutably so it is the only access to the coefficients.
+        unsafe { core::slice::from_raw_parts_mut(self.data.as_mut_ptr() as *mut C, ROWS * COLS) }
+    }
+}
+
+///Conversion to column-major order.
+impl<C, const ROWS: usize, const COLS: usize> From<&Matrix<C, ROWS, COLS>>
+    for ColMajorMatrix<C, ROWS, COLS>
+where
+    C: Clone,
+{
+    fn from(matrix: &Matrix<C, ROWS, COLS>) -> Self {
+        ColMajorMatrix {
+            data: core::array::from_fn(|col| {
+                core::array::from_fn(|row| matrix.data[row][col].clone())
+            }),
+        }
+    }
+}
+
+///Conversion to row-major order.
+impl<C, const ROWS: usize, const COLS: usize> From<&ColMajorMatrix<C, ROWS, COLS>>
+    for Matrix<C, ROWS, COLS>
+where
+    C: Clone,
+{
+    fn from(matrix: &ColMajorMatrix<C, ROWS, COLS>) -> Self {
+        Matrix::from_fn(|row, col| matrix.data[col][row].clone())
+    }
+}
+
+///Access to a single coefficient with a `(row, col)` pair, as for [`Matrix`].
+///
+/// [`Matrix`]: struct.Matrix.html
+impl<C, const ROWS: usize, const COLS: usize> Index<(usize, usize)>
+    for ColMajorMatrix<C, ROWS, COLS>
+{
+    type Output = C;
+    fn index(&self, (row, col): (usize, usize)) -> &C {
+        &self.data[col][row]
+    }
+}
+
+impl<C, const ROWS: usize, const COLS: usize> IndexMut<(usize, usize)>
+    for ColMajorMatrix<C, ROWS, COLS>
+{
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut C {
+        &mut self.data[col][row]
+    }
+}
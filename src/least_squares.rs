@@ -0,0 +1,89 @@
+//! Least squares solutions of overdetermined linear systems.
+
+use crate::{ColVector, Error, Matrix};
+use num::traits::Float;
+
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS>
+where
+    C: Float,
+{
+    //Referencing this fails to compile if the system has fewer equations than unknowns.
+    const OVERDETERMINED: () = assert!(
+        ROWS >= COLS,
+        "least squares need at least as many rows as columns"
+    );
+
+    ///Returns the `x` minimizing `‖self * x - b‖`, for each column of `b`.
+    ///
+    ///The matrix is reduced to an upper triangular `R` by Householder reflections, which are applied to `b` as well,
+    ///and `Rx = Qᵀb` is then solved by back substitution. Unlike solving the normal equations `AᵀAx = Aᵀb`,
+    ///this doesn't square the condition number of the matrix.
+    ///Returns RankDeficient if the columns of the matrix are linearly dependent, in which case the minimizer isn't unique.
+    ///Fails to compile if the matrix has fewer rows than columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// // fit y = a + bx through (0, 1), (1, 3), (2, 4) and (3, 7)
+    /// let a = Matrix::from([[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]]);
+    /// let y = Matrix::from([[1.0], [3.0], [4.0], [7.0]]);
+    /// let fit = a.lstsq(y).unwrap();
+    /// assert!(fit.approx_eq(&Matrix::from([[0.9], [1.9]]), 1e-12));
+    /// ```
+    ///
+    ///Underdetermined systems are rejected.
+    ///
+    /// ```compile_fail
+    ///# use matrix::Matrix;
+    /// let a = Matrix::from([[1.0, 2.0, 3.0]]);
+    /// let x = a.lstsq(Matrix::from([[1.0]]));
+    /// ```
+    pub fn lstsq<const N: usize>(
+        &self,
+        b: Matrix<C, ROWS, N>,
+    ) -> Result<Matrix<C, COLS, N>, Error> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::OVERDETERMINED;
+        //a column is considered dependent once most of its digits are lost in the reflections
+        let tolerance = C::epsilon().sqrt();
+        let mut a = self.clone();
+        let mut x = b;
+        for k in 0..COLS {
+            let original_norm = column_norm(self, k, 0);
+            let norm = column_norm(&a, k, k);
+            if norm.is_zero() || norm <= tolerance * original_norm {
+                return Err(Error::RankDeficient);
+            }
+            //reflect the column onto ±‖column‖, the sign avoiding cancellation
+            let alpha = if a.data[k][k] > C::zero() {
+                -norm
+            } else {
+                norm
+            };
+            let v = ColVector::from_fn(|row, _| match row {
+                row if row < k => C::zero(),
+                row if row == k => a.data[k][k] - alpha,
+                row => a.data[row][k],
+            });
+            a.apply_householder(&v)?;
+            x.apply_householder(&v)?;
+        }
+
+        let r = Matrix::<C, COLS, COLS>::from_fn(|row, col| a.data[row][col]);
+        let y = Matrix::<C, COLS, N>::from_fn(|row, col| x.data[row][col]);
+        r.solve_upper_triangular(y)
+    }
+}
+
+//Returns the norm of column `col` of `m`, from row `from` onwards.
+fn column_norm<C: Float, const ROWS: usize, const COLS: usize>(
+    m: &Matrix<C, ROWS, COLS>,
+    col: usize,
+    from: usize,
+) -> C {
+    m.data[from..]
+        .iter()
+        .fold(C::zero(), |acc, line| acc + line[col] * line[col])
+        .sqrt()
+}
@@ -2,31 +2,170 @@
 //!
 //! This was made as a learning project and thrives to provide matrices generic over any type.
 //! Some basic matrix manipulation operations are implemented for the matrix assuming the concrete type implements the required traits.
-//! The main selling point is that most operations fail to compile if the operation is impossible. This is done through the use of `min_const_generic` (rustc v1.51) and allows operations such as matrix product to always work if the code compiles.
+//! The main selling point is that most operations fail to compile if the operation is impossible. This is done through the use of `min_const_generic` and allows operations such as matrix product to always work if the code compiles.
+//! The crate builds on stable Rust, only the items behind the `nightly` and `simd` features need a nightly compiler.
+//! It also supports `#![no_std]` environments by disabling the default `std` feature, the `alloc` feature bringing back the items which need an allocator.
 //!
 //! Although most operations implemented are done so with mathematical matrices in mind the type itself can be used for any use-case
 //!
 //! For how to use this crate, refer to [`Matrix`]
 
-#![feature(maybe_uninit_extra)]
-#![feature(array_methods)]
-use num::traits::{One, Zero};
-use std::convert::From;
-use std::iter::Sum;
-use std::mem::{self, MaybeUninit};
-use std::ops::{Add, AddAssign, Mul, MulAssign};
-use std::slice::{Iter, IterMut};
-use thiserror::Error;
-
-#[derive(Debug, PartialEq, Eq, Clone)]
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs))]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::convert::{From, TryFrom};
+use core::fmt;
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::slice::{Iter, IterMut};
+use num::traits::{Num, One, Signed, Zero};
+
+mod adjugate;
+#[cfg(feature = "rkyv")]
+mod archive;
+mod banded;
+#[cfg(feature = "alloc")]
+mod bits;
+#[cfg(feature = "blas")]
+mod blas;
+mod blocks;
+#[cfg(feature = "bytemuck")]
+mod bytes;
+mod comparison;
+mod complex;
+mod diagonal;
+#[cfg(feature = "alloc")]
+mod dynamic;
+mod eigen;
+mod field;
+mod functions;
+mod interop;
+#[cfg(feature = "std")]
+mod io;
+mod iter;
+#[cfg(feature = "alloc")]
+mod kernel;
+mod layout;
+mod least_squares;
+mod modular;
+mod norms;
+mod orthogonal;
+mod overflow;
+#[cfg(feature = "matrixmultiply")]
+mod packed;
+#[cfg(feature = "parallel")]
+mod parallel;
+mod permutation;
+mod polynomial;
+#[cfg(feature = "serde")]
+mod serialization;
+#[cfg(feature = "simd")]
+mod simd;
+#[cfg(feature = "alloc")]
+mod sparse;
+mod statistics;
+#[cfg(feature = "strassen")]
+mod strassen;
+mod structure;
+mod svd;
+mod transform;
+mod triangular;
+mod vector;
+mod view;
+pub use banded::{BandMatrix, Tridiagonal};
+#[cfg(feature = "alloc")]
+pub use bits::BitMatrix;
+pub use diagonal::Diagonal;
+#[cfg(feature = "alloc")]
+pub use dynamic::DynMatrix;
+pub use field::{FieldLinearAlgebra, Pivot};
+#[cfg(feature = "std")]
+pub use io::MatrixMarketFormat;
+pub use iter::{Coefficients, CoefficientsMut, Columns, ColumnsMut, IntoCoefficients};
+pub use layout::ColMajorMatrix;
+pub use modular::Mod;
+pub use permutation::Permutation;
+#[cfg(feature = "alloc")]
+pub use sparse::SparseMatrix;
+pub use transform::{DepthRange, Handedness};
+pub use vector::{ColVector, RowVector};
+pub use view::{MatrixView, MatrixViewMut};
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 /// Matrix type generic over its coefficient and dimensions
 ///
 /// This struct contains most of the crate's features.
+#[repr(transparent)]
 pub struct Matrix<C, const ROWS: usize, const COLS: usize> {
     data: [[C; COLS]; ROWS],
 }
 
 impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS> {
+    ///Builds a matrix by calling `f(row, col)` for each coefficient.
+    ///
+    ///Coefficients are computed row after row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat: Matrix<usize, 2, 3> = Matrix::from_fn(|row, col| row * 10 + col);
+    /// assert_eq!(mat, Matrix::from([[0, 1, 2], [10, 11, 12]]));
+    /// ```
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> C,
+    {
+        Matrix {
+            data: core::array::from_fn(|row| core::array::from_fn(|col| f(row, col))),
+        }
+    }
+
+    ///Builds a matrix from the coefficients yielded by `iter`, row after row.
+    ///
+    ///Returns WrongLength if `iter` doesn't yield exactly `ROWS * COLS` coefficients.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# use matrix::{Error, Matrix};
+    /// let mat: Matrix<u32, 2, 3> = Matrix::try_from_iter(1..=6).unwrap();
+    /// assert_eq!(mat, Matrix::from([[1, 2, 3], [4, 5, 6]]));
+    /// assert_eq!(Matrix::<u32, 2, 3>::try_from_iter(1..=5), Err(Error::WrongLength));
+    /// ```
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = C>,
+    {
+        let mut iter = iter.into_iter();
+        let coefficients = Matrix::from_fn(|_, _| iter.next());
+        if iter.next().is_some() {
+            return Err(Error::WrongLength);
+        }
+        Matrix::from_options(coefficients).ok_or(Error::WrongLength)
+    }
+
+    //Unwraps every coefficient, or returns `None` if any of them is missing.
+    pub(crate) fn from_options(matrix: Matrix<Option<C>, ROWS, COLS>) -> Option<Self> {
+        if matrix.iter().any(Option::is_none) {
+            return None;
+        }
+        Some(matrix.map(Option::unwrap))
+    }
+
     ///Returns an iterator of all lines of the matrix.
     ///
     /// # Examples
@@ -36,7 +175,7 @@ impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS> {
     /// let mat = Matrix::from([[9, 8, 7], [6, 5, 4]]);
     /// assert_eq!(mat.get_lines().nth(1), Some(&[6, 5, 4]));
     /// ```
-    pub fn get_lines(&self) -> Iter<[C; COLS]> {
+    pub fn get_lines(&self) -> Iter<'_, [C; COLS]> {
         self.data.iter()
     }
 
@@ -44,7 +183,7 @@ impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS> {
     /// See [`get_lines`] for examples.
     ///
     /// [`get_lines`]: #method.get_lines
-    pub fn get_mut_lines(&mut self) -> IterMut<[C; COLS]> {
+    pub fn get_mut_lines(&mut self) -> IterMut<'_, [C; COLS]> {
         self.data.iter_mut()
     }
 
@@ -69,6 +208,113 @@ impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS> {
         self.data.get_mut(index)
     }
 
+    ///Returns an iterator of all columns of the matrix.
+    ///Each column is an array of references to its coefficients.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[9, 8, 7], [6, 5, 4]]);
+    /// assert_eq!(mat.get_columns().nth(1), Some([&8, &5]));
+    /// ```
+    pub fn get_columns(&self) -> Columns<'_, C, ROWS> {
+        Columns::new(self.data.each_ref().map(|line| line.iter()), COLS)
+    }
+
+    ///Returns a mutable iterator of all columns of the matrix.
+    /// See [`get_columns`] for examples.
+    ///
+    /// [`get_columns`]: #method.get_columns
+    pub fn get_mut_columns(&mut self) -> ColumnsMut<'_, C, ROWS> {
+        ColumnsMut::new(self.data.each_mut().map(|line| line.iter_mut()), COLS)
+    }
+
+    ///Returns references to the coefficients of a column or `None` if index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[9, 8, 7], [6, 5, 4]]);
+    /// assert_eq!(mat.get_column(2), Some([&7, &4]));
+    /// ```
+    pub fn get_column(&self, index: usize) -> Option<[&C; ROWS]> {
+        if index >= COLS {
+            return None;
+        }
+        Some(self.data.each_ref().map(|line| &line[index]))
+    }
+
+    ///Returns mutable references to the coefficients of a column or `None` if index is out of bounds.
+    /// See [`get_column`] for examples.
+    ///
+    /// [`get_column`]: #method.get_column
+    pub fn get_mut_column(&mut self, index: usize) -> Option<[&mut C; ROWS]> {
+        if index >= COLS {
+            return None;
+        }
+        Some(self.data.each_mut().map(|line| &mut line[index]))
+    }
+
+    ///Returns an iterator of all coefficients of the matrix, row after row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[9, 8, 7], [6, 5, 4]]);
+    /// assert_eq!(mat.iter().nth(3), Some(&6));
+    /// assert_eq!(mat.iter().sum::<i32>(), 39);
+    /// ```
+    pub fn iter(&self) -> Coefficients<'_, C, COLS> {
+        self.data.iter().flatten()
+    }
+
+    ///Returns a mutable iterator of all coefficients of the matrix, row after row.
+    /// See [`iter`] for examples.
+    ///
+    /// [`iter`]: #method.iter
+    pub fn iter_mut(&mut self) -> CoefficientsMut<'_, C, COLS> {
+        self.data.iter_mut().flatten()
+    }
+
+    ///Returns all coefficients as a contiguous slice, row after row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[9, 8, 7], [6, 5, 4]]);
+    /// assert_eq!(mat.as_slice(), &[9, 8, 7, 6, 5, 4]);
+    /// ```
+    pub fn as_slice(&self) -> &[C] {
+        //SAFETY: nested arrays are laid out contiguously without padding, so `data` holds exactly `ROWS * COLS` coefficients.
+        unsafe { core::slice::from_raw_parts(self.data.as_ptr() as *const C, ROWS * COLS) }
+    }
+
+    ///Returns all coefficients as a contiguous mutable slice, row after row.
+    /// See [`as_slice`] for details.
+    ///
+    /// [`as_slice`]: #method.as_slice
+    pub fn as_mut_slice(&mut self) -> &mut [C] {
+        //SAFETY: see `as_slice`, the slice borrows `self` mutably so it is the only access to the coefficients.
+        unsafe { core::slice::from_raw_parts_mut(self.data.as_mut_ptr() as *mut C, ROWS * COLS) }
+    }
+
+    ///Returns the nested array of rows the matrix is made of, which is the inverse of `From<[[C; COLS]; ROWS]>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[9, 8, 7], [6, 5, 4]]);
+    /// assert_eq!(mat.into_inner(), [[9, 8, 7], [6, 5, 4]]);
+    /// ```
+    pub fn into_inner(self) -> [[C; COLS]; ROWS] {
+        self.data
+    }
+
     ///Returns a reference to a single coefficient or `None` if either `row` or `col` is out of bounds.
     ///
     /// # Examples
@@ -95,205 +341,1120 @@ impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS> {
             Some(line) => line.get_mut(col),
         }
     }
-}
 
-impl<C, const ROWS: usize, const COLS: usize> From<[[C; COLS]; ROWS]> for Matrix<C, ROWS, COLS> {
-    fn from(data: [[C; COLS]; ROWS]) -> Self {
-        Matrix { data }
+    ///Returns the transpose of the matrix, whose rows are the columns of the original one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[9, 8, 7], [6, 5, 4]]);
+    /// assert_eq!(mat.transpose(), Matrix::from([[9, 6], [8, 5], [7, 4]]));
+    /// ```
+    pub fn transpose(&self) -> Matrix<C, COLS, ROWS>
+    where
+        C: Clone,
+    {
+        Matrix::from_fn(|row, col| self.data[col][row].clone())
     }
-}
 
-///Multiplication by a coefficient. Can never fail, works matrices of all dimensions.
-///Similar to the `dilate` method of square matrices but for all lines at once.
-impl<'a, C: 'a, const ROWS: usize, const COLS: usize> MulAssign<&'a C> for Matrix<C, ROWS, COLS>
-where
-    C: MulAssign<&'a C> + Copy,
-{
-    fn mul_assign(&mut self, coef: &'a C) {
-        for row in self.data.iter_mut() {
-            for c in row.iter_mut() {
-                *c *= coef
-            }
+    ///Returns the matrix of the results of `f` applied to every coefficient, row after row.
+    ///
+    ///The coefficients of the result may be of another type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1, -2], [3, -4]]);
+    /// assert_eq!(mat.clone().map(|c| c * 10), Matrix::from([[10, -20], [30, -40]]));
+    /// assert_eq!(mat.map(|c| c > 0), Matrix::from([[true, false], [true, false]]));
+    /// ```
+    pub fn map<T, F>(self, mut f: F) -> Matrix<T, ROWS, COLS>
+    where
+        F: FnMut(C) -> T,
+    {
+        Matrix {
+            data: self.data.map(|line| line.map(&mut f)),
         }
     }
-}
 
-///Matrix addition, they must be of the same size
-impl<C, const ROWS: usize, const COLS: usize> AddAssign<Matrix<C, ROWS, COLS>>
-    for Matrix<C, ROWS, COLS>
-where
-    C: AddAssign + Copy,
-{
-    fn add_assign(&mut self, other: Matrix<C, ROWS, COLS>) {
-        self.data
-            .iter_mut()
-            .zip(other.data.iter())
-            .for_each(|(row_a, row_b)| {
-                row_a
-                    .iter_mut()
-                    .zip(row_b.iter())
-                    .for_each(|(a, b)| *a += *b)
-            });
+    ///Converts every coefficient to another type which can represent all of their values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[2u8, 1], [4, 3]]);
+    /// let inverse = mat.cast::<f64>().inverse().unwrap();
+    /// assert_eq!(inverse, Matrix::from([[1.5, -0.5], [-2.0, 1.0]]));
+    /// ```
+    pub fn cast<T>(self) -> Matrix<T, ROWS, COLS>
+    where
+        T: From<C>,
+    {
+        self.map(T::from)
     }
-}
 
-///Matrix product. The implementation garuantees matrix compatibility at compile-time. If it compiles, it'll succeed.
-///
-/// # Commutativity
-///
-///Since matrix product is not commutative, the order matters.
-/// ```
-///# use matrix::Matrix;
-/// let left = Matrix::from([[1, 2], [6, 7]]);
-/// let right = Matrix::from([[3, 4], [5, 8]]);
-/// assert_ne!(right.clone()*left.clone(), left*right)
-/// ```
-///
-/// If the matrixes aren't square that also means you need to pay attention to their dimensions.
-/// If the result of the product is of dimension *n\*p*, then the *left* matrix needs to be of dimension *n\*q* and the *right* one *q\*p*.
-///
-/// ```compile_fail
-///# use matrix::Matrix;
-/// let left = Matrix::from([[1, 2], [3, 4], [5, 6]]);
-/// let right = Matrix::from([[9, 8, 7], [6, 5, 4]]);
-/// right * left //<- this fails because right is of dimension 2*3 and left 3*2
-/// ```
-///
-/// This however will compile.
-/// ```
-///# use matrix::Matrix;
-/// let left = Matrix::from([[1, 2], [3, 4], [5, 6]]);
-/// let right = Matrix::from([[9, 8, 7], [6, 5, 4]]);
-/// assert_eq!(left*right, Matrix::from([[21, 18, 15], [51, 44, 37], [81, 70, 59]]));
-///```
-impl<C, const ROWS: usize, const COLS: usize, const Q: usize> Mul<Matrix<C, Q, COLS>>
-    for Matrix<C, ROWS, Q>
-where
-    //clone may be more appropriate here, is there a way to require neither Copy nor Clone?
-    C: Add + Mul<C, Output = C> + Sum + Clone,
-{
-    type Output = Matrix<C, ROWS, COLS>;
-    fn mul(self, other: Matrix<C, Q, COLS>) -> Self::Output {
-        let mut m: [[MaybeUninit<C>; COLS]; ROWS] = unsafe { MaybeUninit::uninit().assume_init() };
-        for row in 0..ROWS {
-            for col in 0..COLS {
-                m[row][col].write(
-                    self.data[row]
-                        .iter()
-                        .enumerate()
-                        .map(|(j, a)| a.clone() * other.data[j][col].clone())
-                        .sum(),
-                );
+    ///Converts every coefficient to another type, failing with the error of the first conversion which fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1i32, 200], [-3, 4]]);
+    /// assert!(mat.clone().try_cast::<u8>().is_err());
+    /// assert_eq!(mat.try_cast::<i16>(), Ok(Matrix::from([[1, 200], [-3, 4]])));
+    /// ```
+    pub fn try_cast<T>(self) -> Result<Matrix<T, ROWS, COLS>, T::Error>
+    where
+        T: TryFrom<C>,
+    {
+        let mut error = None;
+        let coefficients = self.map(|c| match T::try_from(c) {
+            Ok(c) => Some(c),
+            Err(e) => {
+                error.get_or_insert(e);
+                None
             }
-        }
-        Matrix {
-            //transmute_copy because transmute doesn't work for const generics yet
-            data: unsafe { mem::transmute_copy::<_, [[C; COLS]; ROWS]>(&m) },
+        });
+        match error {
+            Some(e) => Err(e),
+            //every conversion succeeded so this never fails
+            None => Ok(Matrix::from_options(coefficients).unwrap()),
         }
     }
-}
 
-///Some functions for Matrix that have coefficients to have nil and neutral product values.
-///This gives access to nil matrixes as well as the identity matrix
-impl<C, const SIZE: usize> Matrix<C, SIZE, SIZE>
-where
-    C: One + Zero + Copy,
-{
-    ///Returns the identity matrix of size `SIZE`.
-    /// Note that this often requires type annotation.
+    ///Returns the matrix of the results of `f` applied to the coefficients of both matrices at the same position.
+    ///
+    ///This generalizes component-wise operations such as [`component_mul`].
     ///
     /// # Example
     ///
     /// ```
     ///# use matrix::Matrix;
-    /// let identity: Matrix<u8, 2, 2> = Matrix::identity();
-    /// assert_eq!(identity, [[1, 0], [0, 1]].into());
+    /// let mat = Matrix::from([[1, 5], [3, 2]]);
+    /// let other = Matrix::from([[4, 4], [4, 4]]);
+    /// assert_eq!(mat.zip_map(&other, |a, b| *a.min(b)), Matrix::from([[1, 4], [3, 2]]));
+    /// let mask = Matrix::from([[true, false], [false, true]]);
+    /// assert_eq!(mat.zip_map(&mask, |c, keep| if *keep { *c } else { 0 }), Matrix::from([[1, 0], [0, 2]]));
     /// ```
-    pub fn identity() -> Self {
-        let mut m = Self::nil();
-        for i in 0..SIZE {
-            m.data[i][i] = C::one()
-        }
-        m
+    ///
+    /// [`component_mul`]: #method.component_mul
+    pub fn zip_map<B, T, F>(&self, other: &Matrix<B, ROWS, COLS>, mut f: F) -> Matrix<T, ROWS, COLS>
+    where
+        F: FnMut(&C, &B) -> T,
+    {
+        Matrix::from_fn(|row, col| f(&self.data[row][col], &other.data[row][col]))
     }
 
-    ///Returns the nil matrix of size `SIZE`.
-    /// Note that this often requires type annotation.
+    ///Folds every coefficient, row after row, into an accumulator starting at `init`.
     ///
     /// # Example
     ///
     /// ```
     ///# use matrix::Matrix;
-    /// let identity: Matrix<u8, 2, 2> = Matrix::nil();
-    /// assert_eq!(identity, [[0, 0], [0, 0]].into());
+    /// let mat = Matrix::from([[1i32, -7], [3, 4]]);
+    /// assert_eq!(mat.fold(0, |acc, c| acc.max(c.abs())), 7);
     /// ```
-    pub fn nil() -> Self {
-        [[C::zero(); SIZE]; SIZE].into()
+    pub fn fold<T, F>(&self, init: T, f: F) -> T
+    where
+        F: FnMut(T, &C) -> T,
+    {
+        self.iter().fold(init, f)
     }
-}
 
-///Matrix internal manipulation operations
-impl<'a, C: 'a, const SIZE: usize> Matrix<C, SIZE, SIZE>
-where
-    C: Clone + MulAssign<&'a C> + AddAssign<&'a C>,
-{
-    /// Permutes two rows.
-    ///
-    /// Permutation is an operation that can be understood as swapping a line for another.
-    /// Returns an Error if either `source` of `target` is out of bounds, that is greater than `SIZE`.
+    ///Returns the sum of all coefficients.
     ///
     /// # Example
     ///
     /// ```
     ///# use matrix::Matrix;
-    /// let mut mat = Matrix::from([[1, 2, 1], [3, 4, 1], [5, 6, 1]]);
-    /// mat.permute(0, 1);
-    /// assert_eq!(mat, Matrix::from([[3, 4, 1], [1, 2, 1], [5, 6, 1]])); //look at the order of the lines
-    ///```
-    pub fn permute(&mut self, source: usize, target: usize) -> Result<(), Error> {
-        if (target >= SIZE) | (source >= SIZE) {
-            return Err(Error::OutOfBounds);
-        } else {
-            self.data.as_mut_slice().swap(source, target);
-        }
-        Ok(())
+    /// let mat = Matrix::from([[1, 2], [3, 4]]);
+    /// assert_eq!(mat.sum(), 10);
+    /// assert_eq!(mat.product(), 24);
+    /// ```
+    pub fn sum(&self) -> C
+    where
+        C: Sum + Clone,
+    {
+        self.iter().cloned().sum()
     }
 
-    /// Dilates a row
+    ///Returns the product of all coefficients, see [`sum`] for examples.
     ///
-    /// To dilate a row is to multiply all the coefficients of the row by a factor.
-    /// Returns an Error if `row` is out of bounds, that is greater than `SIZE`.
+    /// [`sum`]: #method.sum
+    pub fn product(&self) -> C
+    where
+        C: Product + Clone,
+    {
+        self.iter().cloned().product()
+    }
+
+    ///Returns the number of coefficients for which `predicate` is true.
     ///
     /// # Example
     ///
     /// ```
     ///# use matrix::Matrix;
-    /// let mut mat = Matrix::from([[1, 2, 1], [3, 4, 1], [5, 6, 1]]);
-    /// mat.dilate(0, &2);
-    /// assert_eq!(mat, Matrix::from([[2, 4, 2], [3, 4, 1], [5, 6, 1]]));
-    ///```
-    pub fn dilate(&mut self, row: usize, factor: &'a C) -> Result<(), Error> {
-        match self.data.get_mut(row) {
-            None => Err(Error::OutOfBounds),
-            Some(line) => Ok(line.iter_mut().for_each(|c| *c *= factor)),
-        }
+    /// let mat = Matrix::from([[1, -2], [0, -4]]);
+    /// assert_eq!(mat.count_where(|c| *c < 0), 2);
+    /// ```
+    pub fn count_where<F>(&self, mut predicate: F) -> usize
+    where
+        F: FnMut(&C) -> bool,
+    {
+        self.iter().filter(|c| predicate(c)).count()
     }
 }
 
-//Separated from the previous impl because of the need of HRTB
-impl<C, const SIZE: usize> Matrix<C, SIZE, SIZE>
+impl<C, const ROWS: usize, const COLS: usize> From<[[C; COLS]; ROWS]> for Matrix<C, ROWS, COLS> {
+    fn from(data: [[C; COLS]; ROWS]) -> Self {
+        Matrix { data }
+    }
+}
+
+///Fills the matrix with the default value of the coefficients.
+///
+/// # Example
+///
+/// ```
+///# use matrix::Matrix;
+/// #[derive(Default)]
+/// struct Camera {
+///     view: Matrix<f32, 4, 4>,
+/// }
+/// assert_eq!(Camera::default().view, Matrix::from([[0.0; 4]; 4]));
+/// ```
+impl<C, const ROWS: usize, const COLS: usize> Default for Matrix<C, ROWS, COLS>
 where
-    for<'a> C: Clone + MulAssign<&'a C> + AddAssign<&'a C>,
+    C: Default,
 {
-    /// Applies a transvection from a row to another
-    ///
-    /// Tto transvect is to add a row to another, coefficient by coefficient.
-    /// Returns OutOfBounds if either `source` of `other` is out of bounds, that is greater than `SIZE`.
-    ///
-    /// WrongOperation is returned if `source` and `other` are the same row.
-    /// If you encounter this issue, use [`dilate`] instead.
-    ///
-    /// [`dilate`]: #method.dilate
+    fn default() -> Self {
+        Matrix::from_fn(|_, _| C::default())
+    }
+}
+
+///Pretty-prints the matrix one row per line, with each column right-aligned.
+///
+///The precision of the formatter, if any, is applied to every coefficient.
+///
+/// # Example
+///
+/// ```
+///# use matrix::Matrix;
+/// let mat = Matrix::from([[1, -20], [300, 4]]);
+/// assert_eq!(mat.to_string(), "[  1 -20]\n[300   4]");
+/// let mat = Matrix::from([[1.0, 0.25]]);
+/// assert_eq!(format!("{:.1}", mat), "[1.0 0.2]");
+/// ```
+impl<C, const ROWS: usize, const COLS: usize> fmt::Display for Matrix<C, ROWS, COLS>
+where
+    C: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision();
+        let write_cell = |out: &mut dyn fmt::Write, c: &C| match precision {
+            Some(precision) => write!(out, "{:.*}", precision, c),
+            None => write!(out, "{}", c),
+        };
+        //cells are formatted once to be measured then once more to be written, which doesn't need an allocation
+        let cell_width = |c: &C| {
+            let mut counter = CharCounter(0);
+            //counting never fails
+            let _ = write_cell(&mut counter, c);
+            counter.0
+        };
+        let widths: [usize; COLS] = core::array::from_fn(|col| {
+            self.data
+                .iter()
+                .map(|line| cell_width(&line[col]))
+                .max()
+                .unwrap_or(0)
+        });
+
+        for (i, line) in self.data.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "[")?;
+            for (j, (c, width)) in line.iter().zip(widths.iter()).enumerate() {
+                if j > 0 {
+                    write!(f, " ")?;
+                }
+                for _ in cell_width(c)..*width {
+                    write!(f, " ")?;
+                }
+                write_cell(f, c)?;
+            }
+            write!(f, "]")?;
+        }
+        Ok(())
+    }
+}
+
+//Writer counting the characters written to it, used to align the columns of `Display`.
+struct CharCounter(usize);
+
+impl fmt::Write for CharCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+///Fills the matrix with the coefficients of the slice, row after row.
+///Returns WrongLength if the slice doesn't contain exactly `ROWS * COLS` coefficients.
+///
+/// # Example
+///
+/// ```
+///# use matrix::Matrix;
+///# use std::convert::TryFrom;
+/// let buffer = [1, 2, 3, 4];
+/// let mat = Matrix::<u8, 2, 2>::try_from(&buffer[..]).unwrap();
+/// assert_eq!(mat, Matrix::from([[1, 2], [3, 4]]));
+/// ```
+impl<C, const ROWS: usize, const COLS: usize> TryFrom<&[C]> for Matrix<C, ROWS, COLS>
+where
+    C: Clone,
+{
+    type Error = Error;
+    fn try_from(slice: &[C]) -> Result<Self, Self::Error> {
+        Matrix::try_from_iter(slice.iter().cloned())
+    }
+}
+
+///Fills the matrix with the coefficients of the vector, row after row, without cloning them.
+///Returns WrongLength if the vector doesn't contain exactly `ROWS * COLS` coefficients.
+///
+/// # Example
+///
+/// ```
+///# use matrix::{Error, Matrix};
+///# use std::convert::TryFrom;
+/// let mat = Matrix::<_, 2, 2>::try_from(vec![1, 2, 3, 4]);
+/// assert_eq!(mat, Ok(Matrix::from([[1, 2], [3, 4]])));
+/// assert_eq!(Matrix::<_, 2, 2>::try_from(vec![1, 2, 3]), Err(Error::WrongLength));
+/// ```
+#[cfg(feature = "alloc")]
+impl<C, const ROWS: usize, const COLS: usize> TryFrom<Vec<C>> for Matrix<C, ROWS, COLS> {
+    type Error = Error;
+    fn try_from(vec: Vec<C>) -> Result<Self, Self::Error> {
+        Matrix::try_from_iter(vec)
+    }
+}
+
+///Builds the matrix from a vector of rows.
+///Returns DimensionMismatch if there aren't `ROWS` rows of `COLS` coefficients each.
+///
+///Since a matrix of vectors can also be built from a flat vector, the coefficient type usually needs to be given.
+///
+/// # Example
+///
+/// ```
+///# use matrix::{Error, Matrix};
+///# use std::convert::TryFrom;
+/// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+/// assert_eq!(Matrix::<i32, 2, 3>::try_from(rows), Ok(Matrix::from([[1, 2, 3], [4, 5, 6]])));
+/// let ragged = vec![vec![1, 2, 3], vec![4, 5]];
+/// assert_eq!(Matrix::<i32, 2, 3>::try_from(ragged), Err(Error::DimensionMismatch));
+/// ```
+#[cfg(feature = "alloc")]
+impl<C, const ROWS: usize, const COLS: usize> TryFrom<Vec<Vec<C>>> for Matrix<C, ROWS, COLS> {
+    type Error = Error;
+    fn try_from(rows: Vec<Vec<C>>) -> Result<Self, Self::Error> {
+        if rows.len() != ROWS || rows.iter().any(|row| row.len() != COLS) {
+            return Err(Error::DimensionMismatch);
+        }
+        Matrix::try_from_iter(rows.into_iter().flatten())
+    }
+}
+
+///Iterates over the coefficients, row after row.
+impl<C, const ROWS: usize, const COLS: usize> IntoIterator for Matrix<C, ROWS, COLS> {
+    type Item = C;
+    type IntoIter = IntoCoefficients<C, ROWS, COLS>;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIterator::into_iter(self.data).flatten()
+    }
+}
+
+///Iterates over references to the coefficients, row after row.
+impl<'a, C, const ROWS: usize, const COLS: usize> IntoIterator for &'a Matrix<C, ROWS, COLS> {
+    type Item = &'a C;
+    type IntoIter = Coefficients<'a, C, COLS>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+///Iterates over mutable references to the coefficients, row after row.
+impl<'a, C, const ROWS: usize, const COLS: usize> IntoIterator for &'a mut Matrix<C, ROWS, COLS> {
+    type Item = &'a mut C;
+    type IntoIter = CoefficientsMut<'a, C, COLS>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+///Access to a single coefficient with a `(row, col)` pair.
+///
+///Unlike [`Matrix::get`] this panics if either `row` or `col` is out of bounds.
+///
+/// # Example
+///
+/// ```
+///# use matrix::Matrix;
+/// let mut mat = Matrix::from([[9, 8, 7], [6, 5, 4]]);
+/// mat[(1, 2)] = 0;
+/// assert_eq!(mat[(1, 2)], 0);
+/// ```
+impl<C, const ROWS: usize, const COLS: usize> Index<(usize, usize)> for Matrix<C, ROWS, COLS> {
+    type Output = C;
+    fn index(&self, (row, col): (usize, usize)) -> &C {
+        &self.data[row][col]
+    }
+}
+
+///Mutable access to a single coefficient with a `(row, col)` pair, see the `Index` implementation.
+impl<C, const ROWS: usize, const COLS: usize> IndexMut<(usize, usize)> for Matrix<C, ROWS, COLS> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut C {
+        &mut self.data[row][col]
+    }
+}
+
+///Multiplication by a coefficient. Can never fail, works matrices of all dimensions.
+///Similar to the `dilate` method but for all lines at once.
+impl<'a, C: 'a, const ROWS: usize, const COLS: usize> MulAssign<&'a C> for Matrix<C, ROWS, COLS>
+where
+    C: MulAssign<&'a C>,
+{
+    fn mul_assign(&mut self, coef: &'a C) {
+        for row in self.data.iter_mut() {
+            for c in row.iter_mut() {
+                *c *= coef
+            }
+        }
+    }
+}
+
+///Multiplication by a coefficient, returning a new matrix. Can never fail, works matrices of all dimensions.
+impl<C, const ROWS: usize, const COLS: usize> Mul<C> for Matrix<C, ROWS, COLS>
+where
+    C: Mul<Output = C> + Clone,
+{
+    type Output = Matrix<C, ROWS, COLS>;
+    fn mul(self, coef: C) -> Self::Output {
+        Matrix {
+            data: self.data.map(|line| line.map(|c| c * coef.clone())),
+        }
+    }
+}
+
+//Coherence rules forbid a generic implementation with the coefficient on the left, so it's done for primitives only.
+macro_rules! impl_scalar_mul {
+    ($($t:ty),*) => {
+        $(
+            ///Multiplication by a coefficient on the left, see the `Mul<C>` implementation of [`Matrix`].
+            impl<const ROWS: usize, const COLS: usize> Mul<Matrix<$t, ROWS, COLS>> for $t {
+                type Output = Matrix<$t, ROWS, COLS>;
+                fn mul(self, matrix: Matrix<$t, ROWS, COLS>) -> Self::Output {
+                    matrix * self
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_mul!(
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+    num::Complex<f32>,
+    num::Complex<f64>
+);
+
+///Matrix addition, they must be of the same size
+impl<C, const ROWS: usize, const COLS: usize> AddAssign<Matrix<C, ROWS, COLS>>
+    for Matrix<C, ROWS, COLS>
+where
+    C: AddAssign + Clone,
+{
+    fn add_assign(&mut self, other: Matrix<C, ROWS, COLS>) {
+        *self += &other;
+    }
+}
+
+///Matrix addition of a reference, they must be of the same size
+///
+/// # Example
+///
+/// ```
+///# use matrix::Matrix;
+/// let step = Matrix::from([[1, 2], [3, 4]]);
+/// let mut total = Matrix::from([[0, 0], [0, 0]]);
+/// for _ in 0..3 {
+///     total += &step;
+/// }
+/// assert_eq!(total, Matrix::from([[3, 6], [9, 12]]));
+/// ```
+impl<C, const ROWS: usize, const COLS: usize> AddAssign<&Matrix<C, ROWS, COLS>>
+    for Matrix<C, ROWS, COLS>
+where
+    C: AddAssign + Clone,
+{
+    fn add_assign(&mut self, other: &Matrix<C, ROWS, COLS>) {
+        self.data
+            .iter_mut()
+            .zip(other.data.iter())
+            .for_each(|(row_a, row_b)| {
+                row_a
+                    .iter_mut()
+                    .zip(row_b.iter())
+                    .for_each(|(a, b)| *a += b.clone())
+            });
+    }
+}
+
+///Matrix addition, they must be of the same size
+impl<C, const ROWS: usize, const COLS: usize> Add<Matrix<C, ROWS, COLS>> for Matrix<C, ROWS, COLS>
+where
+    C: AddAssign + Clone,
+{
+    type Output = Matrix<C, ROWS, COLS>;
+    fn add(mut self, other: Matrix<C, ROWS, COLS>) -> Self::Output {
+        self += other;
+        self
+    }
+}
+
+///Matrix subtraction, they must be of the same size
+impl<C, const ROWS: usize, const COLS: usize> SubAssign<Matrix<C, ROWS, COLS>>
+    for Matrix<C, ROWS, COLS>
+where
+    C: SubAssign + Clone,
+{
+    fn sub_assign(&mut self, other: Matrix<C, ROWS, COLS>) {
+        self.data
+            .iter_mut()
+            .zip(other.data.iter())
+            .for_each(|(row_a, row_b)| {
+                row_a
+                    .iter_mut()
+                    .zip(row_b.iter())
+                    .for_each(|(a, b)| *a -= b.clone())
+            });
+    }
+}
+
+///Matrix subtraction, they must be of the same size
+impl<C, const ROWS: usize, const COLS: usize> Sub<Matrix<C, ROWS, COLS>> for Matrix<C, ROWS, COLS>
+where
+    C: SubAssign + Clone,
+{
+    type Output = Matrix<C, ROWS, COLS>;
+    fn sub(mut self, other: Matrix<C, ROWS, COLS>) -> Self::Output {
+        self -= other;
+        self
+    }
+}
+
+///Matrix addition of references, they must be of the same size
+impl<C, const ROWS: usize, const COLS: usize> Add<&Matrix<C, ROWS, COLS>> for &Matrix<C, ROWS, COLS>
+where
+    C: AddAssign + Clone,
+{
+    type Output = Matrix<C, ROWS, COLS>;
+    fn add(self, other: &Matrix<C, ROWS, COLS>) -> Self::Output {
+        let mut m = self.clone();
+        m += other;
+        m
+    }
+}
+
+///Matrix subtraction of references, they must be of the same size
+impl<C, const ROWS: usize, const COLS: usize> Sub<&Matrix<C, ROWS, COLS>> for &Matrix<C, ROWS, COLS>
+where
+    C: SubAssign + Clone,
+{
+    type Output = Matrix<C, ROWS, COLS>;
+    fn sub(self, other: &Matrix<C, ROWS, COLS>) -> Self::Output {
+        self.clone() - other.clone()
+    }
+}
+
+///Matrix negation, every coefficient is negated
+impl<C, const ROWS: usize, const COLS: usize> Neg for Matrix<C, ROWS, COLS>
+where
+    C: Neg<Output = C>,
+{
+    type Output = Matrix<C, ROWS, COLS>;
+    fn neg(self) -> Self::Output {
+        Matrix {
+            data: self.data.map(|line| line.map(|c| -c)),
+        }
+    }
+}
+
+///Matrix product. The implementation garuantees matrix compatibility at compile-time. If it compiles, it'll succeed.
+///
+/// # Commutativity
+///
+///Since matrix product is not commutative, the order matters.
+/// ```
+///# use matrix::Matrix;
+/// let left = Matrix::from([[1, 2], [6, 7]]);
+/// let right = Matrix::from([[3, 4], [5, 8]]);
+/// assert_ne!(right.clone()*left.clone(), left*right)
+/// ```
+///
+/// If the matrixes aren't square that also means you need to pay attention to their dimensions.
+/// If the result of the product is of dimension *n\*p*, then the *left* matrix needs to be of dimension *n\*q* and the *right* one *q\*p*.
+///
+/// ```compile_fail
+///# use matrix::Matrix;
+/// let left = Matrix::from([[1, 2], [3, 4], [5, 6]]);
+/// let right = Matrix::from([[9, 8, 7], [6, 5, 4]]);
+/// right * left //<- this fails because right is of dimension 2*3 and left 3*2
+/// ```
+///
+/// This however will compile.
+/// ```
+///# use matrix::Matrix;
+/// let left = Matrix::from([[1, 2], [3, 4], [5, 6]]);
+/// let right = Matrix::from([[9, 8, 7], [6, 5, 4]]);
+/// assert_eq!(left*right, Matrix::from([[21, 18, 15], [51, 44, 37], [81, 70, 59]]));
+///```
+impl<C, const ROWS: usize, const COLS: usize, const Q: usize> Mul<Matrix<C, Q, COLS>>
+    for Matrix<C, ROWS, Q>
+where
+    C: Add + Mul<C, Output = C> + Sum + Clone,
+{
+    type Output = Matrix<C, ROWS, COLS>;
+    fn mul(self, other: Matrix<C, Q, COLS>) -> Self::Output {
+        &self * &other
+    }
+}
+
+///Matrix product of references, see the implementation for owned matrices.
+///
+/// # Example
+///
+/// ```
+///# use matrix::Matrix;
+/// let left = Matrix::from([[1, 2], [3, 4]]);
+/// let right = Matrix::from([[0, 1], [1, 0]]);
+/// assert_eq!(&left * &right, Matrix::from([[2, 1], [4, 3]]));
+/// assert_eq!(&left * &left, Matrix::from([[7, 10], [15, 22]]));
+///```
+impl<C, const ROWS: usize, const COLS: usize, const Q: usize> Mul<&Matrix<C, Q, COLS>>
+    for &Matrix<C, ROWS, Q>
+where
+    C: Add + Mul<C, Output = C> + Sum + Clone,
+{
+    type Output = Matrix<C, ROWS, COLS>;
+    fn mul(self, other: &Matrix<C, Q, COLS>) -> Self::Output {
+        #[cfg(feature = "alloc")]
+        if ROWS > 1 && Q * COLS * core::mem::size_of::<C>() > BLOCKED_MUL_THRESHOLD {
+            return blocked_mul(self, other);
+        }
+        Matrix::from_fn(|row, col| {
+            self.data[row]
+                .iter()
+                .zip(other.data.iter())
+                .map(|(a, line)| a.clone() * line[col].clone())
+                .sum()
+        })
+    }
+}
+
+//Size in bytes of the right operand above which walking its columns thrashes the L1 cache.
+#[cfg(feature = "alloc")]
+const BLOCKED_MUL_THRESHOLD: usize = 32 * 1024;
+//Number of rows, columns and terms of the tiles computed by `blocked_mul`.
+#[cfg(feature = "alloc")]
+const MUL_TILE: usize = 32;
+
+//Product for large matrices, computed by tiles of `MUL_TILE` rows, columns and terms so that the parts of both operands in use stay in cache.
+//The columns of `right` are first copied to contiguous memory. Each coefficient accumulates the terms of one tile after the other,
+//so that they are added in the same order as with the naive product.
+#[cfg(feature = "alloc")]
+fn blocked_mul<C, const ROWS: usize, const COLS: usize, const Q: usize>(
+    left: &Matrix<C, ROWS, Q>,
+    right: &Matrix<C, Q, COLS>,
+) -> Matrix<C, ROWS, COLS>
+where
+    C: Mul<C, Output = C> + Sum + Clone,
+{
+    let columns: Vec<C> = (0..COLS)
+        .flat_map(|col| right.data.iter().map(move |line| line[col].clone()))
+        .collect();
+    //the empty sum is the only zero `Sum` provides
+    let zero = || core::iter::empty::<C>().sum::<C>();
+    let mut result = Matrix::<C, ROWS, COLS>::from_fn(|_, _| zero());
+    for k_start in (0..Q).step_by(MUL_TILE) {
+        let k_end = Q.min(k_start + MUL_TILE);
+        for row_start in (0..ROWS).step_by(MUL_TILE) {
+            for col_start in (0..COLS).step_by(MUL_TILE) {
+                for row in row_start..ROWS.min(row_start + MUL_TILE) {
+                    let terms = &left.data[row][k_start..k_end];
+                    for col in col_start..COLS.min(col_start + MUL_TILE) {
+                        let column = &columns[col * Q + k_start..col * Q + k_end];
+                        let coefficient = &mut result.data[row][col];
+                        let partial = core::mem::replace(coefficient, zero());
+                        *coefficient = core::iter::once(partial)
+                            .chain(terms.iter().zip(column).map(|(a, b)| a.clone() * b.clone()))
+                            .sum();
+                    }
+                }
+            }
+        }
+    }
+    result
+}
+
+///Component-wise operations, working on matrices of all dimensions
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS>
+where
+    C: MulAssign + Clone,
+{
+    ///Returns the Hadamard product of two matrices, that is the product of their coefficients one by one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+    /// let mask = Matrix::from([[1, 0, 1], [0, 1, 0]]);
+    /// assert_eq!(mat.component_mul(&mask), Matrix::from([[1, 0, 3], [0, 5, 0]]));
+    /// ```
+    pub fn component_mul(&self, other: &Self) -> Self {
+        let mut m = self.clone();
+        m.component_mul_assign(other);
+        m
+    }
+
+    ///In-place variant of [`component_mul`].
+    ///
+    /// [`component_mul`]: #method.component_mul
+    pub fn component_mul_assign(&mut self, other: &Self) {
+        self.data
+            .iter_mut()
+            .zip(other.data.iter())
+            .for_each(|(row_a, row_b)| {
+                row_a
+                    .iter_mut()
+                    .zip(row_b.iter())
+                    .for_each(|(a, b)| *a *= b.clone())
+            });
+    }
+}
+
+///Operations whose result dimensions are computed from the operands' ones.
+///
+///These rely on `generic_const_exprs` and are only available with the `nightly` feature.
+///Because of the way this feature works, crates using these need to enable it too.
+#[cfg(feature = "nightly")]
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS>
+where
+    C: Mul<Output = C> + Clone,
+{
+    ///Returns the Kronecker product of two matrices.
+    ///
+    ///Each coefficient of `self` is replaced by the block made of its product with `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# #![allow(incomplete_features)]
+    ///# #![feature(generic_const_exprs)]
+    ///# use matrix::Matrix;
+    /// let left = Matrix::from([[1, 2], [3, 4]]);
+    /// let right = Matrix::from([[0, 1]]);
+    /// assert_eq!(left.kronecker(&right), Matrix::from([[0, 1, 0, 2], [0, 3, 0, 4]]));
+    /// ```
+    pub fn kronecker<const R2: usize, const C2: usize>(
+        &self,
+        other: &Matrix<C, R2, C2>,
+    ) -> Matrix<C, { ROWS * R2 }, { COLS * C2 }>
+    where
+        [(); ROWS * R2]:,
+        [(); COLS * C2]:,
+    {
+        Matrix {
+            data: core::array::from_fn(|i| {
+                core::array::from_fn(|j| {
+                    self.data[i / R2][j / C2].clone() * other.data[i % R2][j % C2].clone()
+                })
+            }),
+        }
+    }
+}
+
+///Some functions for Matrix that have coefficients to have nil and neutral product values.
+///This gives access to nil matrixes as well as the identity matrix
+impl<C, const SIZE: usize> Matrix<C, SIZE, SIZE>
+where
+    C: One + Zero,
+{
+    ///Returns the identity matrix of size `SIZE`.
+    /// Note that this often requires type annotation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let identity: Matrix<u8, 2, 2> = Matrix::identity();
+    /// assert_eq!(identity, [[1, 0], [0, 1]].into());
+    /// ```
+    pub fn identity() -> Self {
+        Matrix::from_fn(|row, col| if row == col { C::one() } else { C::zero() })
+    }
+
+    ///Returns the nil matrix of size `SIZE`.
+    /// Note that this often requires type annotation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let identity: Matrix<u8, 2, 2> = Matrix::nil();
+    /// assert_eq!(identity, [[0, 0], [0, 0]].into());
+    /// ```
+    pub fn nil() -> Self {
+        Matrix::from_fn(|_, _| C::zero())
+    }
+}
+
+///The nil matrix is the neutral element of the addition.
+///
+///Unlike [`nil`], this is available for matrices of all dimensions.
+///
+/// # Example
+///
+/// ```
+///# use matrix::Matrix;
+/// use num::traits::Zero;
+/// let nil = Matrix::<i32, 2, 3>::zero();
+/// assert!(nil.is_zero());
+/// assert_eq!(nil + Matrix::from([[1, 2, 3], [4, 5, 6]]), Matrix::from([[1, 2, 3], [4, 5, 6]]));
+/// ```
+///
+/// [`nil`]: struct.Matrix.html#method.nil
+impl<C, const ROWS: usize, const COLS: usize> Zero for Matrix<C, ROWS, COLS>
+where
+    C: Zero + AddAssign + Clone,
+{
+    fn zero() -> Self {
+        Matrix::from_fn(|_, _| C::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.iter().all(Zero::is_zero)
+    }
+}
+
+///The identity matrix is the neutral element of the matrix product.
+///
+/// # Example
+///
+/// ```
+///# use matrix::Matrix;
+/// use num::traits::One;
+/// let fibonacci = Matrix::from([[1, 1], [1, 0]]);
+/// assert_eq!(&fibonacci * &Matrix::one(), fibonacci);
+/// //generic numeric code relying on `One` works with matrices
+/// assert_eq!(num::pow(fibonacci, 10), Matrix::from([[89, 55], [55, 34]]));
+/// ```
+impl<C, const SIZE: usize> One for Matrix<C, SIZE, SIZE>
+where
+    C: Zero + One + Mul<C, Output = C> + Sum + Clone,
+{
+    fn one() -> Self {
+        Matrix::from_fn(|row, col| if row == col { C::one() } else { C::zero() })
+    }
+}
+
+///Sum of matrices, which is the nil matrix for an empty iterator.
+///
+/// # Example
+///
+/// ```
+///# use matrix::Matrix;
+/// let gradients = vec![Matrix::from([[1, 2]]), Matrix::from([[3, 4]]), Matrix::from([[5, 6]])];
+/// assert_eq!(gradients.into_iter().sum::<Matrix<_, 1, 2>>(), Matrix::from([[9, 12]]));
+/// ```
+impl<C, const ROWS: usize, const COLS: usize> Sum for Matrix<C, ROWS, COLS>
+where
+    C: Zero + AddAssign + Clone,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, m| acc + m)
+    }
+}
+
+///Sum of references to matrices, see the implementation for owned matrices.
+impl<'a, C, const ROWS: usize, const COLS: usize> Sum<&'a Matrix<C, ROWS, COLS>>
+    for Matrix<C, ROWS, COLS>
+where
+    C: Zero + AddAssign + Clone,
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |mut acc, m| {
+            acc += m;
+            acc
+        })
+    }
+}
+
+///Product of square matrices in the order of the iterator, which is the identity matrix for an empty iterator.
+///
+/// # Example
+///
+/// ```
+///# use matrix::Matrix;
+/// //transforms applied from right to left
+/// let frames = [Matrix::from([[1, 1], [0, 1]]), Matrix::from([[2, 0], [0, 1]])];
+/// assert_eq!(frames.iter().product::<Matrix<_, 2, 2>>(), Matrix::from([[2, 1], [0, 1]]));
+/// ```
+impl<C, const SIZE: usize> Product for Matrix<C, SIZE, SIZE>
+where
+    C: Zero + One + Mul<C, Output = C> + Sum + Clone,
+{
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, m| &acc * &m)
+    }
+}
+
+///Product of references to square matrices, see the implementation for owned matrices.
+impl<'a, C, const SIZE: usize> Product<&'a Matrix<C, SIZE, SIZE>> for Matrix<C, SIZE, SIZE>
+where
+    C: Zero + One + Mul<C, Output = C> + Sum + Clone,
+{
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, m| &acc * m)
+    }
+}
+
+impl<C, const SIZE: usize> Matrix<C, SIZE, SIZE>
+where
+    C: Clone,
+{
+    ///Returns the diagonal matrix whose diagonal is `diagonal`, all other coefficients being nil.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from_diagonal([1, 2, 3]);
+    /// assert_eq!(mat, Matrix::from([[1, 0, 0], [0, 2, 0], [0, 0, 3]]));
+    /// ```
+    pub fn from_diagonal(diagonal: [C; SIZE]) -> Self
+    where
+        C: Zero,
+    {
+        Matrix::from_fn(|row, col| {
+            if row == col {
+                diagonal[row].clone()
+            } else {
+                C::zero()
+            }
+        })
+    }
+
+    ///Returns the coefficients of the diagonal of the matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1, 2, 1], [3, 4, 1], [1, 5, 6]]);
+    /// assert_eq!(mat.diagonal(), [1, 4, 6]);
+    /// ```
+    pub fn diagonal(&self) -> [C; SIZE] {
+        core::array::from_fn(|i| self.data[i][i].clone())
+    }
+}
+
+impl<C, const SIZE: usize> Matrix<C, SIZE, SIZE>
+where
+    C: Sum + Clone,
+{
+    ///Returns the trace of the matrix, that is the sum of its diagonal coefficients.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1, 2, 1], [3, 4, 1], [1, 5, 6]]);
+    /// assert_eq!(mat.trace(), 11);
+    /// ```
+    pub fn trace(&self) -> C {
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(i, line)| line[i].clone())
+            .sum()
+    }
+}
+
+impl<C, const SIZE: usize> Matrix<C, SIZE, SIZE>
+where
+    C: One + Zero + Add + Mul<Output = C> + Sum + Clone,
+{
+    ///Raises the matrix to the power `n`.
+    ///
+    ///This uses exponentiation by squaring and as such only needs a logarithmic number of matrix products.
+    ///Any matrix to the power 0 is the identity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let fibonacci = Matrix::from([[1u64, 1], [1, 0]]);
+    /// assert_eq!(fibonacci.pow(10), Matrix::from([[89, 55], [55, 34]]));
+    /// ```
+    pub fn pow(self, mut n: u32) -> Self {
+        let mut result = Self::identity();
+        let mut base = self;
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result * base.clone();
+            }
+            n >>= 1;
+            if n > 0 {
+                base = base.clone() * base;
+            }
+        }
+        result
+    }
+}
+
+///Matrix internal manipulation operations
+impl<'a, C: 'a, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS>
+where
+    C: Clone + MulAssign<&'a C> + AddAssign<&'a C>,
+{
+    /// Permutes two rows.
+    ///
+    /// Permutation is an operation that can be understood as swapping a line for another.
+    /// Returns an Error if either `source` of `target` is out of bounds, that is greater than `ROWS`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mut mat = Matrix::from([[1, 2, 1], [3, 4, 1], [5, 6, 1]]);
+    /// mat.permute(0, 1);
+    /// assert_eq!(mat, Matrix::from([[3, 4, 1], [1, 2, 1], [5, 6, 1]])); //look at the order of the lines
+    ///```
+    pub fn permute(&mut self, source: usize, target: usize) -> Result<(), Error> {
+        if (target >= ROWS) | (source >= ROWS) {
+            return Err(Error::OutOfBounds);
+        } else {
+            self.data.as_mut_slice().swap(source, target);
+        }
+        Ok(())
+    }
+
+    /// Dilates a row
+    ///
+    /// To dilate a row is to multiply all the coefficients of the row by a factor.
+    /// Returns an Error if `row` is out of bounds, that is greater than `ROWS`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mut mat = Matrix::from([[1, 2, 1], [3, 4, 1], [5, 6, 1]]);
+    /// mat.dilate(0, &2);
+    /// assert_eq!(mat, Matrix::from([[2, 4, 2], [3, 4, 1], [5, 6, 1]]));
+    ///```
+    pub fn dilate(&mut self, row: usize, factor: &'a C) -> Result<(), Error> {
+        match self.data.get_mut(row) {
+            None => Err(Error::OutOfBounds),
+            Some(line) => {
+                line.iter_mut().for_each(|c| *c *= factor);
+                Ok(())
+            }
+        }
+    }
+
+    /// Permutes two columns.
+    ///
+    /// This is the column counterpart of [`permute`].
+    /// Returns an Error if either `source` of `target` is out of bounds, that is greater than `COLS`.
+    ///
+    /// [`permute`]: #method.permute
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mut mat = Matrix::from([[1, 2, 1], [3, 4, 1], [5, 6, 1]]);
+    /// mat.permute_cols(0, 1);
+    /// assert_eq!(mat, Matrix::from([[2, 1, 1], [4, 3, 1], [6, 5, 1]]));
+    ///```
+    pub fn permute_cols(&mut self, source: usize, target: usize) -> Result<(), Error> {
+        if (target >= COLS) | (source >= COLS) {
+            return Err(Error::OutOfBounds);
+        }
+        self.data
+            .iter_mut()
+            .for_each(|line| line.swap(source, target));
+        Ok(())
+    }
+
+    /// Dilates a column
+    ///
+    /// This is the column counterpart of [`dilate`].
+    /// Returns an Error if `col` is out of bounds, that is greater than `COLS`.
+    ///
+    /// [`dilate`]: #method.dilate
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mut mat = Matrix::from([[1, 2, 1], [3, 4, 1], [5, 6, 1]]);
+    /// mat.dilate_col(0, &2);
+    /// assert_eq!(mat, Matrix::from([[2, 2, 1], [6, 4, 1], [10, 6, 1]]));
+    ///```
+    pub fn dilate_col(&mut self, col: usize, factor: &'a C) -> Result<(), Error> {
+        if col >= COLS {
+            return Err(Error::OutOfBounds);
+        }
+        self.data.iter_mut().for_each(|line| line[col] *= factor);
+        Ok(())
+    }
+}
+
+//Separated from the previous impl because of the need of HRTB
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS>
+where
+    for<'a> C: Clone + MulAssign<&'a C> + AddAssign<&'a C>,
+{
+    /// Applies a transvection from a row to another
+    ///
+    /// Tto transvect is to add a row to another, coefficient by coefficient.
+    /// Returns OutOfBounds if either `source` of `other` is out of bounds, that is greater than `ROWS`.
+    ///
+    /// WrongOperation is returned if `source` and `other` are the same row.
+    /// If you encounter this issue, use [`dilate`] instead.
+    ///
+    /// [`dilate`]: #method.dilate
     ///
     /// # Examples
     ///
@@ -304,18 +1465,13 @@ where
     /// assert_eq!(mat, Matrix::from([[6, 8, 2], [3, 4, 1], [5, 6, 1]]));
     ///```
     pub fn transvect(&mut self, source: usize, other: usize) -> Result<(), Error> {
-        if (other >= SIZE) | (source >= SIZE) {
+        if (other >= ROWS) | (source >= ROWS) {
             return Err(Error::OutOfBounds);
         } else if other == source {
             //consider calling dilate instead in release
             return Err(Error::WrongOperation);
         } else {
-            let slices = self.data.as_mut_slice().split_at_mut(source.max(other));
-            let (begin, end) = if source > other {
-                (&mut slices.1[0], &mut slices.0[other])
-            } else {
-                (&mut slices.0[source], &mut slices.1[0])
-            };
+            let (begin, end) = self.line_pair(source, other);
             begin
                 .iter_mut()
                 .enumerate()
@@ -324,12 +1480,480 @@ where
 
         Ok(())
     }
+
+    /// Applies a scaled transvection from a row to another
+    ///
+    /// This adds `factor` times the `other` row to the `source` row, coefficient by coefficient.
+    /// It is the elementary operation gaussian elimination is made of.
+    /// Returns OutOfBounds if either `source` of `other` is out of bounds, that is greater than `ROWS`.
+    ///
+    /// WrongOperation is returned if `source` and `other` are the same row.
+    /// If you encounter this issue, use [`dilate`] instead.
+    ///
+    /// [`dilate`]: #method.dilate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mut mat = Matrix::from([[1, 2, 1], [3, 4, 1], [5, 6, 1]]);
+    /// mat.transvect_scaled(1, 0, &-3);
+    /// assert_eq!(mat, Matrix::from([[1, 2, 1], [0, -2, -2], [5, 6, 1]]));
+    ///```
+    pub fn transvect_scaled(
+        &mut self,
+        source: usize,
+        other: usize,
+        factor: &C,
+    ) -> Result<(), Error> {
+        if (other >= ROWS) | (source >= ROWS) {
+            return Err(Error::OutOfBounds);
+        } else if other == source {
+            return Err(Error::WrongOperation);
+        }
+        let (begin, end) = self.line_pair(source, other);
+        begin.iter_mut().zip(end.iter()).for_each(|(c, o)| {
+            let mut scaled = o.clone();
+            scaled *= factor;
+            *c += &scaled
+        });
+
+        Ok(())
+    }
+
+    //Borrows the `source` line mutably alongside the `other` line. Both must be distinct and in bounds.
+    fn line_pair(&mut self, source: usize, other: usize) -> (&mut [C; COLS], &[C; COLS]) {
+        let slices = self.data.as_mut_slice().split_at_mut(source.max(other));
+        if source > other {
+            (&mut slices.1[0], &slices.0[other])
+        } else {
+            (&mut slices.0[source], &slices.1[0])
+        }
+    }
+
+    /// Applies a transvection from a column to another
+    ///
+    /// This is the column counterpart of [`transvect`].
+    /// Returns OutOfBounds if either `source` of `other` is out of bounds, that is greater than `COLS`.
+    ///
+    /// WrongOperation is returned if `source` and `other` are the same column.
+    /// If you encounter this issue, use [`dilate_col`] instead.
+    ///
+    /// [`transvect`]: #method.transvect
+    /// [`dilate_col`]: #method.dilate_col
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mut mat = Matrix::from([[1, 2, 1], [3, 4, 1], [5, 6, 1]]);
+    /// mat.transvect_col(0, 2);
+    /// assert_eq!(mat, Matrix::from([[2, 2, 1], [4, 4, 1], [6, 6, 1]]));
+    ///```
+    pub fn transvect_col(&mut self, source: usize, other: usize) -> Result<(), Error> {
+        if (other >= COLS) | (source >= COLS) {
+            return Err(Error::OutOfBounds);
+        } else if other == source {
+            return Err(Error::WrongOperation);
+        }
+        for line in self.data.iter_mut() {
+            let (begin, end) = line.split_at_mut(source.max(other));
+            if source > other {
+                end[0] += &begin[other];
+            } else {
+                begin[source] += &end[0];
+            }
+        }
+
+        Ok(())
+    }
+}
+
+///Row reduction for matrices of any shape over a field.
+///
+///The coefficients need to be signed so that the pivot can be chosen by absolute value.
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS> {
+    //Returns the row, starting at `from`, whose coefficient in `col` has the biggest `magnitude`.
+    fn pivot_by<M, F>(&self, col: usize, from: usize, magnitude: &F) -> usize
+    where
+        M: PartialOrd,
+        F: Fn(&C) -> M,
+    {
+        (from..ROWS)
+            .max_by(|&i, &j| {
+                magnitude(&self.data[i][col])
+                    .partial_cmp(&magnitude(&self.data[j][col]))
+                    .unwrap_or(Ordering::Equal)
+            })
+            .unwrap_or(from)
+    }
+}
+
+//Row reduction kernel shared with the coefficients which aren't `Signed`, such as complex numbers.
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS>
+where
+    C: Num + Clone,
+{
+    //Gauss-Jordan elimination choosing pivots by `magnitude`, those for which `is_nil` holds being skipped.
+    //Returns whether each column holds a pivot, the `k`-th pivot column having its pivot on row `k`.
+    pub(crate) fn reduce_pivots_by<M, F, N>(&mut self, is_nil: N, magnitude: F) -> [bool; COLS]
+    where
+        M: PartialOrd,
+        F: Fn(&C) -> M,
+        N: Fn(&C) -> bool,
+    {
+        let mut pivots = [false; COLS];
+        let mut pivot_row = 0;
+        for col in 0..COLS {
+            if pivot_row == ROWS {
+                break;
+            }
+            let pivot = self.pivot_by(col, pivot_row, &magnitude);
+            if is_nil(&self.data[pivot][col]) {
+                continue;
+            }
+            self.data.swap(pivot_row, pivot);
+
+            let factor = self.data[pivot_row][col].clone();
+            self.data[pivot_row]
+                .iter_mut()
+                .for_each(|c| *c = c.clone() / factor.clone());
+            let pivot_line = self.data[pivot_row].clone();
+            self.data
+                .iter_mut()
+                .enumerate()
+                .filter(|(i, _)| *i != pivot_row)
+                .for_each(|(_, line)| {
+                    let factor = line[col].clone();
+                    line.iter_mut()
+                        .zip(pivot_line.iter())
+                        .for_each(|(c, p)| *c = c.clone() - factor.clone() * p.clone());
+                });
+            pivots[col] = true;
+            pivot_row += 1;
+        }
+        pivots
+    }
+}
+
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS>
+where
+    C: Signed + PartialOrd + Clone,
+{
+    /// Reduces the matrix to its reduced row echelon form, in place
+    ///
+    /// This is a full Gauss-Jordan elimination with partial pivoting: every pivot ends up being one and is the only non-nil coefficient of its column.
+    /// See [`rref`] for examples.
+    ///
+    /// [`rref`]: #method.rref
+    pub fn reduce(&mut self) {
+        self.reduce_with_tolerance(&C::zero());
+    }
+
+    //Gauss-Jordan elimination where coefficients no bigger than `tolerance` are considered nil.
+    //Returns the number of pivots found, that is the rank of the matrix.
+    fn reduce_with_tolerance(&mut self, tolerance: &C) -> usize {
+        self.reduce_pivots(tolerance)
+            .iter()
+            .filter(|&&pivot| pivot)
+            .count()
+    }
+
+    //Gauss-Jordan elimination where coefficients no bigger than `tolerance` are considered nil.
+    //Returns whether each column holds a pivot, the `k`-th pivot column having its pivot on row `k`.
+    pub(crate) fn reduce_pivots(&mut self, tolerance: &C) -> [bool; COLS] {
+        self.reduce_pivots_by(|c| c.abs() <= *tolerance, |c| c.abs())
+    }
+
+    /// Returns the reduced row echelon form of the matrix
+    ///
+    /// See [`reduce`] for the in-place variant.
+    ///
+    /// [`reduce`]: #method.reduce
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[2.0, 4.0, 2.0], [1.0, 3.0, 2.0]]);
+    /// assert_eq!(mat.rref(), Matrix::from([[1.0, 0.0, -1.0], [0.0, 1.0, 1.0]]));
+    ///```
+    pub fn rref(&self) -> Self {
+        let mut m = self.clone();
+        m.reduce();
+        m
+    }
+
+    /// Returns the rank of the matrix
+    ///
+    /// The rank is the number of linearly independent rows, computed by counting the pivots of the row reduction.
+    /// Because of rounding errors this is not reliable for floating point coefficients, use [`rank_with_tolerance`] instead.
+    ///
+    /// [`rank_with_tolerance`]: #method.rank_with_tolerance
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]]);
+    /// assert_eq!(mat.rank(), 1);
+    ///```
+    pub fn rank(&self) -> usize {
+        self.clone().reduce_with_tolerance(&C::zero())
+    }
+
+    /// Returns the rank of the matrix, considering any coefficient no bigger than `tolerance` in absolute value as nil
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1.0, 2.0], [1.0, 2.0 + 1e-12]]);
+    /// assert_eq!(mat.rank(), 2);
+    /// assert_eq!(mat.rank_with_tolerance(&1e-9), 1);
+    ///```
+    pub fn rank_with_tolerance(&self, tolerance: &C) -> usize {
+        self.clone().reduce_with_tolerance(tolerance)
+    }
+}
+
+///Linear algebra for square matrices over a field.
+///
+///The coefficients need to be signed so that the pivot can be chosen by absolute value.
+impl<C, const SIZE: usize> Matrix<C, SIZE, SIZE>
+where
+    C: Signed + PartialOrd + Clone,
+{
+    /// Returns the determinant of the matrix
+    ///
+    /// The matrix is brought to an upper triangular form through gaussian elimination with partial pivoting,
+    /// the determinant being the product of the pivots, negated for each row swap.
+    /// For floats, rounding errors make the determinant of a singular matrix tiny rather than nil.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let a: Matrix<f64, 3, 3> = Matrix::from([[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [2.0, 0.0, 4.0]]);
+    /// assert!((a.determinant() + 10.0).abs() < 1e-12);
+    /// assert_eq!(Matrix::from([[1.0, 2.0], [2.0, 4.0]]).determinant(), 0.0);
+    ///```
+    pub fn determinant(&self) -> C {
+        self.determinant_by(|c| c.abs())
+    }
+
+    /// Solves the linear system `self * x = b`
+    ///
+    /// The system is solved through gaussian elimination with partial pivoting followed by back substitution.
+    /// Returns Singular if the matrix has no inverse, in which case the system has either no or infinitely many solutions.
+    ///
+    /// Singular is only returned when a pivot is exactly nil. Because of rounding errors, a singular matrix of floats
+    /// usually has tiny pivots instead and gets a meaningless solution with huge coefficients.
+    /// Check [`condition_number`] or [`rank_with_tolerance`] first when the matrix may be close to singular.
+    ///
+    /// [`condition_number`]: #method.condition_number
+    /// [`rank_with_tolerance`]: #method.rank_with_tolerance
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let a = Matrix::from([[2.0, 1.0], [1.0, 3.0]]);
+    /// let x = a.solve([[5.0], [10.0]].into()).unwrap();
+    /// assert_eq!(x, Matrix::from([[1.0], [3.0]]));
+    ///
+    /// // singular, but its pivots aren't exactly nil once rounded
+    /// let singular = Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    /// assert!(singular.solve([[1.0], [1.0], [1.0]].into()).is_ok());
+    /// assert!(singular.condition_number() > 1e15);
+    /// assert_eq!(singular.rank_with_tolerance(&1e-9), 2);
+    ///```
+    pub fn solve(&self, b: Matrix<C, SIZE, 1>) -> Result<Matrix<C, SIZE, 1>, Error> {
+        self.solve_by(b, |c| c.abs())
+    }
+
+    /// Returns the inverse of the matrix
+    ///
+    /// The inverse is computed through Gauss-Jordan elimination with partial pivoting.
+    /// Returns Singular if the matrix has no inverse, which is only detected through exactly nil pivots:
+    /// see [`solve`] for the matrices of floats which are singular up to rounding errors.
+    ///
+    /// [`solve`]: #method.solve
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let a = Matrix::from([[2.0, 1.0], [4.0, 3.0]]);
+    /// assert_eq!(a.inverse(), Ok(Matrix::from([[1.5, -0.5], [-2.0, 1.0]])));
+    ///```
+    pub fn inverse(&self) -> Result<Self, Error> {
+        self.inverse_by(|c| c.abs())
+    }
+}
+
+//Elimination kernels shared with the coefficients which aren't `Signed`, such as complex numbers.
+//The pivot of each column is the remaining coefficient with the biggest `magnitude`.
+impl<C, const SIZE: usize> Matrix<C, SIZE, SIZE>
+where
+    C: Num + Neg<Output = C> + Clone,
+{
+    pub(crate) fn determinant_by<M, F>(&self, magnitude: F) -> C
+    where
+        M: PartialOrd,
+        F: Fn(&C) -> M,
+    {
+        let mut a = self.clone();
+        let mut determinant = C::one();
+        for col in 0..SIZE {
+            let pivot = a.pivot_by(col, col, &magnitude);
+            if a.data[pivot][col].is_zero() {
+                return C::zero();
+            }
+            if pivot != col {
+                a.data.swap(col, pivot);
+                determinant = -determinant;
+            }
+            determinant = determinant * a.data[col][col].clone();
+
+            let (top, bottom) = a.data.split_at_mut(col + 1);
+            let pivot_line = &top[col];
+            for line in bottom.iter_mut() {
+                let factor = line[col].clone() / pivot_line[col].clone();
+                line.iter_mut()
+                    .zip(pivot_line.iter())
+                    .skip(col)
+                    .for_each(|(c, p)| *c = c.clone() - factor.clone() * p.clone());
+            }
+        }
+        determinant
+    }
+
+    pub(crate) fn solve_by<M, F>(
+        &self,
+        b: Matrix<C, SIZE, 1>,
+        magnitude: F,
+    ) -> Result<Matrix<C, SIZE, 1>, Error>
+    where
+        M: PartialOrd,
+        F: Fn(&C) -> M,
+    {
+        let mut a = self.clone();
+        let mut x = b.data;
+        for col in 0..SIZE {
+            let pivot = a.pivot_by(col, col, &magnitude);
+            if a.data[pivot][col].is_zero() {
+                return Err(Error::Singular);
+            }
+            a.data.swap(col, pivot);
+            x.swap(col, pivot);
+
+            let (top, bottom) = a.data.split_at_mut(col + 1);
+            let (x_top, x_bottom) = x.split_at_mut(col + 1);
+            let pivot_line = &top[col];
+            for (line, b) in bottom.iter_mut().zip(x_bottom.iter_mut()) {
+                let factor = line[col].clone() / pivot_line[col].clone();
+                line.iter_mut()
+                    .zip(pivot_line.iter())
+                    .skip(col)
+                    .for_each(|(c, p)| *c = c.clone() - factor.clone() * p.clone());
+                b[0] = b[0].clone() - factor * x_top[col][0].clone();
+            }
+        }
+
+        for row in (0..SIZE).rev() {
+            let acc = (row + 1..SIZE).fold(x[row][0].clone(), |acc, k| {
+                acc - a.data[row][k].clone() * x[k][0].clone()
+            });
+            x[row][0] = acc / a.data[row][row].clone();
+        }
+        Ok(x.into())
+    }
+
+    pub(crate) fn inverse_by<M, F>(&self, magnitude: F) -> Result<Self, Error>
+    where
+        M: PartialOrd,
+        F: Fn(&C) -> M,
+    {
+        let mut a = self.clone();
+        let mut inverse = Matrix::from_fn(|row, col| if row == col { C::one() } else { C::zero() });
+        for col in 0..SIZE {
+            let pivot = a.pivot_by(col, col, &magnitude);
+            if a.data[pivot][col].is_zero() {
+                return Err(Error::Singular);
+            }
+            a.data.swap(col, pivot);
+            inverse.data.swap(col, pivot);
+
+            let factor = a.data[col][col].clone();
+            a.data[col]
+                .iter_mut()
+                .chain(inverse.data[col].iter_mut())
+                .for_each(|c| *c = c.clone() / factor.clone());
+            let pivot_line = a.data[col].clone();
+            let inverse_line = inverse.data[col].clone();
+            for (row, (line, inverse)) in a.data.iter_mut().zip(inverse.data.iter_mut()).enumerate()
+            {
+                if row == col {
+                    continue;
+                }
+                let factor = line[col].clone();
+                line.iter_mut()
+                    .zip(pivot_line.iter())
+                    .chain(inverse.iter_mut().zip(inverse_line.iter()))
+                    .for_each(|(c, p)| *c = c.clone() - factor.clone() * p.clone());
+            }
+        }
+        Ok(inverse)
+    }
 }
 
-#[derive(Debug, Error, PartialEq, Eq)]
+///Errors of the fallible operations.
+///
+///`Parse` and `Io` only exist with the `std` feature. As features must be additive, the enum is non exhaustive:
+///matching on it needs a wildcard arm, which keeps compiling whichever crate of the dependency graph enables `std`.
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Error {
-    #[error("invalid row: out of bounds")]
     OutOfBounds,
-    #[error("there is an operation better suited for this")]
     WrongOperation,
+    Singular,
+    NilVector,
+    RankDeficient,
+    DimensionMismatch,
+    WrongLength,
+    NotAPermutation,
+    NoConvergence,
+    NotPositiveDefinite,
+    #[cfg(feature = "std")]
+    Parse(String),
+    #[cfg(feature = "std")]
+    Io(String),
+}
+
+//Written by hand rather than derived so that the crate doesn't depend on `std`.
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::OutOfBounds => write!(f, "invalid row or column: out of bounds"),
+            Error::WrongOperation => write!(f, "there is an operation better suited for this"),
+            Error::Singular => write!(f, "the matrix is singular"),
+            Error::NilVector => write!(f, "the vector is nil"),
+            Error::RankDeficient => write!(f, "the vectors are linearly dependent"),
+            Error::DimensionMismatch => write!(f, "the dimensions of the matrices don't match"),
+            Error::WrongLength => {
+                write!(f, "the number of coefficients doesn't match the dimensions")
+            }
+            Error::NotAPermutation => write!(f, "the indices aren't a permutation"),
+            Error::NoConvergence => write!(f, "the iteration didn't converge"),
+            Error::NotPositiveDefinite => write!(f, "the matrix isn't positive definite"),
+            #[cfg(feature = "std")]
+            Error::Parse(message) => write!(f, "invalid input: {}", message),
+            #[cfg(feature = "std")]
+            Error::Io(message) => write!(f, "i/o error: {}", message),
+        }
+    }
 }
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
@@ -0,0 +1,229 @@
+//! Integers modulo `P`, so that matrices over ℤ/pℤ get exact linear algebra.
+
+use crate::Pivot;
+use core::fmt;
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+use num::traits::{Num, One, Zero};
+
+///Integer modulo `P`, represented by its remainder in `0..P`.
+///
+///When `P` is prime every non-nil value has an inverse, so matrices of `Mod<P>` can use the linear algebra of fields
+///provided by [`FieldLinearAlgebra`]. The field isn't ordered, so the values are neither signed nor comparable.
+///
+///For other moduli, dividing by a value which isn't coprime to `P` panics.
+///
+/// [`FieldLinearAlgebra`]: crate::FieldLinearAlgebra
+///
+/// # Example
+///
+/// ```
+///# use matrix::{Matrix, Mod};
+/// use matrix::FieldLinearAlgebra;
+/// let a = Matrix::from([[2, 3], [1, 4]]).map(Mod::<7>::new);
+/// assert_eq!(a.determinant(), Mod::new(5));
+/// let inverse = a.inverse().unwrap();
+/// assert_eq!(&a * &inverse, Matrix::identity());
+/// assert_eq!(inverse, Matrix::from([[5, 5], [4, 6]]).map(Mod::new));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Mod<const P: u64>(u64);
+
+impl<const P: u64> Mod<P> {
+    //Referencing this fails to compile if there is no integer modulo `P`.
+    const VALID_MODULUS: () = assert!(P > 1, "the modulus must be at least 2");
+
+    ///Returns `value` modulo `P`.
+    pub fn new(value: u64) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_MODULUS;
+        Mod(value % P)
+    }
+
+    ///Returns the representative of the value in `0..P`.
+    pub fn value(self) -> u64 {
+        self.0
+    }
+
+    ///Returns the value raised to the power `exponent`, computed by squaring.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Mod;
+    /// // Fermat's little theorem
+    /// assert_eq!(Mod::<13>::new(5).pow(12), Mod::new(1));
+    /// ```
+    pub fn pow(self, mut exponent: u64) -> Self {
+        let mut base = self;
+        let mut result = Self::one();
+        while exponent > 0 {
+            if exponent % 2 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exponent /= 2;
+        }
+        result
+    }
+
+    ///Returns the multiplicative inverse of the value, or `None` if it isn't coprime to `P`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Mod;
+    /// assert_eq!(Mod::<11>::new(3).inverse(), Some(Mod::new(4)));
+    /// assert_eq!(Mod::<12>::new(3).inverse(), None);
+    /// ```
+    pub fn inverse(self) -> Option<Self> {
+        //extended Euclidean algorithm, keeping the Bézout coefficient of the value only
+        let (mut r0, mut r1) = (i128::from(P), i128::from(self.0));
+        let (mut t0, mut t1) = (0i128, 1i128);
+        while r1 != 0 {
+            let q = r0 / r1;
+            (r0, r1) = (r1, r0 - q * r1);
+            (t0, t1) = (t1, t0 - q * t1);
+        }
+        if r0 != 1 {
+            return None;
+        }
+        Some(Mod(t0.rem_euclid(i128::from(P)) as u64))
+    }
+}
+
+impl<const P: u64> From<u64> for Mod<P> {
+    fn from(value: u64) -> Self {
+        Mod::new(value)
+    }
+}
+
+///Reduces a signed integer, negative values being mapped to their positive representative.
+impl<const P: u64> From<i64> for Mod<P> {
+    fn from(value: i64) -> Self {
+        Mod::new(i128::from(value).rem_euclid(i128::from(P)) as u64)
+    }
+}
+
+impl<const P: u64> fmt::Display for Mod<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<const P: u64> Add for Mod<P> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Mod(((u128::from(self.0) + u128::from(other.0)) % u128::from(P)) as u64)
+    }
+}
+
+impl<const P: u64> Sub for Mod<P> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl<const P: u64> Neg for Mod<P> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Mod((P - self.0) % P)
+    }
+}
+
+impl<const P: u64> Mul for Mod<P> {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        Mod(((u128::from(self.0) * u128::from(other.0)) % u128::from(P)) as u64)
+    }
+}
+
+///Multiplication by the inverse of `other`.
+///
+/// # Panics
+///
+/// If `other` has no inverse, which is only the case of zero when `P` is prime.
+impl<const P: u64> Div for Mod<P> {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Self) -> Self {
+        self * other
+            .inverse()
+            .expect("division by a value with no inverse")
+    }
+}
+
+///Remainder of the division, always zero since the division is exact.
+///
+/// # Panics
+///
+/// If `other` has no inverse, like the division.
+impl<const P: u64> Rem for Mod<P> {
+    type Output = Self;
+    fn rem(self, other: Self) -> Self {
+        other
+            .inverse()
+            .expect("division by a value with no inverse");
+        Self::zero()
+    }
+}
+
+macro_rules! impl_assign {
+    ($($assign:ident, $method:ident, $op:tt);*) => {
+        $(
+            impl<const P: u64> $assign for Mod<P> {
+                fn $method(&mut self, other: Self) {
+                    *self = *self $op other;
+                }
+            }
+        )*
+    };
+}
+
+impl_assign!(AddAssign, add_assign, +; SubAssign, sub_assign, -; MulAssign, mul_assign, *; DivAssign, div_assign, /);
+
+impl<const P: u64> Zero for Mod<P> {
+    fn zero() -> Self {
+        Mod::new(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<const P: u64> One for Mod<P> {
+    fn one() -> Self {
+        Mod::new(1)
+    }
+}
+
+impl<const P: u64> Num for Mod<P> {
+    type FromStrRadixErr = core::num::ParseIntError;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        u64::from_str_radix(s, radix).map(Mod::new)
+    }
+}
+
+///Computations are exact, so any non-nil value is a suitable pivot.
+impl<const P: u64> Pivot for Mod<P> {
+    type Magnitude = bool;
+
+    fn magnitude(&self) -> bool {
+        !self.is_zero()
+    }
+}
+
+impl<const P: u64> Sum for Mod<P> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+impl<const P: u64> Product for Mod<P> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), Mul::mul)
+    }
+}
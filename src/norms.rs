@@ -0,0 +1,104 @@
+//! Matrix norms for floating point coefficients.
+
+use crate::Matrix;
+use num::traits::{Float, Signed};
+
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS>
+where
+    C: Float,
+{
+    ///Returns the Frobenius norm, that is the square root of the sum of the squares of all coefficients.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1.0, -2.0], [2.0, 4.0]]);
+    /// assert_eq!(mat.norm_frobenius(), 5.0);
+    /// ```
+    pub fn norm_frobenius(&self) -> C {
+        self.iter().fold(C::zero(), |acc, c| acc + *c * *c).sqrt()
+    }
+
+    ///Returns the 1-norm, that is the biggest sum of the absolute values of a column.
+    ///
+    ///Like the infinity and max norms, it is NaN as soon as a coefficient is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1.0, -2.0], [2.0, 4.0]]);
+    /// assert_eq!(mat.norm_one(), 6.0);
+    /// ```
+    pub fn norm_one(&self) -> C {
+        self.get_columns()
+            .map(|col| col.iter().fold(C::zero(), |acc, c| acc + c.abs()))
+            .fold(C::zero(), max)
+    }
+
+    ///Returns the infinity norm, that is the biggest sum of the absolute values of a row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1.0, -2.0], [2.0, 4.0]]);
+    /// assert_eq!(mat.norm_inf(), 6.0);
+    /// assert_eq!(mat.norm_inf(), mat.norm_one()); // this isn't true in general
+    /// ```
+    pub fn norm_inf(&self) -> C {
+        self.get_lines()
+            .map(|line| line.iter().fold(C::zero(), |acc, c| acc + c.abs()))
+            .fold(C::zero(), max)
+    }
+
+    ///Returns the max norm, that is the biggest absolute value of a coefficient.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1.0, -5.0], [2.0, 4.0]]);
+    /// assert_eq!(mat.norm_max(), 5.0);
+    /// ```
+    pub fn norm_max(&self) -> C {
+        self.iter().fold(C::zero(), |acc, c| max(acc, c.abs()))
+    }
+}
+
+impl<C, const SIZE: usize> Matrix<C, SIZE, SIZE>
+where
+    C: Float + Signed,
+{
+    ///Returns the condition number of the matrix for the 1-norm, that is `‖A‖₁‖A⁻¹‖₁`.
+    ///
+    ///The bigger it is, the more the solution of a linear system is sensitive to errors on the coefficients.
+    ///Singular matrices have an infinite condition number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let well_conditioned = Matrix::from([[2.0, 0.0], [0.0, 1.0]]);
+    /// assert_eq!(well_conditioned.condition_number(), 2.0);
+    /// let singular = Matrix::from([[1.0, 2.0], [2.0, 4.0]]);
+    /// assert_eq!(singular.condition_number(), f64::INFINITY);
+    /// ```
+    pub fn condition_number(&self) -> C {
+        match self.inverse() {
+            Ok(inverse) => self.norm_one() * inverse.norm_one(),
+            Err(_) => C::infinity(),
+        }
+    }
+}
+
+//Returns the biggest of two values, or NaN if one of them is, unlike `Float::max` which ignores NaN.
+//Norms rely on this so that stopping criteria comparing them to a tolerance never mistake NaN for convergence.
+pub(crate) fn max<C: Float>(a: C, b: C) -> C {
+    if a.is_nan() || b.is_nan() {
+        C::nan()
+    } else {
+        a.max(b)
+    }
+}
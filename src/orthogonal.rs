@@ -0,0 +1,277 @@
+//! Orthogonalization and orthogonal transformations for floating point coefficients.
+
+use crate::{ColVector, Error, Matrix};
+use num::traits::Float;
+
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS>
+where
+    C: Float,
+{
+    ///Returns a matrix whose columns are an orthonormal basis of the space spanned by the columns of `self`.
+    ///
+    ///This uses the modified Gram-Schmidt process, the `n` first columns of the result span the same space as the `n` first columns of `self`.
+    ///Returns RankDeficient if the columns are linearly dependent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[3.0, 1.0], [4.0, 2.0], [0.0, 0.0]]);
+    /// let q = mat.orthogonalize().unwrap();
+    /// assert!((&q.transpose() * &q).approx_eq(&Matrix::identity(), 1e-12));
+    /// ```
+    pub fn orthogonalize(&self) -> Result<Self, Error> {
+        Ok(self.transpose().orthogonalize_rows()?.transpose())
+    }
+
+    ///Returns a matrix whose rows are an orthonormal basis of the space spanned by the rows of `self`.
+    ///
+    ///See [`orthogonalize`] for details.
+    ///
+    /// [`orthogonalize`]: #method.orthogonalize
+    pub fn orthogonalize_rows(&self) -> Result<Self, Error> {
+        //a row is considered dependent once most of its digits are lost in the projections
+        let tolerance = C::epsilon().sqrt();
+        let mut m = self.clone();
+        for i in 0..ROWS {
+            let (basis, rest) = m.data.split_at_mut(i);
+            let line = &mut rest[0];
+            let original_norm = norm(line);
+            for q in basis.iter() {
+                let projection = q
+                    .iter()
+                    .zip(line.iter())
+                    .fold(C::zero(), |acc, (a, b)| acc + *a * *b);
+                line.iter_mut()
+                    .zip(q.iter())
+                    .for_each(|(c, q)| *c = *c - projection * *q);
+            }
+            let norm = norm(line);
+            if norm.is_zero() || norm <= tolerance * original_norm {
+                return Err(Error::RankDeficient);
+            }
+            line.iter_mut().for_each(|c| *c = *c / norm);
+        }
+        Ok(m)
+    }
+}
+
+impl<C, const SIZE: usize> Matrix<C, SIZE, SIZE>
+where
+    C: Float,
+{
+    ///Returns the Householder reflector `I - 2vvᵀ/‖v‖²`, the reflection across the hyperplane orthogonal to `v`.
+    ///
+    ///Returns NilVector if all coefficients of `v` are nil.
+    ///When only the product with a reflector is needed, [`apply_householder`] avoids building it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{ColVector, Matrix};
+    /// let v = ColVector::from([[1.0], [1.0]]);
+    /// let h = Matrix::householder(&v).unwrap();
+    /// assert!(h.approx_eq(&Matrix::from([[0.0, -1.0], [-1.0, 0.0]]), 1e-12));
+    /// ```
+    ///
+    /// [`apply_householder`]: #method.apply_householder
+    pub fn householder(v: &ColVector<C, SIZE>) -> Result<Self, Error> {
+        let mut h = Self::identity();
+        h.apply_householder(v)?;
+        Ok(h)
+    }
+}
+
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS>
+where
+    C: Float,
+{
+    ///Multiplies the matrix on the left by the Householder reflector of `v`, see [`householder`].
+    ///
+    ///This costs `O(ROWS * COLS)` operations instead of the `O(ROWS² * COLS)` of a full product.
+    ///Returns NilVector if all coefficients of `v` are nil, in which case the matrix is left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{ColVector, Matrix};
+    /// //reflect the first column onto the first axis, as done by a QR decomposition
+    /// let mut mat = Matrix::from([[3.0, 1.0], [4.0, 2.0]]);
+    /// let v = ColVector::from([[3.0 - 5.0], [4.0]]);
+    /// mat.apply_householder(&v).unwrap();
+    /// assert!(mat.approx_eq(&Matrix::from([[5.0, 2.2], [0.0, -0.4]]), 1e-12));
+    /// ```
+    ///
+    /// [`householder`]: #method.householder
+    pub fn apply_householder(&mut self, v: &ColVector<C, ROWS>) -> Result<(), Error> {
+        let beta = reflection_factor(v.iter())?;
+        for col in 0..COLS {
+            let projection = (0..ROWS).fold(C::zero(), |acc, row| {
+                acc + v.data[row][0] * self.data[row][col]
+            }) * beta;
+            for row in 0..ROWS {
+                self.data[row][col] = self.data[row][col] - projection * v.data[row][0];
+            }
+        }
+        Ok(())
+    }
+
+    ///Multiplies the matrix on the right by the Householder reflector of `v`, see [`householder`].
+    ///
+    ///Returns NilVector if all coefficients of `v` are nil, in which case the matrix is left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{ColVector, Matrix};
+    /// let mut mat = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
+    /// mat.apply_householder_right(&ColVector::from([[1.0], [0.0]])).unwrap();
+    /// assert_eq!(mat, Matrix::from([[-1.0, 2.0], [-3.0, 4.0]]));
+    /// ```
+    ///
+    /// [`householder`]: #method.householder
+    pub fn apply_householder_right(&mut self, v: &ColVector<C, COLS>) -> Result<(), Error> {
+        let beta = reflection_factor(v.iter())?;
+        for line in self.data.iter_mut() {
+            let projection = line
+                .iter()
+                .zip(v.iter())
+                .fold(C::zero(), |acc, (a, b)| acc + *a * *b)
+                * beta;
+            line.iter_mut()
+                .zip(v.iter())
+                .for_each(|(c, v)| *c = *c - projection * *v);
+        }
+        Ok(())
+    }
+}
+
+impl<C, const SIZE: usize> Matrix<C, SIZE, SIZE>
+where
+    C: Float,
+{
+    ///Returns the Givens rotation of angle `theta` in the plane of the axes `i` and `j`.
+    ///
+    ///This is the identity matrix except for the coefficients `(i, i)` and `(j, j)` which are `cos(theta)`,
+    ///`(i, j)` which is `-sin(theta)` and `(j, i)` which is `sin(theta)`.
+    ///Returns OutOfBounds if either `i` or `j` is out of bounds and WrongOperation if they are equal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let g = Matrix::<f64, 3, 3>::givens(0, 2, std::f64::consts::FRAC_PI_2).unwrap();
+    /// assert!(g.approx_eq(&Matrix::from([[0.0, 0.0, -1.0], [0.0, 1.0, 0.0], [1.0, 0.0, 0.0]]), 1e-12));
+    /// ```
+    pub fn givens(i: usize, j: usize, theta: C) -> Result<Self, Error> {
+        let mut g = Self::identity();
+        g.apply_givens(i, j, theta.cos(), theta.sin())?;
+        Ok(g)
+    }
+}
+
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS>
+where
+    C: Float,
+{
+    ///Multiplies the matrix on the left by a Givens rotation given by its cosine `c` and sine `s`, see [`givens`].
+    ///
+    ///Only the rows `i` and `j` are modified, which makes this much cheaper than a full product.
+    ///The parameters zeroing a given coefficient are returned by [`givens_parameters`].
+    ///Returns OutOfBounds if either `i` or `j` is out of bounds and WrongOperation if they are equal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mut mat = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
+    /// mat.apply_givens(0, 1, 0.0, 1.0).unwrap();
+    /// assert_eq!(mat, Matrix::from([[-3.0, -4.0], [1.0, 2.0]]));
+    /// ```
+    ///
+    /// [`givens`]: #method.givens
+    /// [`givens_parameters`]: #method.givens_parameters
+    pub fn apply_givens(&mut self, i: usize, j: usize, c: C, s: C) -> Result<(), Error> {
+        check_plane(i, j, ROWS)?;
+        for col in 0..COLS {
+            let (a, b) = (self.data[i][col], self.data[j][col]);
+            self.data[i][col] = c * a - s * b;
+            self.data[j][col] = s * a + c * b;
+        }
+        Ok(())
+    }
+
+    ///Multiplies the matrix on the right by a Givens rotation given by its cosine `c` and sine `s`, see [`givens`].
+    ///
+    ///Only the columns `i` and `j` are modified.
+    ///Returns OutOfBounds if either `i` or `j` is out of bounds and WrongOperation if they are equal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mut mat = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
+    /// mat.apply_givens_right(0, 1, 0.0, 1.0).unwrap();
+    /// assert_eq!(mat, Matrix::from([[2.0, -1.0], [4.0, -3.0]]));
+    /// ```
+    ///
+    /// [`givens`]: #method.givens
+    pub fn apply_givens_right(&mut self, i: usize, j: usize, c: C, s: C) -> Result<(), Error> {
+        check_plane(i, j, COLS)?;
+        for line in self.data.iter_mut() {
+            let (a, b) = (line[i], line[j]);
+            line[i] = c * a + s * b;
+            line[j] = c * b - s * a;
+        }
+        Ok(())
+    }
+
+    ///Returns the cosine, sine and norm `(c, s, r)` of the Givens rotation mapping `(a, b)` to `(r, 0)`.
+    ///
+    ///Applied with [`apply_givens`] to rows `i` and `j`, this zeroes the coefficient of row `j` whose value is `b`.
+    ///If both `a` and `b` are nil the identity `(1, 0, 0)` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mut mat = Matrix::from([[3.0, 1.0], [4.0, 1.0]]);
+    /// let (c, s, r) = Matrix::<f64, 2, 2>::givens_parameters(3.0, 4.0);
+    /// assert_eq!(r, 5.0);
+    /// mat.apply_givens(0, 1, c, s).unwrap();
+    /// assert!(mat.approx_eq(&Matrix::from([[5.0, 1.4], [0.0, -0.2]]), 1e-12));
+    /// ```
+    ///
+    /// [`apply_givens`]: #method.apply_givens
+    pub fn givens_parameters(a: C, b: C) -> (C, C, C) {
+        let r = a.hypot(b);
+        if r.is_zero() {
+            (C::one(), C::zero(), C::zero())
+        } else {
+            (a / r, -b / r, r)
+        }
+    }
+}
+
+fn check_plane(i: usize, j: usize, dimension: usize) -> Result<(), Error> {
+    if i >= dimension || j >= dimension {
+        Err(Error::OutOfBounds)
+    } else if i == j {
+        Err(Error::WrongOperation)
+    } else {
+        Ok(())
+    }
+}
+
+//Returns `2/‖v‖²`.
+fn reflection_factor<'a, C: Float + 'a>(v: impl Iterator<Item = &'a C>) -> Result<C, Error> {
+    let squared_norm = v.fold(C::zero(), |acc, c| acc + *c * *c);
+    if squared_norm.is_zero() {
+        return Err(Error::NilVector);
+    }
+    Ok((C::one() + C::one()) / squared_norm)
+}
+
+fn norm<C: Float>(line: &[C]) -> C {
+    line.iter().fold(C::zero(), |acc, c| acc + *c * *c).sqrt()
+}
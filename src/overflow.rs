@@ -0,0 +1,166 @@
+//! Arithmetic with explicit overflow handling, mostly useful for integer coefficients.
+
+use crate::Matrix;
+use num::traits::{
+    CheckedAdd, CheckedMul, SaturatingAdd, SaturatingMul, WrappingAdd, WrappingMul, Zero,
+};
+
+///Checked arithmetic, returning `None` instead of overflowing.
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS> {
+    ///Matrix addition, returning `None` if any coefficient overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1u8, 2], [3, 250]]);
+    /// assert_eq!(mat.checked_add(&[[1, 1], [1, 1]].into()), Some(Matrix::from([[2, 3], [4, 251]])));
+    /// assert_eq!(mat.checked_add(&mat), None);
+    /// ```
+    pub fn checked_add(&self, other: &Self) -> Option<Self>
+    where
+        C: CheckedAdd,
+    {
+        Matrix::try_from_fn(|row, col| self.data[row][col].checked_add(&other.data[row][col]))
+    }
+
+    ///Matrix product, returning `None` if any intermediate result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1u8, 2], [3, 4]]);
+    /// assert_eq!(mat.checked_mul(&mat), Some(Matrix::from([[7, 10], [15, 22]])));
+    /// assert_eq!(Matrix::from([[16u8, 16]]).checked_mul(&Matrix::from([[8u8], [8]])), None);
+    /// ```
+    pub fn checked_mul<const Q: usize>(
+        &self,
+        other: &Matrix<C, COLS, Q>,
+    ) -> Option<Matrix<C, ROWS, Q>>
+    where
+        C: CheckedAdd + CheckedMul + Zero,
+    {
+        Matrix::try_from_fn(|row, col| {
+            self.data[row]
+                .iter()
+                .zip(other.data.iter())
+                .try_fold(C::zero(), |acc, (a, line)| {
+                    acc.checked_add(&a.checked_mul(&line[col])?)
+                })
+        })
+    }
+
+    ///Multiplication by a coefficient, returning `None` if any coefficient overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1u8, 2], [3, 100]]);
+    /// assert_eq!(mat.checked_scale(&2), Some(Matrix::from([[2, 4], [6, 200]])));
+    /// assert_eq!(mat.checked_scale(&3), None);
+    /// ```
+    pub fn checked_scale(&self, coef: &C) -> Option<Self>
+    where
+        C: CheckedMul,
+    {
+        Matrix::try_from_fn(|row, col| self.data[row][col].checked_mul(coef))
+    }
+
+    //Same as `from_fn` but returns `None` if `f` does for any coefficient.
+    fn try_from_fn<F>(f: F) -> Option<Self>
+    where
+        F: FnMut(usize, usize) -> Option<C>,
+    {
+        Matrix::from_options(Matrix::from_fn(f))
+    }
+}
+
+///Wrapping arithmetic, coefficients wrap around at the boundary of their type.
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS>
+where
+    C: Clone,
+{
+    ///Matrix addition, wrapping around on overflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1u8, 255]]);
+    /// assert_eq!(mat.wrapping_add(&mat), Matrix::from([[2, 254]]));
+    /// ```
+    pub fn wrapping_add(&self, other: &Self) -> Self
+    where
+        C: WrappingAdd,
+    {
+        Matrix::from_fn(|row, col| self.data[row][col].wrapping_add(&other.data[row][col]))
+    }
+
+    ///Matrix product, wrapping around on overflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let left = Matrix::from([[16u8, 1]]);
+    /// let right = Matrix::from([[16u8], [3]]);
+    /// assert_eq!(left.wrapping_mul(&right), Matrix::from([[3]]));
+    /// ```
+    pub fn wrapping_mul<const Q: usize>(&self, other: &Matrix<C, COLS, Q>) -> Matrix<C, ROWS, Q>
+    where
+        C: WrappingAdd + WrappingMul + Zero,
+    {
+        Matrix::from_fn(|row, col| {
+            self.data[row]
+                .iter()
+                .zip(other.data.iter())
+                .fold(C::zero(), |acc, (a, line)| {
+                    acc.wrapping_add(&a.wrapping_mul(&line[col]))
+                })
+        })
+    }
+
+    ///Matrix addition, saturating at the boundaries of the coefficient type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1u8, 200]]);
+    /// assert_eq!(mat.saturating_add(&mat), Matrix::from([[2, 255]]));
+    /// ```
+    pub fn saturating_add(&self, other: &Self) -> Self
+    where
+        C: SaturatingAdd,
+    {
+        Matrix::from_fn(|row, col| self.data[row][col].saturating_add(&other.data[row][col]))
+    }
+
+    ///Matrix product, saturating at the boundaries of the coefficient type.
+    ///
+    ///Every intermediate product and sum saturates, so the result is only exact if no saturation happened.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let left = Matrix::from([[16u8, 1]]);
+    /// let right = Matrix::from([[16u8], [3]]);
+    /// assert_eq!(left.saturating_mul(&right), Matrix::from([[255]]));
+    /// ```
+    pub fn saturating_mul<const Q: usize>(&self, other: &Matrix<C, COLS, Q>) -> Matrix<C, ROWS, Q>
+    where
+        C: SaturatingAdd + SaturatingMul + Zero,
+    {
+        Matrix::from_fn(|row, col| {
+            self.data[row]
+                .iter()
+                .zip(other.data.iter())
+                .fold(C::zero(), |acc, (a, line)| {
+                    acc.saturating_add(&a.saturating_mul(&line[col]))
+                })
+        })
+    }
+}
@@ -0,0 +1,62 @@
+//! Float products computed by the `matrixmultiply` crate, enabled by the `matrixmultiply` feature.
+//!
+//! Unlike the `blas` feature this is pure Rust, so there is no system library to link.
+
+use crate::Matrix;
+
+//`matrixmultiply` takes the distance between two rows and between two columns, in coefficients.
+fn strides(cols: usize) -> (isize, isize) {
+    (cols as isize, 1)
+}
+
+//The functions are the same for `f32` and `f64` except for the `s`/`d` prefix.
+macro_rules! impl_packed_mul {
+    ($t:ty, $gemm:ident) => {
+        ///Products computed by `matrixmultiply`
+        impl<const ROWS: usize, const COLS: usize> Matrix<$t, ROWS, COLS> {
+            ///Matrix product computed by the `matrixmultiply` crate.
+            ///
+            ///The operands are copied into packed blocks fitting the cache then multiplied by vectorized kernels,
+            ///which is much faster than the `*` operator for large matrices.
+            #[doc = concat!("The result is the same as with the `*` operator up to rounding errors since `", stringify!($t), "` terms are summed in another order.")]
+            ///
+            /// # Example
+            ///
+            /// ```
+            ///# use matrix::Matrix;
+            #[doc = concat!("let left = Matrix::<", stringify!($t), ", 2, 3>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);")]
+            /// let right = Matrix::from([[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]);
+            /// assert_eq!(left.packed_mul(&right), Matrix::from([[4.0, 5.0], [10.0, 11.0]]));
+            /// ```
+            pub fn packed_mul<const Q: usize>(&self, other: &Matrix<$t, COLS, Q>) -> Matrix<$t, ROWS, Q> {
+                let mut result = Matrix::from_fn(|_, _| 0.0);
+                let (rsa, csa) = strides(COLS);
+                //`other` and the result both have `Q` columns
+                let (rsb, csb) = strides(Q);
+                // SAFETY: the matrices are contiguous row-major arrays whose dimensions and strides are the ones given
+                unsafe {
+                    matrixmultiply::$gemm(
+                        ROWS,
+                        COLS,
+                        Q,
+                        1.0,
+                        self.as_slice().as_ptr(),
+                        rsa,
+                        csa,
+                        other.as_slice().as_ptr(),
+                        rsb,
+                        csb,
+                        0.0,
+                        result.as_mut_slice().as_mut_ptr(),
+                        rsb,
+                        csb,
+                    );
+                }
+                result
+            }
+        }
+    };
+}
+
+impl_packed_mul!(f32, sgemm);
+impl_packed_mul!(f64, dgemm);
@@ -0,0 +1,108 @@
+//! Multithreaded operations through `rayon`, enabled by the `parallel` feature.
+//!
+//! The arithmetic operators stay single-threaded since running them on several threads requires the coefficients to be `Send` and `Sync`.
+
+use crate::Matrix;
+use rayon::prelude::*;
+use std::convert::TryInto;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul};
+
+//Number of scalar operations under which spawning tasks costs more than it saves.
+const THRESHOLD: usize = 1 << 15;
+
+//Builds a matrix from its rows, which were collected from a parallel iterator.
+fn from_rows<C, const ROWS: usize, const COLS: usize>(
+    rows: Vec<[C; COLS]>,
+) -> Matrix<C, ROWS, COLS> {
+    Matrix {
+        data: rows
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("one row is computed for each row of the result")),
+    }
+}
+
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS>
+where
+    C: Send + Sync,
+{
+    ///Matrix product computed on several threads, one row of the result at a time.
+    ///
+    ///Small products are computed on the current thread, as with the `*` operator, since they wouldn't benefit from it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let left = Matrix::<f64, 64, 128>::from_fn(|row, col| (row + col) as f64);
+    /// let right = Matrix::<f64, 128, 64>::from_fn(|row, col| (row * col % 7) as f64);
+    /// assert_eq!(left.par_mul(&right), &left * &right);
+    /// ```
+    pub fn par_mul<const Q: usize>(&self, other: &Matrix<C, COLS, Q>) -> Matrix<C, ROWS, Q>
+    where
+        C: Add + Mul<C, Output = C> + Sum + Clone,
+    {
+        if ROWS * COLS * Q < THRESHOLD {
+            return self * other;
+        }
+        from_rows(
+            self.data
+                .par_iter()
+                .map(|line| {
+                    std::array::from_fn(|col| {
+                        line.iter()
+                            .zip(other.data.iter())
+                            .map(|(a, other_line)| a.clone() * other_line[col].clone())
+                            .sum()
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    ///Matrix addition computed on several threads for large matrices, see [`par_mul`].
+    ///
+    /// [`par_mul`]: #method.par_mul
+    pub fn par_add(&self, other: &Self) -> Self
+    where
+        C: AddAssign + Clone,
+    {
+        if ROWS * COLS < THRESHOLD {
+            return self + other;
+        }
+        let mut m = self.clone();
+        m.data
+            .par_iter_mut()
+            .zip(other.data.par_iter())
+            .for_each(|(line, other_line)| {
+                line.iter_mut()
+                    .zip(other_line.iter())
+                    .for_each(|(a, b)| *a += b.clone())
+            });
+        m
+    }
+
+    ///Variant of [`map`] computed on several threads for large matrices, see [`par_mul`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::<f32, 128, 256>::from_fn(|row, col| row as f32 - col as f32);
+    /// let relu = mat.clone().par_map(|c| c.max(0.0));
+    /// assert_eq!(relu, mat.map(|c| c.max(0.0)));
+    /// ```
+    ///
+    /// [`map`]: #method.map
+    /// [`par_mul`]: #method.par_mul
+    pub fn par_map<T, F>(self, f: F) -> Matrix<T, ROWS, COLS>
+    where
+        T: Send,
+        F: Fn(C) -> T + Send + Sync,
+    {
+        if ROWS * COLS < THRESHOLD {
+            return self.map(f);
+        }
+        from_rows(self.data.into_par_iter().map(|line| line.map(&f)).collect())
+    }
+}
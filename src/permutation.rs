@@ -0,0 +1,208 @@
+//! Permutation matrices, stored as the index array of the permutation.
+
+use crate::{Error, Matrix};
+use core::convert::TryFrom;
+use core::ops::Mul;
+use num::traits::{One, Zero};
+
+///Permutation of `SIZE` elements, which acts on matrices like the permutation matrix of the same size.
+///
+///Only the index array is stored, and `indices[i]` is the position the `i`-th element is taken from:
+///row `i` of `p * a` is row `p.indices()[i]` of `a`, and row `i` of the permutation matrix has its one in column `indices[i]`.
+///Applying the permutation to a matrix only moves its rows or columns, instead of the `SIZE³` operations of a product.
+///
+/// # Example
+///
+/// ```
+///# use matrix::{Matrix, Permutation};
+/// let p = Permutation::new([2, 0, 1]).unwrap();
+/// let a = Matrix::from([[1, 1], [2, 2], [3, 3]]);
+/// assert_eq!(&p * &a, Matrix::from([[3, 3], [1, 1], [2, 2]]));
+/// assert_eq!(&p * &a, &p.to_matrix() * &a);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Permutation<const SIZE: usize> {
+    indices: [usize; SIZE],
+}
+
+impl<const SIZE: usize> Permutation<SIZE> {
+    ///Returns the permutation taking the `i`-th element from position `indices[i]`.
+    ///
+    ///Applied to a matrix, row `i` of the result is row `indices[i]` of the input.
+    ///Returns NotAPermutation if an index is out of bounds or appears twice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{Matrix, Permutation};
+    /// //the first row of the result is the last row of the input
+    /// let p = Permutation::new([2, 0, 1]).unwrap();
+    /// let a = Matrix::from([[0], [10], [20]]);
+    /// assert_eq!(&p * &a, Matrix::from([[20], [0], [10]]));
+    /// ```
+    pub fn new(indices: [usize; SIZE]) -> Result<Self, Error> {
+        let mut seen = [false; SIZE];
+        for &index in indices.iter() {
+            if index >= SIZE || seen[index] {
+                return Err(Error::NotAPermutation);
+            }
+            seen[index] = true;
+        }
+        Ok(Permutation { indices })
+    }
+
+    ///Returns the permutation which leaves every element in place.
+    pub fn identity() -> Self {
+        Permutation {
+            indices: core::array::from_fn(|i| i),
+        }
+    }
+
+    ///Returns the permutation exchanging `a` and `b`, that is the permutation matrix of the `permute` row operation.
+    ///
+    ///Returns OutOfBounds if either is out of bounds.
+    pub fn transposition(a: usize, b: usize) -> Result<Self, Error> {
+        if a >= SIZE || b >= SIZE {
+            return Err(Error::OutOfBounds);
+        }
+        let mut p = Self::identity();
+        p.indices.swap(a, b);
+        Ok(p)
+    }
+
+    ///Returns the index array of the permutation.
+    pub fn indices(&self) -> &[usize; SIZE] {
+        &self.indices
+    }
+
+    ///Returns the inverse permutation, which is also the transpose of the permutation matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Permutation;
+    /// let p = Permutation::new([1, 2, 3, 0]).unwrap();
+    /// assert_eq!(p.inverse(), Permutation::new([3, 0, 1, 2]).unwrap());
+    /// assert_eq!(&p * &p.inverse(), Permutation::identity());
+    /// ```
+    pub fn inverse(&self) -> Self {
+        let mut indices = [0; SIZE];
+        for (i, &index) in self.indices.iter().enumerate() {
+            indices[index] = i;
+        }
+        Permutation { indices }
+    }
+
+    ///Returns the sign of the permutation: 1 if it is made of an even number of transpositions, -1 otherwise.
+    ///
+    ///This is the determinant of the permutation matrix, computed from the number of cycles of the permutation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Permutation;
+    /// assert_eq!(Permutation::new([1, 2, 0]).unwrap().sign(), 1);
+    /// assert_eq!(Permutation::<3>::transposition(0, 2).unwrap().sign(), -1);
+    /// ```
+    pub fn sign(&self) -> i8 {
+        let mut visited = [false; SIZE];
+        let mut cycles = 0;
+        for start in 0..SIZE {
+            if visited[start] {
+                continue;
+            }
+            cycles += 1;
+            let mut i = start;
+            while !visited[i] {
+                visited[i] = true;
+                i = self.indices[i];
+            }
+        }
+        //a cycle of length k is made of k - 1 transpositions
+        if (SIZE - cycles) % 2 == 0 {
+            1
+        } else {
+            -1
+        }
+    }
+
+    ///Returns whether the permutation is made of an even number of transpositions.
+    pub fn is_even(&self) -> bool {
+        self.sign() == 1
+    }
+
+    ///Returns the permutation matrix, whose row `i` has its one in column `indices()[i]`.
+    pub fn to_matrix<C>(&self) -> Matrix<C, SIZE, SIZE>
+    where
+        C: Zero + One,
+    {
+        Matrix::from_fn(|row, col| {
+            if self.indices[row] == col {
+                C::one()
+            } else {
+                C::zero()
+            }
+        })
+    }
+}
+
+impl<const SIZE: usize> Default for Permutation<SIZE> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+///Checks the indices, see [`Permutation::new`].
+impl<const SIZE: usize> TryFrom<[usize; SIZE]> for Permutation<SIZE> {
+    type Error = Error;
+
+    fn try_from(indices: [usize; SIZE]) -> Result<Self, Error> {
+        Permutation::new(indices)
+    }
+}
+
+///Composition of permutations, which is the product of their matrices: `other` is applied first.
+impl<const SIZE: usize> Mul for &Permutation<SIZE> {
+    type Output = Permutation<SIZE>;
+
+    fn mul(self, other: Self) -> Self::Output {
+        Permutation {
+            indices: self.indices.map(|i| other.indices[i]),
+        }
+    }
+}
+
+///Permutation of the rows of `matrix`, row `i` of the result being row `indices()[i]` of `matrix`.
+impl<C, const ROWS: usize, const COLS: usize> Mul<&Matrix<C, ROWS, COLS>> for &Permutation<ROWS>
+where
+    C: Clone,
+{
+    type Output = Matrix<C, ROWS, COLS>;
+
+    fn mul(self, matrix: &Matrix<C, ROWS, COLS>) -> Self::Output {
+        Matrix::from_fn(|row, col| matrix.data[self.indices[row]][col].clone())
+    }
+}
+
+///Permutation of the columns of `self`, column `indices()[j]` of the result being column `j` of `self`.
+///
+/// # Example
+///
+/// ```
+///# use matrix::{Matrix, Permutation};
+/// let p = Permutation::new([2, 0, 1]).unwrap();
+/// let a = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+/// assert_eq!(&a * &p, Matrix::from([[2, 3, 1], [5, 6, 4]]));
+/// assert_eq!(&a * &p, &a * &p.to_matrix());
+/// ```
+impl<C, const ROWS: usize, const COLS: usize> Mul<&Permutation<COLS>> for &Matrix<C, ROWS, COLS>
+where
+    C: Clone,
+{
+    type Output = Matrix<C, ROWS, COLS>;
+
+    fn mul(self, permutation: &Permutation<COLS>) -> Self::Output {
+        let inverse = permutation.inverse();
+        Matrix::from_fn(|row, col| self.data[row][inverse.indices[col]].clone())
+    }
+}
@@ -0,0 +1,153 @@
+//! Polynomials and matrices: characteristic polynomial, evaluation of a polynomial at a matrix, companion and Vandermonde matrices.
+//!
+//! Polynomials are given by their coefficients in increasing degree order, monic polynomials of degree `SIZE`
+//! by their `SIZE` first coefficients, the leading one being implied.
+
+use crate::Matrix;
+use core::iter::Sum;
+use num::traits::Num;
+
+impl<C, const SIZE: usize> Matrix<C, SIZE, SIZE>
+where
+    C: Num + Sum + Clone,
+{
+    ///Returns the coefficients `[c₀, c₁, …, cₙ₋₁]` of the characteristic polynomial `det(λI - A) = λⁿ + cₙ₋₁λⁿ⁻¹ + … + c₀`.
+    ///
+    ///The polynomial `det(A - λI)` is the same one multiplied by `(-1)ⁿ`.
+    ///The coefficients are computed by the Faddeev-LeVerrier algorithm, whose only divisions are exact ones by `1, 2, …, n`:
+    ///the result is exact for rational and even integer coefficients, unlike what numerical eigenvalue algorithms provide.
+    ///This takes `O(n⁴)` operations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// // λ² - 4λ + 3 = (λ - 1)(λ - 3)
+    /// let a = Matrix::from([[2, 1], [1, 2]]);
+    /// assert_eq!(a.char_poly(), [3, -4]);
+    /// ```
+    pub fn char_poly(&self) -> [C; SIZE] {
+        let mut coefficients: [C; SIZE] = core::array::from_fn(|_| C::zero());
+        //with Mₖ = AMₖ₋₁ + cₙ₋ₖ₊₁I starting from M₀ = 0, the coefficient cₙ₋ₖ is -tr(AMₖ)/k
+        let mut product = Self::nil();
+        let mut coefficient = C::one();
+        let mut k = C::zero();
+        for i in (0..SIZE).rev() {
+            let mut m = product;
+            for (j, line) in m.data.iter_mut().enumerate() {
+                line[j] = line[j].clone() + coefficient.clone();
+            }
+            product = self * &m;
+            k = k + C::one();
+            coefficient = (C::zero() - product.trace()) / k.clone();
+            coefficients[i] = coefficient.clone();
+        }
+        coefficients
+    }
+
+    ///Returns `c₀I + c₁A + … + cₙAⁿ`, the polynomial of coefficients `coeffs` evaluated at the matrix.
+    ///
+    ///This is computed by Horner's scheme, `(…(cₙA + cₙ₋₁I)A + …)A + c₀I`, which takes `n` matrix products.
+    ///An empty slice is the nil polynomial.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let a = Matrix::from([[1, 2], [0, 3]]);
+    /// // 1 + 2A + A²
+    /// assert_eq!(a.polyval(&[1, 2, 1]), Matrix::from([[4, 12], [0, 16]]));
+    /// // a matrix is a root of its characteristic polynomial
+    /// let [c0, c1] = a.char_poly();
+    /// assert_eq!(a.polyval(&[c0, c1, 1]), Matrix::nil());
+    /// ```
+    pub fn polyval(&self, coeffs: &[C]) -> Self {
+        let mut coeffs = coeffs.iter().rev();
+        let mut result = match coeffs.next() {
+            Some(leading) => Self::identity() * leading.clone(),
+            None => return Self::nil(),
+        };
+        for coefficient in coeffs {
+            result = &result * self;
+            for (i, line) in result.data.iter_mut().enumerate() {
+                line[i] = line[i].clone() + coefficient.clone();
+            }
+        }
+        result
+    }
+}
+
+impl<C, const SIZE: usize> Matrix<C, SIZE, SIZE>
+where
+    C: Num + Clone,
+{
+    ///Returns the companion matrix of the monic polynomial `λⁿ + cₙ₋₁λⁿ⁻¹ + … + c₀`, given as `[c₀, c₁, …, cₙ₋₁]`.
+    ///
+    ///The matrix has ones below its diagonal and `-c₀, …, -cₙ₋₁` in its last column, so that its characteristic polynomial
+    ///is the given one and its eigenvalues are the roots of the polynomial. For instance [`dominant_eigenpair`] finds the root of largest modulus.
+    ///
+    /// [`dominant_eigenpair`]: #method.dominant_eigenpair
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// // λ² - 5λ + 6 = (λ - 2)(λ - 3)
+    /// let companion = Matrix::companion([6.0, -5.0]);
+    /// assert_eq!(companion, Matrix::from([[0.0, -6.0], [1.0, 5.0]]));
+    /// assert_eq!(companion.char_poly(), [6.0, -5.0]);
+    /// let (root, _) = companion.dominant_eigenpair(1000, 1e-9).unwrap();
+    /// assert!((root - 3.0f64).abs() < 1e-6);
+    /// ```
+    pub fn companion(coeffs: [C; SIZE]) -> Self {
+        Self::from_fn(|row, col| {
+            if col + 1 == SIZE {
+                C::zero() - coeffs[row].clone()
+            } else if row == col + 1 {
+                C::one()
+            } else {
+                C::zero()
+            }
+        })
+    }
+}
+
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS>
+where
+    C: Num + Clone,
+{
+    ///Returns the Vandermonde matrix of the points, whose row `i` is `[1, xᵢ, xᵢ², …, xᵢᶜᴼᴸˢ⁻¹]`.
+    ///
+    ///Multiplying it by the coefficients of a polynomial of degree less than `COLS` evaluates the polynomial at every point.
+    ///Solving `V * c = y` thus interpolates the values `y` when the matrix is square and the points distinct,
+    ///and [`lstsq`] fits a polynomial to them when there are more points than coefficients.
+    ///
+    /// [`lstsq`]: #method.lstsq
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let v: Matrix<i32, 3, 4> = Matrix::vandermonde([1, 2, 3]);
+    /// assert_eq!(v, Matrix::from([[1, 1, 1, 1], [1, 2, 4, 8], [1, 3, 9, 27]]));
+    ///
+    /// // the parabola through (0, 1), (1, 0) and (2, 3) is 1 - 3x + 2x²
+    /// let v: Matrix<f64, 3, 3> = Matrix::vandermonde([0.0, 1.0, 2.0]);
+    /// let c = v.solve(Matrix::from([[1.0], [0.0], [3.0]])).unwrap();
+    /// assert!(c.approx_eq(&Matrix::from([[1.0], [-3.0], [2.0]]), 1e-12));
+    /// ```
+    pub fn vandermonde(points: [C; ROWS]) -> Self {
+        Matrix {
+            data: points.map(|x| {
+                let mut power = C::one();
+                //raised before every column but the first, so that no power beyond the last column overflows
+                core::array::from_fn(|col| {
+                    if col > 0 {
+                        power = power.clone() * x.clone();
+                    }
+                    power.clone()
+                })
+            }),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! Serde support, available with the `serde` feature.
+//!
+//! Matrices are represented as a sequence of rows, each row being a sequence of coefficients.
+//! The dimensions are checked when deserializing.
+
+use crate::Matrix;
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+impl<C, const ROWS: usize, const COLS: usize> Serialize for Matrix<C, ROWS, COLS>
+where
+    C: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.data.iter().map(|line| &line[..]))
+    }
+}
+
+impl<'de, C, const ROWS: usize, const COLS: usize> Deserialize<'de> for Matrix<C, ROWS, COLS>
+where
+    C: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(MatrixVisitor(PhantomData))
+    }
+}
+
+struct MatrixVisitor<C, const ROWS: usize, const COLS: usize>(PhantomData<C>);
+
+impl<'de, C, const ROWS: usize, const COLS: usize> Visitor<'de> for MatrixVisitor<C, ROWS, COLS>
+where
+    C: Deserialize<'de>,
+{
+    type Value = Matrix<C, ROWS, COLS>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a sequence of {} rows of {} coefficients", ROWS, COLS)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut coefficients = Vec::with_capacity(ROWS * COLS);
+        let mut rows = 0;
+        while let Some(line) = seq.next_element::<Vec<C>>()? {
+            if line.len() != COLS {
+                return Err(de::Error::custom(format_args!(
+                    "row {} has {} coefficients, expected {}",
+                    rows,
+                    line.len(),
+                    COLS
+                )));
+            }
+            rows += 1;
+            if rows > ROWS {
+                break;
+            }
+            coefficients.extend(line);
+        }
+        if rows != ROWS {
+            return Err(de::Error::invalid_length(rows, &self));
+        }
+        Matrix::try_from_iter(coefficients).map_err(de::Error::custom)
+    }
+}
@@ -0,0 +1,85 @@
+//! Vectorized kernels through `portable_simd`, enabled by the `simd` feature.
+
+use crate::Matrix;
+use core::ops::{Add, Mul};
+use core::simd::{Simd, SimdElement};
+use num::traits::Zero;
+
+//Number of coefficients processed at once, wide enough for 256-bit vectors of `f32`.
+//The vector type spells it out since named constants in bounds trip `generic_const_exprs` up.
+const LANES: usize = 8;
+type Vector<C> = Simd<C, 8>;
+
+//Adds `factor * source` to `target`, both slices having the same length.
+fn multiply_add<C>(target: &mut [C], factor: C, source: &[C])
+where
+    C: SimdElement + Add<Output = C> + Mul<Output = C>,
+    Vector<C>: Add<Output = Vector<C>> + Mul<Output = Vector<C>>,
+{
+    let splat = Vector::splat(factor);
+    let mut target_chunks = target.chunks_exact_mut(LANES);
+    let mut source_chunks = source.chunks_exact(LANES);
+    for (t, s) in target_chunks.by_ref().zip(source_chunks.by_ref()) {
+        (Vector::from_slice(t) + splat * Vector::from_slice(s)).copy_to_slice(t);
+    }
+    for (t, s) in target_chunks
+        .into_remainder()
+        .iter_mut()
+        .zip(source_chunks.remainder())
+    {
+        *t = *t + factor * *s;
+    }
+}
+
+///Vectorized operations for matrices of primitive numbers.
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS>
+where
+    C: SimdElement + Zero + Add<Output = C> + Mul<Output = C>,
+    Vector<C>: Add<Output = Vector<C>> + Mul<Output = Vector<C>>,
+{
+    ///Matrix product computed with SIMD instructions.
+    ///
+    ///Each row of the result accumulates the rows of `other` scaled by the coefficients of the row of `self`,
+    ///which processes several contiguous coefficients at once.
+    ///The terms are summed in the same order as with the `*` operator, so floating point results are identical.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let left = Matrix::<f32, 3, 20>::from_fn(|row, col| (row * col) as f32 / 8.0);
+    /// let right = Matrix::<f32, 20, 11>::from_fn(|row, col| row as f32 - col as f32);
+    /// assert_eq!(left.simd_mul(&right), &left * &right);
+    /// ```
+    pub fn simd_mul<const Q: usize>(&self, other: &Matrix<C, COLS, Q>) -> Matrix<C, ROWS, Q> {
+        let mut result = Matrix::from_fn(|_, _| C::zero());
+        for (line, result_line) in self.data.iter().zip(result.data.iter_mut()) {
+            for (c, other_line) in line.iter().zip(other.data.iter()) {
+                multiply_add(result_line, *c, other_line);
+            }
+        }
+        result
+    }
+
+    ///Multiplies every coefficient by `scalar` with SIMD instructions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mut mat = Matrix::<i32, 3, 5>::from_fn(|row, col| (row * 5 + col) as i32);
+    /// let expected = mat.clone() * 3;
+    /// mat.simd_scale(3);
+    /// assert_eq!(mat, expected);
+    /// ```
+    pub fn simd_scale(&mut self, scalar: C) {
+        let splat = Vector::splat(scalar);
+        let mut chunks = self.as_mut_slice().chunks_exact_mut(LANES);
+        for chunk in chunks.by_ref() {
+            (Vector::from_slice(chunk) * splat).copy_to_slice(chunk);
+        }
+        for c in chunks.into_remainder() {
+            *c = *c * scalar;
+        }
+    }
+}
@@ -0,0 +1,209 @@
+//! Sparse matrices, which only store their non-nil coefficients.
+
+use crate::{Error, Matrix};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter::Sum;
+use core::ops::{AddAssign, Mul};
+use num::traits::Zero;
+
+///Matrix of `ROWS` rows and `COLS` columns in compressed sparse row (CSR) format.
+///
+///Only the non-nil coefficients are stored, row by row, along with their column.
+///This saves memory and multiplication time when most coefficients are nil.
+///
+/// # Example
+///
+/// ```
+///# use matrix::{Matrix, SparseMatrix};
+/// let dense = Matrix::from([[0, 2, 0], [0, 0, 0], [1, 0, 3]]);
+/// let sparse = SparseMatrix::from(&dense);
+/// assert_eq!(sparse.nnz(), 3);
+/// assert_eq!(sparse.to_dense(), dense);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseMatrix<C, const ROWS: usize, const COLS: usize> {
+    values: Vec<C>,
+    columns: Vec<usize>,
+    //the coefficients of row `i` are at indices `row_offsets[i]..row_offsets[i + 1]`
+    row_offsets: Vec<usize>,
+}
+
+impl<C, const ROWS: usize, const COLS: usize> SparseMatrix<C, ROWS, COLS> {
+    ///Returns a sparse matrix whose coefficients are all nil.
+    pub fn new() -> Self {
+        SparseMatrix {
+            values: Vec::new(),
+            columns: Vec::new(),
+            row_offsets: vec![0; ROWS + 1],
+        }
+    }
+
+    ///Builds a sparse matrix from `(row, column, value)` triplets, in any order.
+    ///
+    ///Values given for the same position are summed and nil values aren't stored.
+    ///Returns OutOfBounds if a position is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{Matrix, SparseMatrix};
+    /// let sparse = SparseMatrix::<_, 2, 3>::from_triplets(vec![(1, 2, 4), (0, 0, 1), (1, 2, 1)]).unwrap();
+    /// assert_eq!(sparse.to_dense(), Matrix::from([[1, 0, 0], [0, 0, 5]]));
+    /// ```
+    pub fn from_triplets<I>(triplets: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = (usize, usize, C)>,
+        C: Zero + AddAssign,
+    {
+        let mut triplets = triplets.into_iter().collect::<Vec<_>>();
+        if triplets
+            .iter()
+            .any(|&(row, col, _)| row >= ROWS || col >= COLS)
+        {
+            return Err(Error::OutOfBounds);
+        }
+        triplets.sort_by_key(|&(row, col, _)| (row, col));
+        let mut sparse = Self::new();
+        let mut last = None;
+        for (row, col, value) in triplets {
+            if last == Some((row, col)) {
+                *sparse.values.last_mut().unwrap() += value;
+            } else {
+                sparse.values.push(value);
+                sparse.columns.push(col);
+                sparse.row_offsets[row + 1] += 1;
+                last = Some((row, col));
+            }
+        }
+        for row in 0..ROWS {
+            sparse.row_offsets[row + 1] += sparse.row_offsets[row];
+        }
+        if sparse.values.iter().any(Zero::is_zero) {
+            sparse.prune();
+        }
+        Ok(sparse)
+    }
+
+    ///Returns the number of stored, that is non-nil, coefficients.
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    ///Returns a reference to the coefficient at `(row, col)`, or None if it is nil or out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{Matrix, SparseMatrix};
+    /// let sparse = SparseMatrix::from(&Matrix::from([[0, 2], [3, 0]]));
+    /// assert_eq!(sparse.get(0, 1), Some(&2));
+    /// assert_eq!(sparse.get(0, 0), None);
+    /// ```
+    pub fn get(&self, row: usize, col: usize) -> Option<&C> {
+        if row >= ROWS {
+            return None;
+        }
+        let range = self.row_offsets[row]..self.row_offsets[row + 1];
+        self.columns[range.clone()]
+            .binary_search(&col)
+            .ok()
+            .map(|index| &self.values[range.start + index])
+    }
+
+    ///Returns an iterator over the stored coefficients as `(row, column, value)` triplets, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &C)> {
+        (0..ROWS).flat_map(move |row| {
+            (self.row_offsets[row]..self.row_offsets[row + 1])
+                .map(move |index| (row, self.columns[index], &self.values[index]))
+        })
+    }
+
+    ///Returns the dense matrix with the same coefficients.
+    pub fn to_dense(&self) -> Matrix<C, ROWS, COLS>
+    where
+        C: Zero + Clone,
+    {
+        let mut dense = Matrix::from_fn(|_, _| C::zero());
+        for (row, col, value) in self.iter() {
+            dense.data[row][col] = value.clone();
+        }
+        dense
+    }
+
+    //Removes the nil coefficients which were stored.
+    fn prune(&mut self)
+    where
+        C: Zero,
+    {
+        let mut entries = core::mem::take(&mut self.values)
+            .into_iter()
+            .zip(core::mem::take(&mut self.columns));
+        let mut start = 0;
+        for row in 0..ROWS {
+            let end = self.row_offsets[row + 1];
+            for (value, col) in entries.by_ref().take(end - start) {
+                if !value.is_zero() {
+                    self.values.push(value);
+                    self.columns.push(col);
+                }
+            }
+            start = end;
+            self.row_offsets[row + 1] = self.values.len();
+        }
+    }
+}
+
+impl<C, const ROWS: usize, const COLS: usize> Default for SparseMatrix<C, ROWS, COLS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+///Conversion of a dense matrix, only its non-nil coefficients are kept.
+impl<C, const ROWS: usize, const COLS: usize> From<&Matrix<C, ROWS, COLS>>
+    for SparseMatrix<C, ROWS, COLS>
+where
+    C: Zero + Clone,
+{
+    fn from(dense: &Matrix<C, ROWS, COLS>) -> Self {
+        let mut sparse = Self::new();
+        for (row, line) in dense.data.iter().enumerate() {
+            for (col, value) in line.iter().enumerate() {
+                if !value.is_zero() {
+                    sparse.values.push(value.clone());
+                    sparse.columns.push(col);
+                }
+            }
+            sparse.row_offsets[row + 1] = sparse.values.len();
+        }
+        sparse
+    }
+}
+
+///Product of a sparse matrix by a dense one, which only costs as many operations as there are stored coefficients for each column of `other`.
+///
+/// # Example
+///
+/// ```
+///# use matrix::{ColVector, Matrix, SparseMatrix};
+/// let sparse = SparseMatrix::from(&Matrix::from([[0, 2, 0], [1, 0, 3]]));
+/// let v = ColVector::from([[1], [2], [3]]);
+/// assert_eq!(&sparse * &v, ColVector::from([[4], [10]]));
+/// ```
+impl<C, const ROWS: usize, const COLS: usize, const Q: usize> Mul<&Matrix<C, Q, COLS>>
+    for &SparseMatrix<C, ROWS, Q>
+where
+    C: Mul<C, Output = C> + Sum + Clone,
+{
+    type Output = Matrix<C, ROWS, COLS>;
+    fn mul(self, other: &Matrix<C, Q, COLS>) -> Self::Output {
+        Matrix::from_fn(|row, col| {
+            (self.row_offsets[row]..self.row_offsets[row + 1])
+                .map(|index| {
+                    self.values[index].clone() * other.data[self.columns[index]][col].clone()
+                })
+                .sum()
+        })
+    }
+}
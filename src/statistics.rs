@@ -0,0 +1,188 @@
+//! Aggregates over the coefficients of a matrix and over its rows and columns.
+
+use crate::Matrix;
+use core::cmp::Ordering;
+use core::iter::Sum;
+use num::traits::Float;
+
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS>
+where
+    C: Sum + Clone,
+{
+    ///Returns the sum of the coefficients of each row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(mat.row_sums(), [6, 15]);
+    /// assert_eq!(mat.col_sums(), [5, 7, 9]);
+    /// ```
+    pub fn row_sums(&self) -> [C; ROWS] {
+        core::array::from_fn(|row| self.data[row].iter().cloned().sum())
+    }
+
+    ///Returns the sum of the coefficients of each column, see [`row_sums`] for examples.
+    ///
+    /// [`row_sums`]: #method.row_sums
+    pub fn col_sums(&self) -> [C; COLS] {
+        core::array::from_fn(|col| self.data.iter().map(|line| line[col].clone()).sum())
+    }
+}
+
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS>
+where
+    C: Float + Sum,
+{
+    ///Returns the mean of the coefficients of each row, which is NaN if there are no columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 9.0]]);
+    /// assert_eq!(mat.row_means(), [2.0, 6.0]);
+    /// assert_eq!(mat.col_means(), [2.5, 3.5, 6.0]);
+    /// ```
+    pub fn row_means(&self) -> [C; ROWS] {
+        let count = C::from(COLS).unwrap_or_else(C::nan);
+        self.row_sums().map(|sum| sum / count)
+    }
+
+    ///Returns the mean of the coefficients of each column, which is NaN if there are no rows.
+    ///See [`row_means`] for examples.
+    ///
+    /// [`row_means`]: #method.row_means
+    pub fn col_means(&self) -> [C; COLS] {
+        let count = C::from(ROWS).unwrap_or_else(C::nan);
+        self.col_sums().map(|sum| sum / count)
+    }
+}
+
+///Extrema of ordered coefficients.
+///
+///Incomparable coefficients, such as NaN for floats, are ignored.
+///When several coefficients are extrema, the first one row after row is chosen.
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS>
+where
+    C: PartialOrd,
+{
+    ///Returns the smallest coefficient, or None if there are none.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[3, 1, 4], [1, 5, 9]]);
+    /// assert_eq!(mat.min(), Some(&1));
+    /// assert_eq!(mat.max(), Some(&9));
+    /// ```
+    pub fn min(&self) -> Option<&C> {
+        self.argmin().map(|(row, col)| &self.data[row][col])
+    }
+
+    ///Returns the largest coefficient, or None if there are none. See [`min`] for examples.
+    ///
+    /// [`min`]: #method.min
+    pub fn max(&self) -> Option<&C> {
+        self.argmax().map(|(row, col)| &self.data[row][col])
+    }
+
+    ///Returns the position of the smallest coefficient, or None if there are none.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[3.0, 1.0, 4.0], [1.0, f64::NAN, 9.0]]);
+    /// assert_eq!(mat.argmin(), Some((0, 1)));
+    /// assert_eq!(mat.argmax(), Some((1, 2)));
+    /// ```
+    pub fn argmin(&self) -> Option<(usize, usize)> {
+        extremum(self.positions(), Ordering::Less)
+    }
+
+    ///Returns the position of the largest coefficient, or None if there are none. See [`argmin`] for examples.
+    ///
+    /// [`argmin`]: #method.argmin
+    pub fn argmax(&self) -> Option<(usize, usize)> {
+        extremum(self.positions(), Ordering::Greater)
+    }
+
+    ///Returns the column of the smallest coefficient of each row, which is None if there are no columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[3, 1, 4], [1, 5, 9]]);
+    /// assert_eq!(mat.row_argmin(), [Some(1), Some(0)]);
+    /// assert_eq!(mat.row_argmax(), [Some(2), Some(2)]);
+    /// assert_eq!(mat.col_argmin(), [Some(1), Some(0), Some(0)]);
+    /// assert_eq!(mat.col_argmax(), [Some(0), Some(1), Some(1)]);
+    /// ```
+    pub fn row_argmin(&self) -> [Option<usize>; ROWS] {
+        core::array::from_fn(|row| extremum(self.data[row].iter().enumerate(), Ordering::Less))
+    }
+
+    ///Returns the column of the largest coefficient of each row, which is None if there are no columns.
+    ///See [`row_argmin`] for examples.
+    ///
+    /// [`row_argmin`]: #method.row_argmin
+    pub fn row_argmax(&self) -> [Option<usize>; ROWS] {
+        core::array::from_fn(|row| extremum(self.data[row].iter().enumerate(), Ordering::Greater))
+    }
+
+    ///Returns the row of the smallest coefficient of each column, which is None if there are no rows.
+    ///See [`row_argmin`] for examples.
+    ///
+    /// [`row_argmin`]: #method.row_argmin
+    pub fn col_argmin(&self) -> [Option<usize>; COLS] {
+        core::array::from_fn(|col| {
+            extremum(
+                self.data.iter().map(|line| &line[col]).enumerate(),
+                Ordering::Less,
+            )
+        })
+    }
+
+    ///Returns the row of the largest coefficient of each column, which is None if there are no rows.
+    ///See [`row_argmin`] for examples.
+    ///
+    /// [`row_argmin`]: #method.row_argmin
+    pub fn col_argmax(&self) -> [Option<usize>; COLS] {
+        core::array::from_fn(|col| {
+            extremum(
+                self.data.iter().map(|line| &line[col]).enumerate(),
+                Ordering::Greater,
+            )
+        })
+    }
+
+    fn positions(&self) -> impl Iterator<Item = ((usize, usize), &C)> {
+        self.data
+            .iter()
+            .enumerate()
+            .flat_map(|(row, line)| line.iter().enumerate().map(move |(col, c)| ((row, col), c)))
+    }
+}
+
+//Returns the key of the first value which is `wanted` compared to all others, skipping incomparable values.
+fn extremum<'a, K, C, I>(values: I, wanted: Ordering) -> Option<K>
+where
+    C: PartialOrd + 'a,
+    I: Iterator<Item = (K, &'a C)>,
+{
+    let mut best: Option<(K, &C)> = None;
+    for (key, value) in values {
+        if value.partial_cmp(value).is_none() {
+            continue;
+        }
+        match best {
+            Some((_, current)) if value.partial_cmp(current) != Some(wanted) => {}
+            _ => best = Some((key, value)),
+        }
+    }
+    best.map(|(key, _)| key)
+}
@@ -0,0 +1,124 @@
+//! Strassen's matrix product, enabled by the `strassen` feature.
+
+use crate::Matrix;
+use alloc::vec::Vec;
+use core::iter::Sum;
+use core::ops::{Add, Mul, Sub};
+
+//Side below which the recursion stops and blocks are multiplied the usual way.
+//Strassen only saves time once the additions it trades for products are cheap in comparison.
+const LEAF_SIZE: usize = 64;
+
+//Square blocks are stored row-major in a `Vec` of `size * size` coefficients.
+fn naive<C>(left: &[C], right: &[C], size: usize) -> Vec<C>
+where
+    C: Mul<C, Output = C> + Sum + Clone,
+{
+    (0..size * size)
+        .map(|i| {
+            let (row, col) = (i / size, i % size);
+            (0..size)
+                .map(|k| left[row * size + k].clone() * right[k * size + col].clone())
+                .sum()
+        })
+        .collect()
+}
+
+//Copies the quarter of `block` starting at (`row`, `col`).
+fn quarter<C: Clone>(block: &[C], size: usize, row: usize, col: usize) -> Vec<C> {
+    let half = size / 2;
+    (row..row + half)
+        .flat_map(|r| block[r * size + col..r * size + col + half].iter().cloned())
+        .collect()
+}
+
+fn add<C: Add<Output = C> + Clone>(left: &[C], right: &[C]) -> Vec<C> {
+    left.iter()
+        .zip(right)
+        .map(|(a, b)| a.clone() + b.clone())
+        .collect()
+}
+
+fn sub<C: Sub<Output = C> + Clone>(left: &[C], right: &[C]) -> Vec<C> {
+    left.iter()
+        .zip(right)
+        .map(|(a, b)| a.clone() - b.clone())
+        .collect()
+}
+
+//`size` must be a power of two.
+fn strassen<C>(left: &[C], right: &[C], size: usize) -> Vec<C>
+where
+    C: Add<Output = C> + Sub<Output = C> + Mul<C, Output = C> + Sum + Clone,
+{
+    if size <= LEAF_SIZE {
+        return naive(left, right, size);
+    }
+    let half = size / 2;
+    let (a11, a12) = (quarter(left, size, 0, 0), quarter(left, size, 0, half));
+    let (a21, a22) = (
+        quarter(left, size, half, 0),
+        quarter(left, size, half, half),
+    );
+    let (b11, b12) = (quarter(right, size, 0, 0), quarter(right, size, 0, half));
+    let (b21, b22) = (
+        quarter(right, size, half, 0),
+        quarter(right, size, half, half),
+    );
+
+    let m1 = strassen(&add(&a11, &a22), &add(&b11, &b22), half);
+    let m2 = strassen(&add(&a21, &a22), &b11, half);
+    let m3 = strassen(&a11, &sub(&b12, &b22), half);
+    let m4 = strassen(&a22, &sub(&b21, &b11), half);
+    let m5 = strassen(&add(&a11, &a12), &b22, half);
+    let m6 = strassen(&sub(&a21, &a11), &add(&b11, &b12), half);
+    let m7 = strassen(&sub(&a12, &a22), &add(&b21, &b22), half);
+
+    let c11 = add(&sub(&add(&m1, &m4), &m5), &m7);
+    let c12 = add(&m3, &m5);
+    let c21 = add(&m2, &m4);
+    let c22 = add(&add(&sub(&m1, &m2), &m3), &m6);
+
+    let mut result = Vec::with_capacity(size * size);
+    for row in 0..half {
+        result.extend_from_slice(&c11[row * half..(row + 1) * half]);
+        result.extend_from_slice(&c12[row * half..(row + 1) * half]);
+    }
+    for row in 0..half {
+        result.extend_from_slice(&c21[row * half..(row + 1) * half]);
+        result.extend_from_slice(&c22[row * half..(row + 1) * half]);
+    }
+    result
+}
+
+///Strassen's product, for large square matrices
+impl<C, const N: usize> Matrix<C, N, N>
+where
+    C: Add<Output = C> + Sub<Output = C> + Mul<C, Output = C> + Sum + Clone,
+{
+    ///Returns the matrix product computed with Strassen's algorithm.
+    ///
+    ///Each halving of the matrices replaces 8 block products by 7, bringing the cost down to about `N^2.81` operations.
+    ///The recursion stops on blocks of 64 by 64 coefficients which are multiplied the usual way.
+    ///When `N` isn't a power of two or is too small to benefit from it, this is the same as the `*` operator.
+    ///
+    ///For floating point coefficients the result may differ slightly from the `*` operator's
+    ///since the algorithm sums and subtracts the coefficients in another order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let left = Matrix::<i32, 128, 128>::from_fn(|row, col| ((row * 3 + col) % 7) as i32 - 3);
+    /// let right = Matrix::<i32, 128, 128>::from_fn(|row, col| ((row + col * 5) % 11) as i32);
+    /// assert_eq!(left.strassen_mul(&right), &left * &right);
+    /// ```
+    pub fn strassen_mul(&self, other: &Self) -> Self {
+        if !N.is_power_of_two() || N <= LEAF_SIZE {
+            return self * other;
+        }
+        let mut result = strassen(self.as_slice(), other.as_slice(), N).into_iter();
+        //the product of two `N` by `N` blocks has `N * N` coefficients
+        Matrix::from_fn(|_, _| result.next().unwrap())
+    }
+}
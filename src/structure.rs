@@ -0,0 +1,180 @@
+//! Structural properties of matrices: symmetry, triangular and diagonal shapes, identity and orthogonal matrices.
+//!
+//! The symmetry, triangular and diagonal checks come in an exact variant and a `_with_tolerance` variant
+//! for floating point coefficients, which considers nil any coefficient no bigger than the tolerance in absolute value.
+//! The identity and orthogonality checks only exist with a tolerance, since they are mostly applied to computed results:
+//! pass a nil tolerance for an exact comparison.
+
+use crate::Matrix;
+use core::iter::Sum;
+use num::traits::{Signed, Zero};
+
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS> {
+    //Returns whether `predicate` holds for every coefficient whose position satisfies `position`.
+    fn all_where<P, F>(&self, position: P, predicate: F) -> bool
+    where
+        P: Fn(usize, usize) -> bool,
+        F: Fn(&C) -> bool,
+    {
+        self.get_lines().enumerate().all(|(row, line)| {
+            line.iter()
+                .enumerate()
+                .all(|(col, c)| !position(row, col) || predicate(c))
+        })
+    }
+}
+
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS>
+where
+    C: Zero,
+{
+    ///Returns whether every coefficient below the diagonal is nil.
+    ///
+    ///The matrix doesn't need to be square, in which case the diagonal starts at the top left corner.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// assert!(Matrix::from([[1, 2, 3], [0, 4, 5]]).is_upper_triangular());
+    /// assert!(!Matrix::from([[1, 2], [3, 4]]).is_upper_triangular());
+    /// ```
+    pub fn is_upper_triangular(&self) -> bool {
+        self.all_where(|row, col| row > col, C::is_zero)
+    }
+
+    ///Returns whether every coefficient above the diagonal is nil.
+    ///
+    ///The matrix doesn't need to be square, in which case the diagonal starts at the top left corner.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// assert!(Matrix::from([[1, 0], [2, 3], [4, 5]]).is_lower_triangular());
+    /// assert!(!Matrix::from([[1, 2], [3, 4]]).is_lower_triangular());
+    /// ```
+    pub fn is_lower_triangular(&self) -> bool {
+        self.all_where(|row, col| row < col, C::is_zero)
+    }
+
+    ///Returns whether every coefficient outside the diagonal is nil.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// assert!(Matrix::from([[1, 0], [0, 3]]).is_diagonal());
+    /// assert!(!Matrix::from([[1, 0], [2, 3]]).is_diagonal());
+    /// ```
+    pub fn is_diagonal(&self) -> bool {
+        self.all_where(|row, col| row != col, C::is_zero)
+    }
+}
+
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS>
+where
+    C: Signed + PartialOrd,
+{
+    ///Returns whether every coefficient below the diagonal is no bigger than `tolerance` in absolute value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1.0, 2.0], [1e-14, 3.0]]);
+    /// assert!(!mat.is_upper_triangular());
+    /// assert!(mat.is_upper_triangular_with_tolerance(&1e-12));
+    /// ```
+    pub fn is_upper_triangular_with_tolerance(&self, tolerance: &C) -> bool {
+        self.all_where(|row, col| row > col, |c| c.abs() <= *tolerance)
+    }
+
+    ///Returns whether every coefficient above the diagonal is no bigger than `tolerance` in absolute value.
+    pub fn is_lower_triangular_with_tolerance(&self, tolerance: &C) -> bool {
+        self.all_where(|row, col| row < col, |c| c.abs() <= *tolerance)
+    }
+
+    ///Returns whether every coefficient outside the diagonal is no bigger than `tolerance` in absolute value.
+    pub fn is_diagonal_with_tolerance(&self, tolerance: &C) -> bool {
+        self.all_where(|row, col| row != col, |c| c.abs() <= *tolerance)
+    }
+}
+
+impl<C, const SIZE: usize> Matrix<C, SIZE, SIZE>
+where
+    C: PartialEq,
+{
+    ///Returns whether the matrix is equal to its transpose.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// assert!(Matrix::from([[1, 2], [2, 3]]).is_symmetric());
+    /// assert!(!Matrix::from([[1, 2], [0, 3]]).is_symmetric());
+    /// ```
+    pub fn is_symmetric(&self) -> bool {
+        (0..SIZE).all(|row| (row + 1..SIZE).all(|col| self.data[row][col] == self.data[col][row]))
+    }
+}
+
+impl<C, const SIZE: usize> Matrix<C, SIZE, SIZE>
+where
+    C: Signed + PartialOrd + Clone,
+{
+    ///Returns whether every coefficient is within `tolerance` of its mirror across the diagonal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[1.0, 0.1 + 0.2], [0.3, 1.0]]);
+    /// assert!(!mat.is_symmetric());
+    /// assert!(mat.is_symmetric_with_tolerance(&1e-12));
+    /// ```
+    pub fn is_symmetric_with_tolerance(&self, tolerance: &C) -> bool {
+        (0..SIZE).all(|row| {
+            (row + 1..SIZE).all(|col| {
+                (self.data[row][col].clone() - self.data[col][row].clone()).abs() <= *tolerance
+            })
+        })
+    }
+}
+
+impl<C, const SIZE: usize> Matrix<C, SIZE, SIZE>
+where
+    C: Signed + PartialOrd + Clone + Sum,
+{
+    ///Returns whether every coefficient is within `tolerance` of the one of the identity matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mat = Matrix::from([[2.0, 1.0], [4.0, 3.0]]);
+    /// assert!((&mat * &mat.inverse().unwrap()).is_identity(&1e-12));
+    /// assert!(!mat.is_identity(&1e-12));
+    /// assert!(Matrix::<i32, 3, 3>::identity().is_identity(&0));
+    /// ```
+    pub fn is_identity(&self, tolerance: &C) -> bool {
+        self.approx_eq(&Self::identity(), tolerance.clone())
+    }
+
+    ///Returns whether the matrix is orthogonal, that is whether AᵀA is the identity, up to `tolerance`.
+    ///
+    ///Every coefficient of AᵀA must be within `tolerance` of the one of the identity.
+    ///The columns of orthogonal matrices form an orthonormal basis, their inverse being their transpose.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let rotation = Matrix::from([[0.6, -0.8], [0.8, 0.6]]);
+    /// assert!(rotation.is_orthogonal(&1e-12));
+    /// assert!(!(rotation * 2.0).is_orthogonal(&1e-12));
+    /// ```
+    pub fn is_orthogonal(&self, tolerance: &C) -> bool {
+        (&self.transpose() * self).is_identity(tolerance)
+    }
+}
@@ -0,0 +1,127 @@
+//! The Moore-Penrose pseudo-inverse, computed from a singular value decomposition, for floating point coefficients.
+
+use crate::Matrix;
+use num::traits::Float;
+
+//Bound on the number of Jacobi sweeps, convergence being quadratic a handful is enough in practice.
+const MAX_SWEEPS: usize = 60;
+
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS>
+where
+    C: Float,
+{
+    //Returns `(W, V)` where `V` is orthogonal and the columns of `W = self * V` are orthogonal,
+    //so that the norms of the columns of `W` are the singular values, as computed by the one-sided Jacobi method.
+    //The normalized non-nil columns of `W` and the columns of `V` are the left and right singular vectors.
+    pub(crate) fn jacobi_svd(&self) -> (Self, Matrix<C, COLS, COLS>) {
+        let mut w = self.clone();
+        let mut v = Matrix::<C, COLS, COLS>::identity();
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for p in 0..COLS {
+                for q in p + 1..COLS {
+                    let (alpha, beta, gamma) = w.data.iter().fold(
+                        (C::zero(), C::zero(), C::zero()),
+                        |(alpha, beta, gamma), line| {
+                            (
+                                alpha + line[p] * line[p],
+                                beta + line[q] * line[q],
+                                gamma + line[p] * line[q],
+                            )
+                        },
+                    );
+                    if gamma.abs() <= C::epsilon() * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+                    //rotation making columns `p` and `q` orthogonal
+                    let zeta = (beta - alpha) / (gamma + gamma);
+                    let t = zeta.signum() / (zeta.abs() + (C::one() + zeta * zeta).sqrt());
+                    let c = (C::one() + t * t).sqrt().recip();
+                    let s = c * t;
+                    w.apply_givens_right(p, q, c, -s)
+                        .expect("p and q are distinct columns");
+                    v.apply_givens_right(p, q, c, -s)
+                        .expect("p and q are distinct columns");
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+        (w, v)
+    }
+
+    ///Returns the Moore-Penrose pseudo-inverse of the matrix.
+    ///
+    ///Singular values smaller than `max(ROWS, COLS) * ε * σ₁` are considered nil, `σ₁` being the biggest one,
+    ///see [`pinv_with_tolerance`] to choose this threshold.
+    ///
+    ///The pseudo-inverse is the inverse of invertible matrices. Otherwise `pinv * b` is the least squares solution of `self * x = b`
+    ///with the smallest norm, which makes it suited to underdetermined and rank deficient systems.
+    ///
+    /// [`pinv_with_tolerance`]: #method.pinv_with_tolerance
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// // x + y = 2 has infinitely many solutions, the one with the smallest norm is (1, 1)
+    /// let a = Matrix::from([[1.0, 1.0]]);
+    /// let x = &a.pinv() * &Matrix::from([[2.0]]);
+    /// assert!(x.approx_eq(&Matrix::from([[1.0], [1.0]]), 1e-12));
+    /// ```
+    pub fn pinv(&self) -> Matrix<C, COLS, ROWS> {
+        let (w, v) = self.jacobi_svd();
+        let largest = singular_values(&w).fold(C::zero(), C::max);
+        let dimension = C::from(ROWS.max(COLS)).unwrap_or_else(C::infinity);
+        pseudo_inverse(&w, &v, dimension * C::epsilon() * largest)
+    }
+
+    ///Returns the Moore-Penrose pseudo-inverse of the matrix, singular values no bigger than `tolerance` being considered nil.
+    ///
+    ///See [`pinv`] for details. Discarding small singular values regularizes ill-conditioned problems at the cost of accuracy.
+    ///
+    /// [`pinv`]: #method.pinv
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let a = Matrix::from([[1.0, 0.0], [0.0, 1e-9]]);
+    /// assert!(a.pinv().approx_eq(&Matrix::from([[1.0, 0.0], [0.0, 1e9]]), 1e-3));
+    /// assert!(a.pinv_with_tolerance(&1e-6).approx_eq(&Matrix::from([[1.0, 0.0], [0.0, 0.0]]), 1e-12));
+    /// ```
+    pub fn pinv_with_tolerance(&self, tolerance: &C) -> Matrix<C, COLS, ROWS> {
+        let (w, v) = self.jacobi_svd();
+        pseudo_inverse(&w, &v, *tolerance)
+    }
+}
+
+//Returns the norms of the columns of `w`.
+fn singular_values<C: Float, const ROWS: usize, const COLS: usize>(
+    w: &Matrix<C, ROWS, COLS>,
+) -> impl Iterator<Item = C> + '_ {
+    w.get_columns()
+        .map(|col| col.iter().fold(C::zero(), |acc, c| acc + **c * **c).sqrt())
+}
+
+//Returns `VΣ⁺Uᵀ`, that is the sum of `vₖwₖᵀ/σₖ²` over the singular values bigger than `tolerance`.
+fn pseudo_inverse<C: Float, const ROWS: usize, const COLS: usize>(
+    w: &Matrix<C, ROWS, COLS>,
+    v: &Matrix<C, COLS, COLS>,
+    tolerance: C,
+) -> Matrix<C, COLS, ROWS> {
+    let inverse_squares: [C; COLS] = {
+        let mut values = singular_values(w);
+        core::array::from_fn(|_| match values.next() {
+            Some(sigma) if sigma > tolerance => (sigma * sigma).recip(),
+            _ => C::zero(),
+        })
+    };
+    Matrix::from_fn(|row, col| {
+        (0..COLS).fold(C::zero(), |acc, k| {
+            acc + v.data[row][k] * w.data[col][k] * inverse_squares[k]
+        })
+    })
+}
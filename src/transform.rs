@@ -0,0 +1,546 @@
+//! Geometric transformations of the plane and of space.
+
+use crate::{ColVector, Error, Matrix};
+use num::traits::Float;
+
+///Orientation of the view space axes used by projections.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Handedness {
+    ///The camera looks towards the positive z axis, as in Direct3D.
+    Left,
+    ///The camera looks towards the negative z axis, as in OpenGL and most wgpu code.
+    Right,
+}
+
+///Range of the depth in normalized device coordinates produced by projections.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DepthRange {
+    ///Depths go from 0 at the near plane to 1 at the far plane, as in Direct3D, Vulkan, Metal and wgpu.
+    ZeroToOne,
+    ///Depths go from -1 at the near plane to 1 at the far plane, as in OpenGL.
+    MinusOneToOne,
+}
+
+impl DepthRange {
+    fn lowest<C: Float>(self) -> C {
+        match self {
+            DepthRange::ZeroToOne => C::zero(),
+            DepthRange::MinusOneToOne => -C::one(),
+        }
+    }
+}
+
+impl<C> Matrix<C, 2, 2>
+where
+    C: Float,
+{
+    ///Returns the counterclockwise rotation of the plane by `angle` radians.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{ColVector, Matrix};
+    /// let r = Matrix::rotation(std::f64::consts::FRAC_PI_2);
+    /// let v = &r * &ColVector::from([[1.0], [0.0]]);
+    /// assert!(v.approx_eq(&ColVector::from([[0.0], [1.0]]), 1e-12));
+    /// ```
+    pub fn rotation(angle: C) -> Self {
+        let (s, c) = angle.sin_cos();
+        Matrix::from([[c, -s], [s, c]])
+    }
+}
+
+impl<C> Matrix<C, 3, 3>
+where
+    C: Float,
+{
+    ///Returns the rotation of space by `angle` radians around the x axis.
+    ///
+    ///Rotations follow the right-hand rule: seen from the tip of the axis, they are counterclockwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{ColVector, Matrix};
+    /// let r = Matrix::rotation_x(std::f64::consts::FRAC_PI_2);
+    /// let v = &r * &ColVector::from([[0.0], [1.0], [0.0]]);
+    /// assert!(v.approx_eq(&ColVector::from([[0.0], [0.0], [1.0]]), 1e-12));
+    /// ```
+    pub fn rotation_x(angle: C) -> Self {
+        let (s, c) = angle.sin_cos();
+        let (o, i) = (C::zero(), C::one());
+        Matrix::from([[i, o, o], [o, c, -s], [o, s, c]])
+    }
+
+    ///Returns the rotation of space by `angle` radians around the y axis, see [`rotation_x`].
+    ///
+    /// [`rotation_x`]: #method.rotation_x
+    pub fn rotation_y(angle: C) -> Self {
+        let (s, c) = angle.sin_cos();
+        let (o, i) = (C::zero(), C::one());
+        Matrix::from([[c, o, s], [o, i, o], [-s, o, c]])
+    }
+
+    ///Returns the rotation of space by `angle` radians around the z axis, see [`rotation_x`].
+    ///
+    /// [`rotation_x`]: #method.rotation_x
+    pub fn rotation_z(angle: C) -> Self {
+        let (s, c) = angle.sin_cos();
+        let (o, i) = (C::zero(), C::one());
+        Matrix::from([[c, -s, o], [s, c, o], [o, o, i]])
+    }
+
+    ///Returns the rotation of space by `angle` radians around `axis`, which doesn't need to be normalized.
+    ///
+    ///Returns NilVector if all coefficients of `axis` are nil.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{ColVector, Matrix};
+    /// let axis = ColVector::from([[1.0], [1.0], [1.0]]);
+    /// let r = Matrix::from_axis_angle(&axis, 2.0 * std::f64::consts::FRAC_PI_3).unwrap();
+    /// //a third of a turn around the diagonal cycles the axes
+    /// assert!(r.approx_eq(&Matrix::from([[0.0, 0.0, 1.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]), 1e-12));
+    /// ```
+    pub fn from_axis_angle(axis: &ColVector<C, 3>, angle: C) -> Result<Self, Error> {
+        let squared_norm = axis.iter().fold(C::zero(), |acc, c| acc + *c * *c);
+        if squared_norm.is_zero() {
+            return Err(Error::NilVector);
+        }
+        let norm = squared_norm.sqrt();
+        let k = [axis[0] / norm, axis[1] / norm, axis[2] / norm];
+        let (s, c) = angle.sin_cos();
+        let t = C::one() - c;
+        //Rodrigues' formula: cI + s[k]× + (1 - c)kkᵀ
+        Ok(Matrix::from([
+            [
+                c + t * k[0] * k[0],
+                t * k[0] * k[1] - s * k[2],
+                t * k[0] * k[2] + s * k[1],
+            ],
+            [
+                t * k[1] * k[0] + s * k[2],
+                c + t * k[1] * k[1],
+                t * k[1] * k[2] - s * k[0],
+            ],
+            [
+                t * k[2] * k[0] - s * k[1],
+                t * k[2] * k[1] + s * k[0],
+                c + t * k[2] * k[2],
+            ],
+        ]))
+    }
+
+    ///Returns the rotation represented by the quaternion `[w, x, y, z]`, `w` being the scalar part.
+    ///
+    ///The quaternion is normalized first, so it doesn't need to be a unit quaternion.
+    ///Returns NilVector if all its coefficients are nil.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let half = std::f64::consts::FRAC_PI_4;
+    /// let r = Matrix::from_quaternion([half.cos(), 0.0, 0.0, half.sin()]).unwrap();
+    /// assert!(r.approx_eq(&Matrix::rotation_z(std::f64::consts::FRAC_PI_2), 1e-12));
+    /// ```
+    pub fn from_quaternion(quaternion: [C; 4]) -> Result<Self, Error> {
+        let squared_norm = quaternion.iter().fold(C::zero(), |acc, c| acc + *c * *c);
+        if squared_norm.is_zero() {
+            return Err(Error::NilVector);
+        }
+        let [w, x, y, z] = quaternion;
+        //dividing by the squared norm normalizes the products below
+        let two = (C::one() + C::one()) / squared_norm;
+        let i = C::one();
+        Ok(Matrix::from([
+            [
+                i - two * (y * y + z * z),
+                two * (x * y - w * z),
+                two * (x * z + w * y),
+            ],
+            [
+                two * (x * y + w * z),
+                i - two * (x * x + z * z),
+                two * (y * z - w * x),
+            ],
+            [
+                two * (x * z - w * y),
+                two * (y * z + w * x),
+                i - two * (x * x + y * y),
+            ],
+        ]))
+    }
+
+    ///Returns the unit quaternion `[w, x, y, z]` representing the rotation, with a non-negative scalar part `w`.
+    ///
+    ///The matrix is expected to be a rotation, that is orthogonal with a determinant of one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let q = Matrix::rotation_x(std::f64::consts::PI).to_quaternion();
+    /// assert!(q.iter().zip([0.0, 1.0, 0.0, 0.0].iter()).all(|(a, b)| (a - b).abs() < 1e-12));
+    /// ```
+    pub fn to_quaternion(&self) -> [C; 4] {
+        let m = &self.data;
+        let i = C::one();
+        let four = i + i + i + i;
+        //start from the largest component to avoid dividing by a small number
+        let candidates = [
+            i + m[0][0] + m[1][1] + m[2][2],
+            i + m[0][0] - m[1][1] - m[2][2],
+            i - m[0][0] + m[1][1] - m[2][2],
+            i - m[0][0] - m[1][1] + m[2][2],
+        ];
+        let largest = (1..4).fold(0, |best, k| {
+            if candidates[k] > candidates[best] {
+                k
+            } else {
+                best
+            }
+        });
+        let s = candidates[largest].sqrt() * (i + i);
+        let q = match largest {
+            0 => [
+                s / four,
+                (m[2][1] - m[1][2]) / s,
+                (m[0][2] - m[2][0]) / s,
+                (m[1][0] - m[0][1]) / s,
+            ],
+            1 => [
+                (m[2][1] - m[1][2]) / s,
+                s / four,
+                (m[0][1] + m[1][0]) / s,
+                (m[0][2] + m[2][0]) / s,
+            ],
+            2 => [
+                (m[0][2] - m[2][0]) / s,
+                (m[0][1] + m[1][0]) / s,
+                s / four,
+                (m[1][2] + m[2][1]) / s,
+            ],
+            _ => [
+                (m[1][0] - m[0][1]) / s,
+                (m[0][2] + m[2][0]) / s,
+                (m[1][2] + m[2][1]) / s,
+                s / four,
+            ],
+        };
+        let norm = q.iter().fold(C::zero(), |acc, c| acc + *c * *c).sqrt();
+        let norm = if q[0] < C::zero() { -norm } else { norm };
+        [q[0] / norm, q[1] / norm, q[2] / norm, q[3] / norm]
+    }
+}
+
+impl<C> Matrix<C, 2, 2>
+where
+    C: Float,
+{
+    ///Returns the 3×3 matrix applying the same linear map to homogeneous coordinates of the plane.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let m = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
+    /// assert_eq!(m.to_homogeneous(), Matrix::from([[1.0, 2.0, 0.0], [3.0, 4.0, 0.0], [0.0, 0.0, 1.0]]));
+    /// ```
+    pub fn to_homogeneous(&self) -> Matrix<C, 3, 3> {
+        to_homogeneous(self)
+    }
+}
+
+impl<C> Matrix<C, 3, 3>
+where
+    C: Float,
+{
+    ///Returns the 4×4 matrix applying the same linear map to homogeneous coordinates of space.
+    ///
+    ///This is how rotations of space are combined with translations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{ColVector, Matrix};
+    /// let m = &Matrix::translation_3d(1.0, 0.0, 0.0) * &Matrix::rotation_z(std::f64::consts::PI).to_homogeneous();
+    /// let p = m.transform_point(&ColVector::from([[1.0], [2.0], [3.0]]));
+    /// assert!(p.approx_eq(&ColVector::from([[0.0], [-2.0], [3.0]]), 1e-12));
+    /// ```
+    pub fn to_homogeneous(&self) -> Matrix<C, 4, 4> {
+        to_homogeneous(self)
+    }
+
+    ///Returns the translation of the plane by `(x, y)` in homogeneous coordinates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{ColVector, Matrix};
+    /// let t = Matrix::translation_2d(1.0, -2.0);
+    /// assert_eq!(t.transform_point(&ColVector::from([[1.0], [1.0]])), ColVector::from([[2.0], [-1.0]]));
+    /// ```
+    pub fn translation_2d(x: C, y: C) -> Self {
+        let mut m = Self::identity();
+        m.data[0][2] = x;
+        m.data[1][2] = y;
+        m
+    }
+
+    ///Returns the scaling of the plane by `x` and `y` along the axes in homogeneous coordinates.
+    pub fn scaling_2d(x: C, y: C) -> Self {
+        Matrix::from_diagonal([x, y, C::one()])
+    }
+
+    ///Returns the counterclockwise rotation of the plane around the origin in homogeneous coordinates.
+    ///
+    ///This is the same as `Matrix::rotation(angle).to_homogeneous()`.
+    pub fn rotation_2d(angle: C) -> Self {
+        Matrix::rotation(angle).to_homogeneous()
+    }
+
+    ///Applies a transformation in homogeneous coordinates to a point of the plane.
+    ///
+    ///The result is divided by its homogeneous coordinate, which is one for affine transformations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{ColVector, Matrix};
+    /// //scale around the point (1, 1)
+    /// let m = &(&Matrix::translation_2d(1.0, 1.0) * &Matrix::scaling_2d(2.0, 3.0)) * &Matrix::translation_2d(-1.0, -1.0);
+    /// assert_eq!(m.transform_point(&ColVector::from([[2.0], [2.0]])), ColVector::from([[3.0], [4.0]]));
+    /// ```
+    pub fn transform_point(&self, point: &ColVector<C, 2>) -> ColVector<C, 2> {
+        let [x, y] = transform_point(self, [point[0], point[1]]);
+        Matrix::from([[x], [y]])
+    }
+
+    ///Applies a transformation in homogeneous coordinates to a direction of the plane, which ignores translations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{ColVector, Matrix};
+    /// let m = Matrix::translation_2d(5.0, 5.0);
+    /// let v = ColVector::from([[1.0], [2.0]]);
+    /// assert_eq!(m.transform_vector(&v), v);
+    /// ```
+    pub fn transform_vector(&self, vector: &ColVector<C, 2>) -> ColVector<C, 2> {
+        let [x, y] = transform_vector(self, [vector[0], vector[1]]);
+        Matrix::from([[x], [y]])
+    }
+}
+
+impl<C> Matrix<C, 4, 4>
+where
+    C: Float,
+{
+    ///Returns the translation of space by `(x, y, z)` in homogeneous coordinates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{ColVector, Matrix};
+    /// let t = Matrix::translation_3d(1.0, 2.0, 3.0);
+    /// let p = ColVector::from([[1.0], [1.0], [1.0]]);
+    /// assert_eq!(t.transform_point(&p), ColVector::from([[2.0], [3.0], [4.0]]));
+    /// ```
+    pub fn translation_3d(x: C, y: C, z: C) -> Self {
+        let mut m = Self::identity();
+        m.data[0][3] = x;
+        m.data[1][3] = y;
+        m.data[2][3] = z;
+        m
+    }
+
+    ///Returns the scaling of space by `x`, `y` and `z` along the axes in homogeneous coordinates.
+    pub fn scaling_3d(x: C, y: C, z: C) -> Self {
+        Matrix::from_diagonal([x, y, z, C::one()])
+    }
+
+    ///Applies a transformation in homogeneous coordinates to a point of space.
+    ///
+    ///The result is divided by its homogeneous coordinate, which is one for affine transformations.
+    pub fn transform_point(&self, point: &ColVector<C, 3>) -> ColVector<C, 3> {
+        let [x, y, z] = transform_point(self, [point[0], point[1], point[2]]);
+        Matrix::from([[x], [y], [z]])
+    }
+
+    ///Applies a transformation in homogeneous coordinates to a direction of space, which ignores translations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{ColVector, Matrix};
+    /// let m = &Matrix::translation_3d(1.0, 1.0, 1.0) * &Matrix::scaling_3d(1.0, 2.0, 3.0);
+    /// let v = ColVector::from([[1.0], [1.0], [1.0]]);
+    /// assert_eq!(m.transform_vector(&v), ColVector::from([[1.0], [2.0], [3.0]]));
+    /// ```
+    pub fn transform_vector(&self, vector: &ColVector<C, 3>) -> ColVector<C, 3> {
+        let [x, y, z] = transform_vector(self, [vector[0], vector[1], vector[2]]);
+        Matrix::from([[x], [y], [z]])
+    }
+}
+
+///Projections.
+impl<C> Matrix<C, 4, 4>
+where
+    C: Float,
+{
+    ///Returns a right-handed perspective projection with a depth range of zero to one, as expected by wgpu.
+    ///
+    ///`fov` is the vertical field of view in radians and `aspect` the ratio of the width by the height.
+    ///See [`perspective_with`] for other conventions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{ColVector, Matrix};
+    /// let p = Matrix::perspective(std::f64::consts::FRAC_PI_2, 2.0, 1.0, 10.0);
+    /// let near = p.transform_point(&ColVector::from([[2.0], [1.0], [-1.0]]));
+    /// let far = p.transform_point(&ColVector::from([[-20.0], [10.0], [-10.0]]));
+    /// assert!(near.approx_eq(&ColVector::from([[1.0], [1.0], [0.0]]), 1e-12));
+    /// assert!(far.approx_eq(&ColVector::from([[-1.0], [1.0], [1.0]]), 1e-12));
+    /// ```
+    ///
+    /// [`perspective_with`]: #method.perspective_with
+    pub fn perspective(fov: C, aspect: C, near: C, far: C) -> Self {
+        Self::perspective_with(
+            fov,
+            aspect,
+            near,
+            far,
+            Handedness::Right,
+            DepthRange::ZeroToOne,
+        )
+    }
+
+    ///Returns a perspective projection using the given handedness and depth range.
+    ///
+    ///`near` and `far` are the positive distances of the clipping planes to the camera.
+    pub fn perspective_with(
+        fov: C,
+        aspect: C,
+        near: C,
+        far: C,
+        handedness: Handedness,
+        depth: DepthRange,
+    ) -> Self {
+        let (o, i) = (C::zero(), C::one());
+        let focal = i / (fov / (i + i)).tan();
+        let lowest = depth.lowest::<C>();
+        //maps the distance `near` to `lowest` and `far` to one once divided by the distance
+        let scale = (far - lowest * near) / (far - near);
+        let offset = far * near * (lowest - i) / (far - near);
+        let sign = match handedness {
+            Handedness::Left => i,
+            Handedness::Right => -i,
+        };
+        Matrix::from([
+            [focal / aspect, o, o, o],
+            [o, focal, o, o],
+            [o, o, sign * scale, offset],
+            [o, o, sign, o],
+        ])
+    }
+
+    ///Returns a right-handed orthographic projection with a depth range of zero to one, as expected by wgpu.
+    ///
+    ///The box bounded by `left`, `right`, `bottom`, `top` and the clipping planes at distances `near` and `far` is mapped to normalized device coordinates.
+    ///See [`orthographic_with`] for other conventions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{ColVector, Matrix};
+    /// let p = Matrix::orthographic(0.0, 4.0, 0.0, 2.0, 1.0, 3.0);
+    /// let corner = p.transform_point(&ColVector::from([[4.0], [0.0], [-3.0]]));
+    /// assert_eq!(corner, ColVector::from([[1.0], [-1.0], [1.0]]));
+    /// ```
+    ///
+    /// [`orthographic_with`]: #method.orthographic_with
+    pub fn orthographic(left: C, right: C, bottom: C, top: C, near: C, far: C) -> Self {
+        Self::orthographic_with(
+            left,
+            right,
+            bottom,
+            top,
+            near,
+            far,
+            Handedness::Right,
+            DepthRange::ZeroToOne,
+        )
+    }
+
+    ///Returns an orthographic projection using the given handedness and depth range.
+    #[allow(clippy::too_many_arguments)]
+    pub fn orthographic_with(
+        left: C,
+        right: C,
+        bottom: C,
+        top: C,
+        near: C,
+        far: C,
+        handedness: Handedness,
+        depth: DepthRange,
+    ) -> Self {
+        let (o, i) = (C::zero(), C::one());
+        let two = i + i;
+        let lowest = depth.lowest::<C>();
+        let scale = (i - lowest) / (far - near);
+        let sign = match handedness {
+            Handedness::Left => i,
+            Handedness::Right => -i,
+        };
+        Matrix::from([
+            [two / (right - left), o, o, -(right + left) / (right - left)],
+            [o, two / (top - bottom), o, -(top + bottom) / (top - bottom)],
+            [o, o, sign * scale, lowest - scale * near],
+            [o, o, o, i],
+        ])
+    }
+}
+
+//Embeds a `N`×`N` linear map in a `H`×`H` matrix, `H` being `N + 1`.
+fn to_homogeneous<C: Float, const N: usize, const H: usize>(
+    m: &Matrix<C, N, N>,
+) -> Matrix<C, H, H> {
+    Matrix::from_fn(|row, col| {
+        if row < N && col < N {
+            m.data[row][col]
+        } else if row == col {
+            C::one()
+        } else {
+            C::zero()
+        }
+    })
+}
+
+fn transform_point<C: Float, const N: usize, const H: usize>(
+    m: &Matrix<C, H, H>,
+    point: [C; N],
+) -> [C; N] {
+    let apply = |row: usize| {
+        point
+            .iter()
+            .enumerate()
+            .fold(m.data[row][N], |acc, (col, c)| acc + m.data[row][col] * *c)
+    };
+    let w = apply(N);
+    core::array::from_fn(|row| apply(row) / w)
+}
+
+fn transform_vector<C: Float, const N: usize, const H: usize>(
+    m: &Matrix<C, H, H>,
+    vector: [C; N],
+) -> [C; N] {
+    core::array::from_fn(|row| {
+        vector
+            .iter()
+            .enumerate()
+            .fold(C::zero(), |acc, (col, c)| acc + m.data[row][col] * *c)
+    })
+}
@@ -0,0 +1,78 @@
+//! Linear systems whose matrix is triangular, solved by substitution.
+
+use crate::{Error, Matrix};
+use core::ops::Range;
+use num::traits::Num;
+
+impl<C, const SIZE: usize> Matrix<C, SIZE, SIZE>
+where
+    C: Num + Clone,
+{
+    ///Solves the linear system `self * x = b` by forward substitution, `self` being lower triangular.
+    ///
+    ///Only the lower triangle of the matrix is read, the coefficients above the diagonal being considered nil.
+    ///Every column of `b` is solved for, in `O(SIZE²)` operations each. Returns Singular if a coefficient of the diagonal is nil.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let l = Matrix::from([[2.0, 0.0, 0.0], [1.0, 1.0, 0.0], [-1.0, 3.0, 4.0]]);
+    /// let x = l.solve_lower_triangular(Matrix::from([[2.0], [3.0], [9.0]])).unwrap();
+    /// assert_eq!(x, Matrix::from([[1.0], [2.0], [1.0]]));
+    /// ```
+    pub fn solve_lower_triangular<const N: usize>(
+        &self,
+        b: Matrix<C, SIZE, N>,
+    ) -> Result<Matrix<C, SIZE, N>, Error> {
+        let mut x = b;
+        for row in 0..SIZE {
+            self.substitute(&mut x, row, 0..row)?;
+        }
+        Ok(x)
+    }
+
+    ///Solves the linear system `self * x = b` by back substitution, `self` being upper triangular.
+    ///
+    ///Only the upper triangle of the matrix is read, the coefficients below the diagonal being considered nil.
+    ///Every column of `b` is solved for, in `O(SIZE²)` operations each. Returns Singular if a coefficient of the diagonal is nil.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let u = Matrix::from([[2.0, 1.0, -1.0], [0.0, 1.0, 3.0], [0.0, 0.0, 4.0]]);
+    /// let x = u.solve_upper_triangular(Matrix::from([[3.0], [5.0], [4.0]])).unwrap();
+    /// assert_eq!(x, Matrix::from([[1.0], [2.0], [1.0]]));
+    /// ```
+    pub fn solve_upper_triangular<const N: usize>(
+        &self,
+        b: Matrix<C, SIZE, N>,
+    ) -> Result<Matrix<C, SIZE, N>, Error> {
+        let mut x = b;
+        for row in (0..SIZE).rev() {
+            self.substitute(&mut x, row, row + 1..SIZE)?;
+        }
+        Ok(x)
+    }
+
+    //Computes row `row` of the solution, the ones of `known` being already computed.
+    fn substitute<const N: usize>(
+        &self,
+        x: &mut Matrix<C, SIZE, N>,
+        row: usize,
+        known: Range<usize>,
+    ) -> Result<(), Error> {
+        let pivot = &self.data[row][row];
+        if pivot.is_zero() {
+            return Err(Error::Singular);
+        }
+        for col in 0..N {
+            let acc = known.clone().fold(x.data[row][col].clone(), |acc, k| {
+                acc - self.data[row][k].clone() * x.data[k][col].clone()
+            });
+            x.data[row][col] = acc / pivot.clone();
+        }
+        Ok(())
+    }
+}
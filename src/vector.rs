@@ -0,0 +1,240 @@
+//! Vectors, which are nothing more than matrices with a single column or a single row.
+//!
+//! Methods of this module only compile for such matrices.
+
+use crate::{Error, Matrix};
+use core::iter::Sum;
+use core::ops::{Index, IndexMut, Mul, Sub};
+use num::traits::Float;
+
+///Column vector of dimension `N`.
+pub type ColVector<C, const N: usize> = Matrix<C, N, 1>;
+
+///Row vector of dimension `N`.
+pub type RowVector<C, const N: usize> = Matrix<C, 1, N>;
+
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS> {
+    //Referencing this fails to compile if the matrix isn't a vector.
+    const IS_VECTOR: () = assert!(
+        ROWS == 1 || COLS == 1,
+        "this operation is only available for vectors"
+    );
+
+    ///Returns the dot product of two vectors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::ColVector;
+    /// let u = ColVector::from([[1], [2], [3]]);
+    /// let v = ColVector::from([[4], [-5], [6]]);
+    /// assert_eq!(u.dot(&v), 12);
+    /// ```
+    ///
+    /// This only works for vectors.
+    /// ```compile_fail
+    ///# use matrix::Matrix;
+    /// let m = Matrix::from([[1, 2], [3, 4]]);
+    /// m.dot(&m);
+    /// ```
+    pub fn dot(&self, other: &Self) -> C
+    where
+        C: Mul<Output = C> + Sum + Clone,
+    {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::IS_VECTOR;
+        self.iter()
+            .zip(other.iter())
+            .map(|(a, b)| a.clone() * b.clone())
+            .sum()
+    }
+
+    ///Returns the euclidean norm, or length, of a vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::RowVector;
+    /// let v = RowVector::from([[3.0, 4.0]]);
+    /// assert_eq!(v.norm(), 5.0);
+    /// ```
+    pub fn norm(&self) -> C
+    where
+        C: Float + Sum,
+    {
+        self.dot(self).sqrt()
+    }
+
+    ///Scales the vector so that its norm is one.
+    ///
+    ///Returns NilVector if all coefficients are nil, in which case the vector is left untouched.
+    ///See [`normalized`] for examples.
+    ///
+    /// [`normalized`]: #method.normalized
+    pub fn normalize(&mut self) -> Result<(), Error>
+    where
+        C: Float + Sum,
+    {
+        let norm = self.norm();
+        if norm.is_zero() {
+            return Err(Error::NilVector);
+        }
+        self.iter_mut().for_each(|c| *c = *c / norm);
+        Ok(())
+    }
+
+    ///Returns the vector with the same direction and a norm of one.
+    ///
+    ///Returns NilVector if all coefficients are nil.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{ColVector, Error};
+    /// let v = ColVector::from([[3.0], [0.0], [-4.0]]);
+    /// assert_eq!(v.normalized(), Ok(ColVector::from([[0.6], [0.0], [-0.8]])));
+    /// assert_eq!(ColVector::<f64, 2>::from([[0.0], [0.0]]).normalized(), Err(Error::NilVector));
+    /// ```
+    pub fn normalized(&self) -> Result<Self, Error>
+    where
+        C: Float + Sum,
+    {
+        let mut v = self.clone();
+        v.normalize()?;
+        Ok(v)
+    }
+}
+
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS>
+where
+    C: Float,
+{
+    ///Scales every row so that its euclidean norm is one. Nil rows are left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mut mat = Matrix::from([[3.0, 4.0], [0.0, 0.0], [0.0, -2.0]]);
+    /// mat.normalize_rows();
+    /// assert_eq!(mat, Matrix::from([[0.6, 0.8], [0.0, 0.0], [0.0, -1.0]]));
+    /// ```
+    pub fn normalize_rows(&mut self) {
+        for line in self.data.iter_mut() {
+            let norm = line.iter().fold(C::zero(), |acc, c| acc + *c * *c).sqrt();
+            if !norm.is_zero() {
+                line.iter_mut().for_each(|c| *c = *c / norm);
+            }
+        }
+    }
+}
+
+impl<C, const ROWS: usize> Matrix<C, ROWS, 1>
+where
+    C: Mul<Output = C> + Clone,
+{
+    ///Returns the outer product of a column vector by a row vector, that is the matrix of the products of their coefficients.
+    ///
+    ///This is the same as the matrix product of both vectors but doesn't need the coefficients to be summed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{ColVector, Matrix, RowVector};
+    /// let u = ColVector::from([[1], [2]]);
+    /// let v = RowVector::from([[3, 4, 5]]);
+    /// assert_eq!(u.outer(&v), Matrix::from([[3, 4, 5], [6, 8, 10]]));
+    /// ```
+    pub fn outer<const COLS: usize>(&self, other: &Matrix<C, 1, COLS>) -> Matrix<C, ROWS, COLS> {
+        Matrix::from_fn(|row, col| self.data[row][0].clone() * other.data[0][col].clone())
+    }
+}
+
+impl<C> Matrix<C, 3, 1>
+where
+    C: Mul<Output = C> + Sub<Output = C> + Clone,
+{
+    ///Returns the cross product of two column vectors of dimension 3.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::ColVector;
+    /// let x = ColVector::from([[1], [0], [0]]);
+    /// let y = ColVector::from([[0], [1], [0]]);
+    /// assert_eq!(x.cross(&y), ColVector::from([[0], [0], [1]]));
+    /// ```
+    pub fn cross(&self, other: &Self) -> Self {
+        let [x, y, z] = cross(
+            [&self[0], &self[1], &self[2]],
+            [&other[0], &other[1], &other[2]],
+        );
+        Matrix::from([[x], [y], [z]])
+    }
+}
+
+impl<C> Matrix<C, 1, 3>
+where
+    C: Mul<Output = C> + Sub<Output = C> + Clone,
+{
+    ///Returns the cross product of two row vectors of dimension 3.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::RowVector;
+    /// let x = RowVector::from([[1, 0, 0]]);
+    /// let y = RowVector::from([[0, 1, 0]]);
+    /// assert_eq!(y.cross(&x), RowVector::from([[0, 0, -1]]));
+    /// ```
+    pub fn cross(&self, other: &Self) -> Self {
+        let [a] = &self.data;
+        let [b] = &other.data;
+        Matrix::from([cross([&a[0], &a[1], &a[2]], [&b[0], &b[1], &b[2]])])
+    }
+}
+
+fn cross<C>(a: [&C; 3], b: [&C; 3]) -> [C; 3]
+where
+    C: Mul<Output = C> + Sub<Output = C> + Clone,
+{
+    let product = |i: usize, j: usize| a[i].clone() * b[j].clone() - a[j].clone() * b[i].clone();
+    [product(1, 2), product(2, 0), product(0, 1)]
+}
+
+///Access to a coefficient of a column vector with a single index.
+///
+///This panics if `index` is out of bounds. Matrices with several columns have no such access, which the type system checks:
+///row vectors are indexed by `(0, index)` since an implementation for them would overlap with this one for 1×1 matrices.
+///
+/// # Example
+///
+/// ```
+///# use matrix::{ColVector, RowVector};
+/// let mut v = ColVector::from([[1], [2], [3]]);
+/// v[2] = 0;
+/// assert_eq!(v[1], 2);
+/// assert_eq!(v, ColVector::from([[1], [2], [0]]));
+/// let row = RowVector::from([[4, 5, 6]]);
+/// assert_eq!(row[(0, 1)], 5);
+/// ```
+///
+/// Other matrices need both indices.
+/// ```compile_fail
+///# use matrix::Matrix;
+/// let m = Matrix::from([[1, 2], [3, 4]]);
+/// let _ = m[0];
+/// ```
+impl<C, const ROWS: usize> Index<usize> for Matrix<C, ROWS, 1> {
+    type Output = C;
+    fn index(&self, index: usize) -> &C {
+        &self.data[index][0]
+    }
+}
+
+///Mutable access to a coefficient of a column vector with a single index, see the `Index<usize>` implementation.
+impl<C, const ROWS: usize> IndexMut<usize> for Matrix<C, ROWS, 1> {
+    fn index_mut(&mut self, index: usize) -> &mut C {
+        &mut self.data[index][0]
+    }
+}
@@ -0,0 +1,395 @@
+//! Borrowed views over a rectangular region of a matrix.
+
+use crate::blocks::block_fits;
+use crate::{norms, Error, Matrix};
+use core::iter::Sum;
+use core::ops::{AddAssign, Index, IndexMut, Mul};
+use num::traits::Float;
+
+///Read-only view over `ROWS` rows and `COLS` columns of a matrix, without copying them.
+///
+///The coefficients of a row are contiguous, and consecutive rows start `stride` coefficients apart.
+///Views are obtained with [`Matrix::view`] and can be multiplied, added into matrices and measured with norms.
+///
+/// # Example
+///
+/// ```
+///# use matrix::Matrix;
+/// let mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+/// let block = mat.view::<2, 2>(1, 1).unwrap();
+/// assert_eq!(block[(0, 1)], 6);
+/// assert_eq!(block.to_matrix(), Matrix::from([[5, 6], [8, 9]]));
+/// ```
+///
+/// [`Matrix::view`]: struct.Matrix.html#method.view
+#[derive(Debug)]
+pub struct MatrixView<'a, C, const ROWS: usize, const COLS: usize> {
+    data: &'a [C],
+    stride: usize,
+}
+
+///Mutable view over `ROWS` rows and `COLS` columns of a matrix, see [`MatrixView`].
+///
+///Views are obtained with [`Matrix::view_mut`], and matrices or other views can be added into them.
+///
+/// # Example
+///
+/// ```
+///# use matrix::Matrix;
+/// let mut mat = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+/// let mut block = mat.view_mut::<2, 1>(0, 2).unwrap();
+/// block[(1, 0)] = 0;
+/// block += Matrix::from([[1], [1]]).as_view();
+/// assert_eq!(mat, Matrix::from([[1, 2, 4], [4, 5, 1]]));
+/// ```
+///
+/// [`MatrixView`]: struct.MatrixView.html
+/// [`Matrix::view_mut`]: struct.Matrix.html#method.view_mut
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, C, const ROWS: usize, const COLS: usize> {
+    data: &'a mut [C],
+    stride: usize,
+}
+
+//Returns how many coefficients a view spans in its buffer, or `None` if that overflows `usize`.
+fn span(rows: usize, cols: usize, stride: usize) -> Option<usize> {
+    if rows == 0 || cols == 0 {
+        Some(0)
+    } else {
+        (rows - 1).checked_mul(stride)?.checked_add(cols)
+    }
+}
+
+impl<C, const ROWS: usize, const COLS: usize> Matrix<C, ROWS, COLS> {
+    ///Returns a view over the `R2` by `C2` block whose top left coefficient is at `(start_row, start_col)`.
+    ///
+    ///Returns OutOfBounds if the block doesn't fit in the matrix.
+    ///Unlike [`submatrix`], the coefficients aren't copied.
+    ///
+    /// [`submatrix`]: #method.submatrix
+    pub fn view<const R2: usize, const C2: usize>(
+        &self,
+        start_row: usize,
+        start_col: usize,
+    ) -> Result<MatrixView<'_, C, R2, C2>, Error> {
+        if !block_fits(start_row, R2, ROWS) || !block_fits(start_col, C2, COLS) {
+            return Err(Error::OutOfBounds);
+        }
+        let start = start_row * COLS + start_col;
+        let len = span(R2, C2, COLS).expect("the block fits in the matrix");
+        Ok(MatrixView {
+            data: if len == 0 {
+                &[]
+            } else {
+                &self.as_slice()[start..start + len]
+            },
+            stride: COLS,
+        })
+    }
+
+    ///Returns a mutable view over the `R2` by `C2` block whose top left coefficient is at `(start_row, start_col)`.
+    ///
+    ///Returns OutOfBounds if the block doesn't fit in the matrix.
+    pub fn view_mut<const R2: usize, const C2: usize>(
+        &mut self,
+        start_row: usize,
+        start_col: usize,
+    ) -> Result<MatrixViewMut<'_, C, R2, C2>, Error> {
+        if !block_fits(start_row, R2, ROWS) || !block_fits(start_col, C2, COLS) {
+            return Err(Error::OutOfBounds);
+        }
+        let start = start_row * COLS + start_col;
+        let len = span(R2, C2, COLS).expect("the block fits in the matrix");
+        Ok(MatrixViewMut {
+            data: if len == 0 {
+                &mut []
+            } else {
+                &mut self.as_mut_slice()[start..start + len]
+            },
+            stride: COLS,
+        })
+    }
+
+    ///Returns a view over the whole matrix.
+    pub fn as_view(&self) -> MatrixView<'_, C, ROWS, COLS> {
+        MatrixView {
+            data: self.as_slice(),
+            stride: COLS,
+        }
+    }
+
+    ///Returns a mutable view over the whole matrix.
+    pub fn as_view_mut(&mut self) -> MatrixViewMut<'_, C, ROWS, COLS> {
+        MatrixViewMut {
+            data: self.as_mut_slice(),
+            stride: COLS,
+        }
+    }
+}
+
+impl<'a, C, const ROWS: usize, const COLS: usize> MatrixView<'a, C, ROWS, COLS> {
+    ///Returns a view over a buffer whose rows start `stride` coefficients apart, such as an image with padded rows.
+    ///
+    ///Returns WrongLength if the buffer is too short to hold `ROWS` rows.
+    ///A `stride` smaller than `COLS` is allowed, in which case consecutive rows overlap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{Matrix, MatrixView};
+    /// //a 2×2 image whose rows are padded to 3 pixels
+    /// let pixels = [1, 2, 0, 3, 4, 0];
+    /// let image = MatrixView::<_, 2, 2>::from_slice(&pixels, 3).unwrap();
+    /// assert_eq!(image.to_matrix(), Matrix::from([[1, 2], [3, 4]]));
+    /// ```
+    pub fn from_slice(data: &'a [C], stride: usize) -> Result<Self, Error> {
+        let len = match span(ROWS, COLS, stride) {
+            Some(len) if len <= data.len() => len,
+            _ => return Err(Error::WrongLength),
+        };
+        Ok(MatrixView {
+            data: &data[..len],
+            stride,
+        })
+    }
+
+    ///Returns a reference to the coefficient at `(row, col)` or `None` if either is out of bounds.
+    pub fn get(&self, row: usize, col: usize) -> Option<&'a C> {
+        if row < ROWS && col < COLS {
+            Some(&self.data[row * self.stride + col])
+        } else {
+            None
+        }
+    }
+
+    ///Returns a row of the view or `None` if it is out of bounds.
+    pub fn get_line(&self, row: usize) -> Option<&'a [C]> {
+        if row < ROWS {
+            let start = row * self.stride;
+            Some(&self.data[start..start + COLS])
+        } else {
+            None
+        }
+    }
+
+    ///Returns an iterator of the rows of the view.
+    pub fn get_lines(&self) -> impl Iterator<Item = &'a [C]> {
+        let view = *self;
+        (0..ROWS).map(move |row| &view.data[row * view.stride..row * view.stride + COLS])
+    }
+
+    ///Returns an iterator of all coefficients of the view, row after row.
+    pub fn iter(&self) -> impl Iterator<Item = &'a C> {
+        self.get_lines().flatten()
+    }
+
+    ///Returns a matrix holding a copy of the coefficients of the view.
+    pub fn to_matrix(&self) -> Matrix<C, ROWS, COLS>
+    where
+        C: Clone,
+    {
+        Matrix::from_fn(|row, col| self[(row, col)].clone())
+    }
+}
+
+///Norms of views, see the ones of [`Matrix`].
+///
+/// [`Matrix`]: struct.Matrix.html
+impl<'a, C, const ROWS: usize, const COLS: usize> MatrixView<'a, C, ROWS, COLS>
+where
+    C: Float,
+{
+    ///Returns the Frobenius norm of the view.
+    pub fn norm_frobenius(&self) -> C {
+        self.iter().fold(C::zero(), |acc, c| acc + *c * *c).sqrt()
+    }
+
+    ///Returns the maximum absolute column sum of the view.
+    pub fn norm_one(&self) -> C {
+        (0..COLS)
+            .map(|col| (0..ROWS).fold(C::zero(), |acc, row| acc + self[(row, col)].abs()))
+            .fold(C::zero(), norms::max)
+    }
+
+    ///Returns the maximum absolute row sum of the view.
+    pub fn norm_inf(&self) -> C {
+        self.get_lines()
+            .map(|line| line.iter().fold(C::zero(), |acc, c| acc + c.abs()))
+            .fold(C::zero(), norms::max)
+    }
+
+    ///Returns the largest absolute value of the coefficients of the view.
+    pub fn norm_max(&self) -> C {
+        self.iter()
+            .fold(C::zero(), |acc, c| norms::max(acc, c.abs()))
+    }
+}
+
+impl<'a, C, const ROWS: usize, const COLS: usize> MatrixViewMut<'a, C, ROWS, COLS> {
+    ///Returns a mutable view over a buffer whose rows start `stride` coefficients apart, see [`MatrixView::from_slice`].
+    ///
+    ///Returns WrongLength if the buffer is too short to hold `ROWS` rows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::{Matrix, MatrixViewMut};
+    /// //interleaved samples of two channels, only the first one is viewed
+    /// let mut samples = [1, 10, 2, 20, 3, 30];
+    /// let mut left = MatrixViewMut::<_, 3, 1>::from_slice_mut(&mut samples, 2).unwrap();
+    /// left += Matrix::from([[1], [1], [1]]).as_view();
+    /// assert_eq!(samples, [2, 10, 3, 20, 4, 30]);
+    /// ```
+    ///
+    /// [`MatrixView::from_slice`]: struct.MatrixView.html#method.from_slice
+    pub fn from_slice_mut(data: &'a mut [C], stride: usize) -> Result<Self, Error> {
+        let len = match span(ROWS, COLS, stride) {
+            Some(len) if len <= data.len() => len,
+            _ => return Err(Error::WrongLength),
+        };
+        Ok(MatrixViewMut {
+            data: &mut data[..len],
+            stride,
+        })
+    }
+
+    ///Returns a read-only view over the same coefficients.
+    pub fn as_view(&self) -> MatrixView<'_, C, ROWS, COLS> {
+        MatrixView {
+            data: self.data,
+            stride: self.stride,
+        }
+    }
+
+    ///Returns a reference to the coefficient at `(row, col)` or `None` if either is out of bounds.
+    pub fn get(&self, row: usize, col: usize) -> Option<&C> {
+        self.as_view().get(row, col)
+    }
+
+    ///Returns a mutable reference to the coefficient at `(row, col)` or `None` if either is out of bounds.
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut C> {
+        if row < ROWS && col < COLS {
+            Some(&mut self.data[row * self.stride + col])
+        } else {
+            None
+        }
+    }
+
+    ///Returns a mutable row of the view or `None` if it is out of bounds.
+    pub fn get_mut_line(&mut self, row: usize) -> Option<&mut [C]> {
+        if row < ROWS {
+            let start = row * self.stride;
+            Some(&mut self.data[start..start + COLS])
+        } else {
+            None
+        }
+    }
+
+    ///Overwrites the coefficients of the view with the ones of `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use matrix::Matrix;
+    /// let mut mat = Matrix::<i32, 3, 3>::nil();
+    /// mat.view_mut::<2, 2>(1, 1).unwrap().copy_from(Matrix::identity().as_view());
+    /// assert_eq!(mat, Matrix::from([[0, 0, 0], [0, 1, 0], [0, 0, 1]]));
+    /// ```
+    pub fn copy_from(&mut self, other: MatrixView<'_, C, ROWS, COLS>)
+    where
+        C: Clone,
+    {
+        for (row, line) in other.get_lines().enumerate() {
+            let start = row * self.stride;
+            self.data[start..start + COLS].clone_from_slice(line);
+        }
+    }
+}
+
+impl<'a, C, const ROWS: usize, const COLS: usize> Clone for MatrixView<'a, C, ROWS, COLS> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, C, const ROWS: usize, const COLS: usize> Copy for MatrixView<'a, C, ROWS, COLS> {}
+
+impl<'a, C, const ROWS: usize, const COLS: usize> Index<(usize, usize)>
+    for MatrixView<'a, C, ROWS, COLS>
+{
+    type Output = C;
+    fn index(&self, (row, col): (usize, usize)) -> &C {
+        self.get(row, col).expect("index out of bounds")
+    }
+}
+
+impl<'a, C, const ROWS: usize, const COLS: usize> Index<(usize, usize)>
+    for MatrixViewMut<'a, C, ROWS, COLS>
+{
+    type Output = C;
+    fn index(&self, (row, col): (usize, usize)) -> &C {
+        self.get(row, col).expect("index out of bounds")
+    }
+}
+
+impl<'a, C, const ROWS: usize, const COLS: usize> IndexMut<(usize, usize)>
+    for MatrixViewMut<'a, C, ROWS, COLS>
+{
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut C {
+        self.get_mut(row, col).expect("index out of bounds")
+    }
+}
+
+///Matrix product of views.
+///
+/// # Example
+///
+/// ```
+///# use matrix::Matrix;
+/// let mat = Matrix::from([[1, 2, 0], [3, 4, 0], [0, 0, 1]]);
+/// let block = mat.view::<2, 2>(0, 0).unwrap();
+/// assert_eq!(block * block, Matrix::from([[7, 10], [15, 22]]));
+/// ```
+impl<'a, 'b, C, const ROWS: usize, const COLS: usize, const Q: usize>
+    Mul<MatrixView<'b, C, Q, COLS>> for MatrixView<'a, C, ROWS, Q>
+where
+    C: Mul<C, Output = C> + Sum + Clone,
+{
+    type Output = Matrix<C, ROWS, COLS>;
+    fn mul(self, other: MatrixView<'b, C, Q, COLS>) -> Self::Output {
+        Matrix::from_fn(|row, col| {
+            (0..Q)
+                .map(|k| self[(row, k)].clone() * other[(k, col)].clone())
+                .sum()
+        })
+    }
+}
+
+///Adds the coefficients of a view into the matrix.
+impl<'a, C, const ROWS: usize, const COLS: usize> AddAssign<MatrixView<'a, C, ROWS, COLS>>
+    for Matrix<C, ROWS, COLS>
+where
+    C: AddAssign + Clone,
+{
+    fn add_assign(&mut self, other: MatrixView<'a, C, ROWS, COLS>) {
+        let mut view = self.as_view_mut();
+        view += other;
+    }
+}
+
+///Adds the coefficients of a view into the ones of a mutable view, typically a block of a larger matrix.
+impl<'a, 'b, C, const ROWS: usize, const COLS: usize> AddAssign<MatrixView<'b, C, ROWS, COLS>>
+    for MatrixViewMut<'a, C, ROWS, COLS>
+where
+    C: AddAssign + Clone,
+{
+    fn add_assign(&mut self, other: MatrixView<'b, C, ROWS, COLS>) {
+        for (row, line) in other.get_lines().enumerate() {
+            let start = row * self.stride;
+            self.data[start..start + COLS]
+                .iter_mut()
+                .zip(line.iter())
+                .for_each(|(c, o)| *c += o.clone());
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use matrix::{Error, FieldLinearAlgebra, Matrix, Mod};
+use num::rational::Ratio;
+
+#[test]
+fn adjugate_identity() {
+    // A * adj(A) = adj(A) * A = det(A) * I, exactly for integers
+    let a = Matrix::from([[2, -1, 0, 3], [1, 4, 2, -2], [0, 3, -5, 1], [7, 0, 1, 1]]);
+    let det = a.clone().map(f64::from).determinant().round() as i64;
+    let a = a.map(i64::from);
+    let expected = Matrix::<i64, 4, 4>::identity() * det;
+    assert_eq!(&a * &a.adjugate(), expected);
+    assert_eq!(&a.adjugate() * &a, expected);
+}
+
+#[test]
+fn singular() {
+    // the adjugate of a singular matrix isn't nil
+    let a = Matrix::from([[1, 2], [2, 4]]);
+    assert_eq!(a.adjugate(), Matrix::from([[4, -2], [-2, 1]]));
+    assert_eq!(&a * &a.adjugate(), Matrix::nil());
+    assert_eq!(
+        a.cramer_solve(Matrix::from([[1], [2]])),
+        Err(Error::Singular)
+    );
+
+    // a zero on the diagonal needs a row swap
+    let b = Matrix::from([[0, 1, 2], [1, 0, 3], [4, -3, 8]]);
+    assert_eq!(&b * &b.adjugate(), Matrix::identity() * -2);
+}
+
+#[test]
+fn cofactors() {
+    let a = Matrix::from([[3, 1], [-2, 5]]);
+    assert_eq!(a.cofactor_matrix(), Matrix::from([[5, 2], [-1, 3]]));
+    assert_eq!(a.cofactor_matrix().transpose(), a.adjugate());
+    assert_eq!(Matrix::from([[7]]).cofactor_matrix(), Matrix::from([[1]]));
+}
+
+#[test]
+fn cramer() {
+    let r = |n: i64| Ratio::from_integer(n);
+    let a = Matrix::from([[2, 1, -1], [-3, -1, 2], [-2, 1, 2]]).map(r);
+    let b = Matrix::from([[8], [-11], [-3]]).map(r);
+    assert_eq!(
+        a.cramer_solve(b.clone()),
+        Ok(Matrix::from([[2], [3], [-1]]).map(r))
+    );
+    assert_eq!(a.cramer_solve(b.clone()), a.solve(b));
+
+    // fractional solution
+    let a = Matrix::from([[2, 1], [1, 3]]).map(r);
+    let x = a.cramer_solve(Matrix::from([[1], [0]]).map(r)).unwrap();
+    assert_eq!(x, Matrix::from([[Ratio::new(3, 5)], [Ratio::new(-1, 5)]]));
+}
+
+#[test]
+fn modular() {
+    let a = Matrix::from([[2, 3], [1, 4]]).map(Mod::<7>::new);
+    assert_eq!(a.adjugate(), a.inverse().unwrap() * a.determinant());
+}
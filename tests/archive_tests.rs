@@ -0,0 +1,40 @@
+//! Tests for the items gated behind the `rkyv` feature
+#![cfg(feature = "rkyv")]
+
+use matrix::Matrix;
+use rkyv::Deserialize;
+
+#[test]
+fn archive_round_trip() {
+    let mat = Matrix::<i64, 3, 4>::from_fn(|row, col| row as i64 * 10 - col as i64);
+    let bytes = rkyv::to_bytes::<_, 256>(&mat).unwrap();
+    let archived = rkyv::check_archived_root::<Matrix<i64, 3, 4>>(&bytes).unwrap();
+    assert_eq!(*archived, mat);
+    assert_eq!(archived[(2, 3)], 17);
+    assert_eq!(archived.as_slice(), mat.as_slice());
+    let deserialized: Matrix<i64, 3, 4> = archived.deserialize(&mut rkyv::Infallible).unwrap();
+    assert_eq!(deserialized, mat);
+}
+
+#[test]
+fn archive_many() {
+    // snapshots of many small matrices stored one after the other
+    let matrices: Vec<Matrix<f32, 2, 2>> = (0..100)
+        .map(|i| Matrix::from_fn(|row, col| (i * 4 + row * 2 + col) as f32))
+        .collect();
+    let bytes = rkyv::to_bytes::<_, 1024>(&matrices).unwrap();
+    let archived = rkyv::check_archived_root::<Vec<Matrix<f32, 2, 2>>>(&bytes).unwrap();
+    assert_eq!(archived.len(), 100);
+    assert_eq!(archived[42].get(1, 1), Some(&171.0));
+    assert!(archived.iter().zip(&matrices).all(|(a, m)| a == m));
+}
+
+#[test]
+fn archive_invalid() {
+    let bytes = rkyv::to_bytes::<_, 256>(&Matrix::from([[true, false]])).unwrap();
+    let mut corrupted = bytes.to_vec();
+    corrupted[0] = 2;
+    let mut aligned = rkyv::AlignedVec::new();
+    aligned.extend_from_slice(&corrupted);
+    assert!(rkyv::check_archived_root::<Matrix<bool, 1, 2>>(&aligned).is_err());
+}
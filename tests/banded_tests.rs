@@ -0,0 +1,78 @@
+use matrix::{BandMatrix, Error, Matrix, Tridiagonal};
+use num::rational::Ratio;
+
+#[test]
+fn storage() {
+    let a = BandMatrix::<i32, 5, 2, 1>::from_fn(|row, col| (10 * row + col) as i32);
+    let dense = a.to_matrix();
+    for row in 0..5 {
+        for col in 0..5 {
+            let expected = if col + 2 >= row && col <= row + 1 {
+                (10 * row + col) as i32
+            } else {
+                0
+            };
+            assert_eq!(dense[(row, col)], expected);
+        }
+    }
+    assert_eq!(a.get(3, 1), Some(&31));
+    assert_eq!(a.get(3, 0), None);
+    assert_eq!(a.get(1, 3), None);
+    assert_eq!(a.get(5, 5), None);
+
+    // the coefficients outside of the matrix are ignored
+    let t = Tridiagonal::new([[9, 1, 2]], [3, 4, 5], [[6, 7, 9]]);
+    assert_eq!(
+        t.to_matrix(),
+        Matrix::from([[3, 6, 0], [1, 4, 7], [0, 2, 5]])
+    );
+    assert_eq!(t, BandMatrix::from_fn(|row, col| t.to_matrix()[(row, col)]));
+
+    let mut t = t;
+    t[(2, 1)] = -2;
+    *t.get_mut(0, 0).unwrap() = 0;
+    assert_eq!(
+        t.to_matrix(),
+        Matrix::from([[0, 6, 0], [1, 4, 7], [0, -2, 5]])
+    );
+    assert!(t.get_mut(2, 0).is_none());
+}
+
+#[test]
+#[should_panic]
+fn index_outside_band() {
+    let t = Tridiagonal::<f64, 3>::from_fn(|_, _| 1.0);
+    let _ = t[(0, 2)];
+}
+
+#[test]
+fn product() {
+    let a = BandMatrix::<i64, 6, 1, 2>::from_fn(|row, col| (row * 3 + col * 7) as i64 % 5 - 2);
+    let b = Matrix::<i64, 6, 3>::from_fn(|row, col| (row + 2 * col) as i64);
+    assert_eq!(&a * &b, &a.to_matrix() * &b);
+}
+
+#[test]
+fn thomas() {
+    // heat equation, implicit step of a diagonally dominant system
+    let a = Tridiagonal::<f64, 8>::from_fn(|row, col| if row == col { 3.0 } else { -1.0 });
+    let x = Matrix::<f64, 8, 1>::from_fn(|row, _| (row as f64).sin());
+    let solution = a.solve(&a * &x).unwrap();
+    assert!(solution.approx_eq(&x, 1e-12));
+    assert!(solution.approx_eq(&a.to_matrix().solve(&a * &x).unwrap(), 1e-12));
+}
+
+#[test]
+fn exact() {
+    let r = |n: i64| Ratio::from_integer(n);
+    let a =
+        BandMatrix::<Ratio<i64>, 5, 2, 1>::from_fn(|row, col| r((row + 2 * col) as i64 % 4 + 1));
+    let x = Matrix::<Ratio<i64>, 5, 1>::from_fn(|row, _| Ratio::new(row as i64, 3));
+    assert_eq!(a.solve(&a * &x), Ok(x));
+}
+
+#[test]
+fn singular() {
+    let t = Tridiagonal::new([[0.0, 1.0]], [1.0, 1.0], [[1.0, 0.0]]);
+    assert_eq!(t.solve(Matrix::from([[1.0], [2.0]])), Err(Error::Singular));
+}
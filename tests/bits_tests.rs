@@ -0,0 +1,108 @@
+#![cfg(feature = "alloc")]
+
+use matrix::{BitMatrix, Error, Matrix};
+
+// pseudo-random bits, spread over several words per row
+fn bits<const ROWS: usize, const COLS: usize>(seed: usize) -> BitMatrix<ROWS, COLS> {
+    BitMatrix::from_fn(|row, col| (row * 31 + col * 17 + seed).wrapping_mul(2654435761) % 7 < 3)
+}
+
+#[test]
+fn get_set() {
+    let mut m = BitMatrix::<3, 100>::new();
+    assert_eq!(m.get(2, 99), Some(false));
+    m.set(2, 99, true);
+    m.set(0, 64, true);
+    m.set(0, 64, true);
+    assert!(m[(2, 99)] && m[(0, 64)]);
+    assert_eq!(m.count_ones(), 2);
+    m.flip(2, 99);
+    assert_eq!(m.count_ones(), 1);
+    assert_eq!(m.get(3, 0), None);
+    assert_eq!(m.get(0, 100), None);
+}
+
+#[test]
+#[should_panic]
+fn flip_out_of_bounds() {
+    BitMatrix::<2, 2>::new().flip(0, 2);
+}
+
+#[test]
+fn conversions() {
+    let mat = Matrix::<bool, 4, 5>::from_fn(|row, col| (row * col) % 3 == 1);
+    let packed = BitMatrix::from(&mat);
+    assert_eq!(packed.to_matrix(), mat);
+    assert_eq!(packed.transpose().to_matrix(), mat.transpose());
+}
+
+#[test]
+fn add() {
+    let a = bits::<5, 130>(1);
+    let b = bits::<5, 130>(2);
+    let expected = a.to_matrix().zip_map(&b.to_matrix(), |x, y| x ^ y);
+    assert_eq!((&a + &b).to_matrix(), expected);
+    let mut c = a.clone();
+    c += &a;
+    assert_eq!(c, BitMatrix::new());
+}
+
+#[test]
+fn mul() {
+    let a = bits::<6, 70>(3);
+    let b = bits::<70, 9>(4);
+    let to_u8 = |m: Matrix<bool, 6, 9>| m.map(u8::from);
+    let expected = &a.to_matrix().map(u8::from) * &b.to_matrix().map(u8::from);
+    assert_eq!(to_u8((&a * &b).to_matrix()), expected.map(|c| c % 2));
+}
+
+#[test]
+fn rank() {
+    let m = bits::<8, 80>(5);
+    // appending the sum of two rows doesn't change the rank
+    let extended = BitMatrix::<9, 80>::from_fn(|row, col| {
+        if row < 8 {
+            m[(row, col)]
+        } else {
+            m[(1, col)] ^ m[(6, col)]
+        }
+    });
+    assert_eq!(extended.rank(), m.rank());
+    assert_eq!(BitMatrix::<5, 5>::identity().rank(), 5);
+    assert_eq!(BitMatrix::<5, 5>::new().rank(), 0);
+}
+
+#[test]
+fn rref() {
+    let m = bits::<6, 10>(6);
+    let reduced = m.rref();
+    let rank = m.rank();
+    // the pivots are the leading ones of the first `rank` rows and the other rows are empty
+    let mut last_pivot = None;
+    for row in 0..6 {
+        let pivot = (0..10).find(|&col| reduced[(row, col)]);
+        assert_eq!(pivot.is_some(), row < rank);
+        if let Some(pivot) = pivot {
+            assert!(last_pivot.map_or(true, |last| pivot > last));
+            assert_eq!((0..6).filter(|&r| reduced[(r, pivot)]).count(), 1);
+            last_pivot = Some(pivot);
+        }
+    }
+}
+
+#[test]
+fn inverse_solve() {
+    // upper triangular with ones on the diagonal, always invertible
+    let m =
+        BitMatrix::<70, 70>::from_fn(|row, col| row == col || (row < col && (row * col) % 5 == 1));
+    let inverse = m.inverse().unwrap();
+    assert_eq!(&m * &inverse, BitMatrix::identity());
+    assert_eq!(&inverse * &m, BitMatrix::identity());
+
+    let b = BitMatrix::<70, 1>::from_fn(|row, _| row % 3 == 0);
+    let x = m.solve(&b).unwrap();
+    assert_eq!(&m * &x, b);
+
+    let singular = BitMatrix::<3, 3>::from_fn(|row, _| row < 2);
+    assert_eq!(singular.inverse(), Err(Error::Singular));
+}
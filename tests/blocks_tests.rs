@@ -0,0 +1,88 @@
+use matrix::{Error, Matrix};
+
+#[test]
+fn from_blocks() {
+    let a = Matrix::from([[1, 2, 3]]);
+    let b = Matrix::from([[4]]);
+    let c = Matrix::from([[5, 6, 7], [8, 9, 10]]);
+    let d = Matrix::from([[11], [12]]);
+    let m: Matrix<_, 3, 4> = Matrix::from_blocks(&a, &b, &c, &d);
+    assert_eq!(
+        m,
+        Matrix::from([[1, 2, 3, 4], [5, 6, 7, 11], [8, 9, 10, 12]])
+    );
+}
+
+#[test]
+fn from_blocks_empty() {
+    // blocks may have no rows or no columns
+    let a = Matrix::from([[1, 2], [3, 4]]);
+    let m: Matrix<_, 2, 2> = Matrix::from_blocks(
+        &a,
+        &Matrix::<i32, 2, 0>::from([[], []]),
+        &Matrix::<i32, 0, 2>::from([]),
+        &Matrix::<i32, 0, 0>::from([]),
+    );
+    assert_eq!(m, a);
+}
+
+#[test]
+fn submatrix() {
+    let m = Matrix::from([[1, 2, 3, 4], [5, 6, 7, 11], [8, 9, 10, 12]]);
+    assert_eq!(m.submatrix::<3, 4>(0, 0), Ok(m.clone()));
+    assert_eq!(m.submatrix::<2, 1>(1, 3), Ok([[11], [12]].into()));
+    assert_eq!(m.submatrix::<1, 2>(2, 1), Ok([[9, 10]].into()));
+    assert_eq!(m.submatrix::<0, 0>(3, 4), Ok(Matrix::from([])));
+    assert_eq!(m.submatrix::<2, 1>(2, 0), Err(Error::OutOfBounds));
+    assert_eq!(m.submatrix::<1, 1>(0, 4), Err(Error::OutOfBounds));
+    assert_eq!(m.submatrix::<1, 1>(usize::MAX, 0), Err(Error::OutOfBounds));
+    assert_eq!(m.submatrix::<1, 1>(0, usize::MAX), Err(Error::OutOfBounds));
+    // the blocks of a matrix put back together give the matrix
+    let top_left = m.submatrix::<1, 3>(0, 0).unwrap();
+    let top_right = m.submatrix::<1, 1>(0, 3).unwrap();
+    let bottom_left = m.submatrix::<2, 3>(1, 0).unwrap();
+    let bottom_right = m.submatrix::<2, 1>(1, 3).unwrap();
+    assert_eq!(
+        Matrix::from_blocks(&top_left, &top_right, &bottom_left, &bottom_right),
+        m
+    );
+}
+
+#[test]
+fn reshape() {
+    let m = Matrix::from([[1, 2, 3, 4], [5, 6, 7, 8]]);
+    assert_eq!(
+        m.clone().reshape::<4, 2>(),
+        [[1, 2], [3, 4], [5, 6], [7, 8]].into()
+    );
+    assert_eq!(m.clone().reshape::<8, 1>().reshape::<2, 4>(), m.clone());
+    assert_eq!(m.clone().reshape::<2, 4>(), m);
+    assert_eq!(
+        Matrix::<u8, 0, 3>::from([]).reshape::<3, 0>(),
+        [[], [], []].into()
+    );
+}
+
+#[test]
+fn minor() {
+    let mat = Matrix::from([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]);
+    assert_eq!(mat.minor(0, 0), Ok(Matrix::from([[6, 7, 8], [10, 11, 12]])));
+    assert_eq!(mat.minor(2, 3), Ok(Matrix::from([[1, 2, 3], [5, 6, 7]])));
+    assert_eq!(mat.minor(1, 2), Ok(Matrix::from([[1, 2, 4], [9, 10, 12]])));
+    assert_eq!(mat.minor::<2, 3>(3, 0), Err(Error::OutOfBounds));
+    assert_eq!(mat.minor::<2, 3>(0, 4), Err(Error::OutOfBounds));
+}
+
+#[test]
+fn minor_cofactors() {
+    // the cofactors are the signed determinants of the minors
+    let mat = Matrix::from([[2.0, -1.0, 0.0], [1.0, 3.0, 2.0], [4.0, 0.0, 5.0]]);
+    let cofactors = mat.cofactor_matrix();
+    for row in 0..3 {
+        for col in 0..3 {
+            let minor: Matrix<f64, 2, 2> = mat.minor(row, col).unwrap();
+            let sign = if (row + col) % 2 == 0 { 1.0 } else { -1.0 };
+            assert!((cofactors[(row, col)] - sign * minor.determinant()).abs() < 1e-12);
+        }
+    }
+}
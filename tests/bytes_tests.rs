@@ -0,0 +1,41 @@
+//! Tests for the items gated behind the `bytemuck` feature
+#![cfg(feature = "bytemuck")]
+
+use matrix::{Error, Matrix};
+
+#[test]
+fn zeroed() {
+    let mat: Matrix<f64, 3, 4> = bytemuck::Zeroable::zeroed();
+    assert_eq!(mat, Matrix::from([[0.0; 4]; 3]));
+}
+
+#[test]
+fn as_bytes() {
+    let mat = Matrix::from([[1u32, 2], [3, 0xdead_beef]]);
+    assert_eq!(mat.as_bytes().len(), 16);
+    assert_eq!(&mat.as_bytes()[12..], &0xdead_beefu32.to_ne_bytes());
+}
+
+#[test]
+fn as_bytes_mut() {
+    let mut mat = Matrix::from([[0u16; 3]; 2]);
+    mat.as_bytes_mut()[8..10].copy_from_slice(&7u16.to_ne_bytes());
+    assert_eq!(mat, Matrix::from([[0, 0, 0], [0, 7, 0]]));
+}
+
+#[test]
+fn from_bytes() {
+    let mat = Matrix::from([[1.0f64, 2.5], [-3.0, 4.0], [5.0, 6.0]]);
+    // copy at an odd offset so the bytes aren't aligned for `f64`
+    let mut buffer = vec![0u8; 1];
+    buffer.extend_from_slice(mat.as_bytes());
+    assert_eq!(Matrix::from_bytes(&buffer[1..]), Ok(mat));
+    assert_eq!(
+        Matrix::<f64, 3, 2>::from_bytes(&buffer),
+        Err(Error::WrongLength)
+    );
+    assert_eq!(
+        Matrix::<f64, 2, 2>::from_bytes(&buffer[1..]),
+        Err(Error::WrongLength)
+    );
+}
@@ -0,0 +1,21 @@
+use matrix::Matrix;
+
+#[test]
+fn approx_eq() {
+    let m = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
+    let inverse_ish = m.solve([[5.0], [11.0]].into()).unwrap();
+    assert!(inverse_ish.approx_eq(&[[1.0], [2.0]].into(), 1e-12));
+    assert!(!m.approx_eq(&[[1.0, 2.0], [3.0, 4.1]].into(), 0.01));
+    assert!(Matrix::from([[1, 5]]).approx_eq(&[[2, 3]].into(), 2));
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn approx_traits() {
+    use approx::{assert_abs_diff_eq, assert_relative_eq, assert_ulps_eq};
+
+    let m = Matrix::from([[0.1 + 0.2, 1e10]]);
+    assert_abs_diff_eq!(m, Matrix::from([[0.3, 1e10]]));
+    assert_relative_eq!(m, Matrix::from([[0.3, 1e10 + 1e-3]]), max_relative = 1e-12);
+    assert_ulps_eq!(m, Matrix::from([[0.3, 1e10]]));
+}
@@ -0,0 +1,133 @@
+use matrix::{Error, FieldLinearAlgebra, Matrix};
+use num::Complex;
+
+fn c(re: f64, im: f64) -> Complex<f64> {
+    Complex::new(re, im)
+}
+
+fn close<const ROWS: usize, const COLS: usize>(
+    a: &Matrix<Complex<f64>, ROWS, COLS>,
+    b: &Matrix<Complex<f64>, ROWS, COLS>,
+) -> bool {
+    a.iter().zip(b.iter()).all(|(x, y)| (x - y).norm() < 1e-12)
+}
+
+#[test]
+fn arithmetic() {
+    let a = Matrix::from([[c(1.0, 1.0), c(0.0, 2.0)], [c(3.0, 0.0), c(1.0, -1.0)]]);
+    let b = Matrix::from([[c(0.0, 1.0), c(1.0, 0.0)], [c(2.0, 0.0), c(0.0, -1.0)]]);
+    assert_eq!(
+        &a * &b,
+        Matrix::from([[c(-1.0, 5.0), c(3.0, 1.0)], [c(2.0, 1.0), c(2.0, -1.0)]])
+    );
+    assert_eq!(
+        &a + &b,
+        Matrix::from([[c(1.0, 2.0), c(1.0, 2.0)], [c(5.0, 0.0), c(1.0, -2.0)]])
+    );
+    assert_eq!(c(0.0, 1.0) * a.clone(), a.clone() * c(0.0, 1.0));
+    assert_eq!(a.trace(), c(2.0, 0.0));
+    assert_eq!(a.clone().pow(2), &a * &a);
+    let identity: Matrix<Complex<f64>, 2, 2> = Matrix::identity();
+    assert_eq!(&a * &identity, a);
+}
+
+#[test]
+fn conjugate_transpose() {
+    let a = Matrix::from([[c(1.0, 1.0), c(0.0, 2.0), c(3.0, 0.0)]]);
+    assert_eq!(
+        a.conjugate(),
+        Matrix::from([[c(1.0, -1.0), c(0.0, -2.0), c(3.0, 0.0)]])
+    );
+    assert_eq!(a.hermitian_transpose(), a.conjugate().transpose());
+    assert_eq!(a.adjoint(), a.hermitian_transpose());
+    assert_eq!(a.adjoint().adjoint(), a);
+
+    // (AB)ᴴ = BᴴAᴴ
+    let b = Matrix::from([[c(2.0, -1.0)], [c(0.0, 1.0)], [c(1.0, 1.0)]]);
+    assert_eq!((&a * &b).adjoint(), &b.adjoint() * &a.adjoint());
+    // AAᴴ is the squared norm of a row vector
+    assert_eq!(&a * &a.adjoint(), Matrix::from([[c(15.0, 0.0)]]));
+}
+
+#[test]
+fn linear_algebra() {
+    let a = Matrix::from([
+        [c(2.0, 1.0), c(0.0, -1.0), c(1.0, 0.0)],
+        [c(1.0, 0.0), c(3.0, 2.0), c(0.0, 1.0)],
+        [c(0.0, 2.0), c(1.0, 1.0), c(4.0, 0.0)],
+    ]);
+    let inverse = a.inverse().unwrap();
+    let identity = Matrix::identity();
+    assert!(close(&(&a * &inverse), &identity));
+    assert!(close(&(&inverse * &a), &identity));
+
+    let x = Matrix::from([[c(1.0, -1.0)], [c(0.0, 2.0)], [c(3.0, 0.5)]]);
+    let solution = a.solve(&a * &x).unwrap();
+    assert!(close(&solution, &x));
+
+    // the determinant of the inverse is the inverse of the determinant
+    assert!((a.determinant() * inverse.determinant() - c(1.0, 0.0)).norm() < 1e-12);
+}
+
+#[test]
+fn singular() {
+    // the second row is i times the first
+    let a = Matrix::from([[c(1.0, 0.0), c(2.0, 1.0)], [c(0.0, 1.0), c(-1.0, 2.0)]]);
+    assert_eq!(a.determinant(), c(0.0, 0.0));
+    assert_eq!(a.inverse(), Err(Error::Singular));
+    assert_eq!(
+        a.solve(Matrix::from([[c(1.0, 0.0)], [c(0.0, 0.0)]])),
+        Err(Error::Singular)
+    );
+}
+
+#[test]
+fn gaussian_integers() {
+    // exact results with rational parts
+    use num::rational::Ratio;
+    let r = |re: i64, im: i64| Complex::new(Ratio::from_integer(re), Ratio::from_integer(im));
+    let a = Matrix::from([[r(1, 1), r(2, 0)], [r(0, 1), r(1, -1)]]);
+    assert_eq!(a.determinant(), r(2, -2));
+    assert_eq!(&a * &a.inverse().unwrap(), Matrix::identity());
+}
+
+#[test]
+fn hermitian() {
+    let a = Matrix::from([
+        [c(2.0, 0.0), c(1.0, -1.0), c(0.0, 3.0)],
+        [c(1.0, 1.0), c(-1.0, 0.0), c(2.0, 0.0)],
+        [c(0.0, -3.0), c(2.0, 0.0), c(5.0, 0.0)],
+    ]);
+    assert!(a.is_hermitian(&0.0));
+    assert_eq!(a.adjoint(), a);
+
+    // a complex diagonal isn't Hermitian
+    let mut b = a.clone();
+    b[(1, 1)] = c(-1.0, 0.1);
+    assert!(!b.is_hermitian(&1e-3));
+    assert!(b.is_hermitian(&0.2));
+
+    // symmetric isn't Hermitian
+    let symmetric = Matrix::from([[c(1.0, 0.0), c(0.0, 1.0)], [c(0.0, 1.0), c(1.0, 0.0)]]);
+    assert!(!symmetric.is_hermitian(&1e-12));
+}
+
+#[test]
+fn unitary() {
+    let h = std::f64::consts::FRAC_1_SQRT_2;
+    // the square root of NOT gate
+    let sqrt_not = Matrix::from([[c(0.5, 0.5), c(0.5, -0.5)], [c(0.5, -0.5), c(0.5, 0.5)]]);
+    assert!(sqrt_not.is_unitary(&1e-12));
+    assert_eq!(
+        &sqrt_not * &sqrt_not,
+        Matrix::from([[c(0.0, 0.0), c(1.0, 0.0)], [c(1.0, 0.0), c(0.0, 0.0)]])
+    );
+
+    let phase = Matrix::from([[c(1.0, 0.0), c(0.0, 0.0)], [c(0.0, 0.0), c(h, h)]]);
+    assert!(phase.is_unitary(&1e-12));
+    assert!(!phase.is_hermitian(&1e-12));
+    assert!((&phase * &sqrt_not).is_unitary(&1e-12));
+
+    let not_unitary = Matrix::from([[c(1.0, 0.0), c(1.0, 0.0)], [c(0.0, 0.0), c(1.0, 0.0)]]);
+    assert!(!not_unitary.is_unitary(&1e-6));
+}
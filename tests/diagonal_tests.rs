@@ -0,0 +1,43 @@
+use matrix::{Diagonal, Error, Matrix};
+
+#[test]
+fn dense() {
+    let d = Diagonal::new([1.0, -2.0, 0.5]);
+    assert_eq!(d.to_matrix(), Matrix::from_diagonal([1.0, -2.0, 0.5]));
+    assert_eq!(Matrix::from(d.clone()), d.to_matrix());
+    assert_eq!(d.diagonal(), &[1.0, -2.0, 0.5]);
+    assert_eq!(d.to_matrix().diagonal(), d.into_inner());
+    assert_eq!(
+        Diagonal::<i32, 3>::identity().to_matrix(),
+        Matrix::identity()
+    );
+}
+
+#[test]
+fn products() {
+    let d = Diagonal::new([2, -1, 3]);
+    let a = Matrix::<i32, 3, 4>::from_fn(|row, col| (row * 4 + col) as i32);
+    assert_eq!(&d * &a, &d.to_matrix() * &a);
+    let b = a.transpose();
+    assert_eq!(&b * &d, &b * &d.to_matrix());
+
+    let e = Diagonal::new([5, 0, 1]);
+    assert_eq!((&d * &e).to_matrix(), &d.to_matrix() * &e.to_matrix());
+    assert_eq!(&d * &Diagonal::identity(), d);
+}
+
+#[test]
+fn inverse() {
+    let d = Diagonal::new([2.0, -4.0, 0.5]);
+    let inverse = d.inverse().unwrap();
+    assert_eq!(&d * &inverse, Diagonal::identity());
+    assert_eq!(inverse.to_matrix(), d.to_matrix().inverse().unwrap());
+    assert_eq!(Diagonal::new([1.0, 0.0]).inverse(), Err(Error::Singular));
+}
+
+#[test]
+fn determinant() {
+    let d = Diagonal::new([2.0, -4.0, 0.5]);
+    assert_eq!(d.determinant(), -4.0);
+    assert_eq!(d.to_matrix().determinant(), d.determinant());
+}
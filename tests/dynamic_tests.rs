@@ -0,0 +1,114 @@
+#![cfg(feature = "alloc")]
+
+use matrix::{DynMatrix, Error, Matrix};
+use std::convert::TryFrom;
+
+fn setup_2x3() -> DynMatrix<i32> {
+    DynMatrix::from_rows(vec![vec![9, 8, 7], vec![6, 5, 4]]).unwrap()
+}
+
+#[test]
+fn constructors() {
+    assert_eq!(
+        DynMatrix::new(2, 3, vec![9, 8, 7, 6, 5, 4]),
+        Ok(setup_2x3())
+    );
+    assert_eq!(DynMatrix::new(2, 2, vec![1, 2, 3]), Err(Error::WrongLength));
+    assert_eq!(
+        DynMatrix::from_rows(vec![vec![1], vec![2, 3]]),
+        Err(Error::DimensionMismatch)
+    );
+    assert_eq!(
+        DynMatrix::<i32>::new(usize::MAX, 2, vec![]),
+        Err(Error::WrongLength)
+    );
+    assert_eq!(
+        DynMatrix::from_fn(2, 2, |row, col| row * 2 + col),
+        DynMatrix::new(2, 2, vec![0, 1, 2, 3]).unwrap()
+    );
+    assert_eq!(
+        DynMatrix::<i32>::identity(2),
+        DynMatrix::new(2, 2, vec![1, 0, 0, 1]).unwrap()
+    );
+    assert_eq!(DynMatrix::<i32>::nil(1, 3).iter().sum::<i32>(), 0);
+}
+
+#[test]
+fn access() {
+    let mut m = setup_2x3();
+    assert_eq!((m.rows(), m.cols()), (2, 3));
+    assert_eq!(m.get(1, 2), Some(&4));
+    assert_eq!(m.get(2, 0), None);
+    assert_eq!(m.get(0, 3), None);
+    *m.get_mut(0, 0).unwrap() = 0;
+    m[(1, 1)] = 1;
+    assert_eq!(m.get_line(0), Some(&[0, 8, 7][..]));
+    assert_eq!(m[(1, 1)], 1);
+    assert_eq!(
+        m.transpose(),
+        DynMatrix::new(3, 2, vec![0, 6, 8, 1, 7, 4]).unwrap()
+    );
+}
+
+#[test]
+fn conversions() {
+    let m = Matrix::from([[9, 8, 7], [6, 5, 4]]);
+    assert_eq!(DynMatrix::from(m.clone()), setup_2x3());
+    assert_eq!(Matrix::try_from(setup_2x3()), Ok(m));
+    assert_eq!(
+        Matrix::<i32, 3, 2>::try_from(setup_2x3()),
+        Err(Error::DimensionMismatch)
+    );
+}
+
+#[test]
+fn arithmetic() {
+    let m = setup_2x3();
+    assert_eq!(&m + &m, Ok(m.clone() * 2));
+    assert_eq!(&m - &m, Ok(DynMatrix::nil(2, 3)));
+    assert_eq!(-m.clone(), m.clone() * -1);
+    assert_eq!(&m + &m.transpose(), Err(Error::DimensionMismatch));
+    assert_eq!(&m - &m.transpose(), Err(Error::DimensionMismatch));
+    let product = (&m * &m.transpose()).unwrap();
+    let expected = Matrix::from([[9, 8, 7], [6, 5, 4]]) * Matrix::from([[9, 6], [8, 5], [7, 4]]);
+    assert_eq!(product, DynMatrix::from(expected));
+    assert_eq!(&m * &m, Err(Error::DimensionMismatch));
+}
+
+#[test]
+fn elementary_operations() {
+    let mut m = setup_2x3();
+    m.permute(0, 1).unwrap();
+    assert_eq!(m.get_line(0), Some(&[6, 5, 4][..]));
+    m.dilate(1, &2).unwrap();
+    assert_eq!(m.get_line(1), Some(&[18, 16, 14][..]));
+    m.transvect_scaled(1, 0, &-3).unwrap();
+    assert_eq!(m.get_line(1), Some(&[0, 1, 2][..]));
+    m.transvect(0, 1).unwrap();
+    assert_eq!(m.get_line(0), Some(&[6, 6, 6][..]));
+    assert_eq!(m.transvect(0, 2), Err(Error::OutOfBounds));
+    assert_eq!(m.permute(0, 2), Err(Error::OutOfBounds));
+    assert_eq!(m.dilate(2, &2), Err(Error::OutOfBounds));
+    assert_eq!(m.transvect_scaled(1, 1, &2), Err(Error::WrongOperation));
+}
+
+#[test]
+fn solve() {
+    let a = DynMatrix::from_rows(vec![
+        vec![0.0f64, 2.0, 1.0],
+        vec![1.0, 1.0, 0.0],
+        vec![2.0, 0.0, 3.0],
+    ])
+    .unwrap();
+    let b = DynMatrix::new(3, 1, vec![7.0, 3.0, 11.0]).unwrap();
+    let x = a.solve(&b).unwrap();
+    let expected = [1.0f64, 2.0, 3.0];
+    assert!(x
+        .iter()
+        .zip(expected.iter())
+        .all(|(a, b)| (a - b).abs() < 1e-12));
+    assert_eq!(a.solve(&a), Err(Error::DimensionMismatch));
+    let singular = DynMatrix::new(2, 2, vec![1.0, 2.0, 2.0, 4.0]).unwrap();
+    let b = DynMatrix::new(2, 1, vec![1.0, 1.0]).unwrap();
+    assert_eq!(singular.solve(&b), Err(Error::Singular));
+}
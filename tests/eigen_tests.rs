@@ -0,0 +1,52 @@
+use matrix::{Error, Matrix};
+
+#[test]
+fn dominant_eigenpair() {
+    // column stochastic: the dominant eigenvalue is 1 and its eigenvector the stationary distribution
+    let p = Matrix::<f64, 3, 3>::from([[0.9, 0.2, 0.1], [0.05, 0.7, 0.3], [0.05, 0.1, 0.6]]);
+    let (lambda, v) = p.dominant_eigenpair(1000, 1e-12).unwrap();
+    assert!((lambda - 1.0).abs() < 1e-12);
+    let distribution = v.clone() * (1.0 / v.sum());
+    assert!((&p * &distribution).approx_eq(&distribution, 1e-10));
+    assert!(distribution.iter().all(|c| *c > 0.0));
+    assert!((distribution[0] - 1.0 / 3.0).abs() > 0.1);
+
+    // the vector of ones is an eigenvector of a graph laplacian, for the smallest eigenvalue
+    let laplacian =
+        Matrix::<f64, 3, 3>::from([[1.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 1.0]]);
+    let (lambda, v) = laplacian.dominant_eigenpair(1000, 1e-12).unwrap();
+    assert!((lambda - 3.0).abs() < 1e-10);
+    assert!((&laplacian * &v).approx_eq(&(v.clone() * lambda), 1e-10));
+    let (lambda, _) = Matrix::<f64, 2, 2>::from([[1.0, -1.0], [-1.0, 1.0]])
+        .dominant_eigenpair(1000, 1e-12)
+        .unwrap();
+    assert!((lambda - 2.0).abs() < 1e-10);
+
+    // the dominant eigenvalue may be negative
+    let a = Matrix::<f64, 2, 2>::from([[-5.0, 0.0], [1.0, 2.0]]);
+    let (lambda, v) = a.dominant_eigenpair(1000, 1e-12).unwrap();
+    assert!((lambda + 5.0).abs() < 1e-12);
+    assert!((v.norm() - 1.0f64).abs() < 1e-12);
+
+    // a rotation has two complex eigenvalues of the same modulus
+    let rotation = Matrix::from([[0.0, -1.0], [1.0, 0.0]]);
+    assert_eq!(
+        rotation.dominant_eigenpair(100, 1e-12),
+        Err(Error::NoConvergence)
+    );
+    assert_eq!(
+        Matrix::<f64, 0, 0>::from([]).dominant_eigenpair(10, 1e-12),
+        Err(Error::NilVector)
+    );
+}
+
+#[test]
+fn eigenpair_near() {
+    let a = Matrix::<f64, 3, 3>::from([[2.0, 0.0, 0.0], [1.0, 5.0, 0.0], [0.0, 1.0, -3.0]]);
+    for (shift, expected) in [(1.5, 2.0), (4.0, 5.0), (-2.0, -3.0)] {
+        let (lambda, v) = a.eigenpair_near(shift, 100, 1e-12).unwrap();
+        assert!((lambda - expected).abs() < 1e-10);
+        assert!((&a * &v).approx_eq(&(v.clone() * lambda), 1e-10));
+    }
+    assert_eq!(a.eigenpair_near(5.0, 100, 1e-12), Err(Error::Singular));
+}
@@ -0,0 +1,119 @@
+use matrix::Matrix;
+
+fn assert_close<const ROWS: usize, const COLS: usize>(
+    left: &Matrix<f64, ROWS, COLS>,
+    right: &Matrix<f64, ROWS, COLS>,
+) {
+    left.get_lines()
+        .zip(right.get_lines())
+        .flat_map(|(a, b)| a.iter().zip(b.iter()))
+        .for_each(|(a, b)| assert!((a - b).abs() < 1e-10, "{:?} != {:?}", left, right));
+}
+
+#[test]
+fn expm_nil() {
+    let m: Matrix<f64, 3, 3> = Matrix::nil();
+    assert_close(&m.expm(), &Matrix::identity());
+}
+
+#[test]
+fn expm_rotation() {
+    let m = Matrix::from([[0.0, 1.0], [-1.0, 0.0]]);
+    let (sin, cos) = 1f64.sin_cos();
+    assert_close(&m.expm(), &[[cos, sin], [-sin, cos]].into());
+}
+
+#[test]
+fn expm_large_norm() {
+    let m = Matrix::from([[10.0, 1.0], [0.0, 10.0]]);
+    let e = 10f64.exp();
+    let exp = m.expm();
+    assert_close(&(exp * (1.0 / e)), &[[1.0, 1.0], [0.0, 1.0]].into());
+}
+
+#[test]
+fn sqrtm() {
+    let a = Matrix::from([[4.0, 1.0, 0.5], [1.0, 3.0, -1.0], [0.5, -1.0, 2.0]]);
+    let root = a.sqrtm().unwrap();
+    assert_close(&(&root * &root), &a);
+    assert_close(&root, &root.transpose());
+    assert_close(
+        &Matrix::<f64, 3, 3>::identity().sqrtm().unwrap(),
+        &Matrix::identity(),
+    );
+
+    // whitening: the covariance of the transformed data is the identity
+    let whitening = root.inverse().unwrap();
+    assert_close(
+        &(&(&whitening * &a) * &whitening.transpose()),
+        &Matrix::identity(),
+    );
+
+    // semi-definite matrices have a root too
+    let singular = Matrix::from([[1.0, 1.0], [1.0, 1.0]]);
+    let root = singular.sqrtm().unwrap();
+    assert_close(&root, &(singular * 0.5f64.sqrt()));
+
+    let indefinite = Matrix::from([[1.0, 2.0], [2.0, 1.0]]);
+    assert_eq!(indefinite.sqrtm(), Err(matrix::Error::NotPositiveDefinite));
+}
+
+#[test]
+fn logm() {
+    let a = Matrix::from([[4.0, 1.0, 0.0], [0.5, 3.0, 1.0], [0.0, 0.2, 2.0]]);
+    assert_close(&a.logm().unwrap().expm(), &a);
+    assert_close(
+        &Matrix::<f64, 3, 3>::identity().logm().unwrap(),
+        &Matrix::nil(),
+    );
+
+    let diagonal = Matrix::from([[1.0, 0.0], [0.0, 1e6]]);
+    assert_close(
+        &diagonal.logm().unwrap(),
+        &[[0.0, 0.0], [0.0, 1e6f64.ln()]].into(),
+    );
+
+    let m = Matrix::from([[0.1, 0.7], [-0.3, -0.2]]);
+    assert_close(&m.expm().logm().unwrap(), &m);
+
+    assert_eq!(
+        Matrix::from([[1.0, 2.0], [2.0, 4.0]]).logm(),
+        Err(matrix::Error::Singular)
+    );
+    for m in [
+        [[1.0, f64::NAN], [0.0, 1.0]],
+        [[1.0, 0.0], [f64::NAN, 1.0]],
+        [[f64::NAN, 0.0], [0.0, 1.0]],
+        [[f64::NAN; 2]; 2],
+        [[f64::INFINITY, 0.0], [0.0, 1.0]],
+    ] {
+        assert_eq!(Matrix::from(m).logm(), Err(matrix::Error::NoConvergence));
+    }
+    // negative eigenvalues have no real logarithm
+    assert!(Matrix::from([[-1.0, 0.0], [0.0, 1.0]]).logm().is_err());
+    assert!(Matrix::from([[-2.0, 1.0], [0.0, 3.0]]).logm().is_err());
+}
+
+#[test]
+fn expm_f32() {
+    let m = Matrix::from([[0.0f32, 1.0], [-1.0, 0.0]]);
+    let (sin, cos) = 1f32.sin_cos();
+    assert!(m
+        .expm()
+        .approx_eq(&Matrix::from([[cos, sin], [-sin, cos]]), 1e-5));
+    let a = Matrix::from([[2.0f32, 0.5], [0.0, 1.0]]);
+    assert!(a.logm().unwrap().expm().approx_eq(&a, 1e-4));
+}
+
+#[test]
+fn expm_non_finite() {
+    let infinite = Matrix::from([[f64::INFINITY, 0.0], [0.0, 1.0]]);
+    assert!(infinite.expm().iter().all(|c| c.is_nan()));
+    let nan = Matrix::from([[f64::NAN, 0.0], [0.0, 1.0]]);
+    assert!(nan.expm().iter().all(|c| c.is_nan()));
+    // finite but too big to be exponentiated
+    let huge = Matrix::from([[f64::MAX, 0.0], [0.0, 1.0]]);
+    assert!(huge.expm().iter().any(|c| !c.is_finite()));
+    let huge = Matrix::from([[f32::MAX, 0.0], [0.0, 1.0]]);
+    assert!(huge.expm().iter().any(|c| !c.is_finite()));
+}
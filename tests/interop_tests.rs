@@ -0,0 +1,71 @@
+//! Tests for the conversions to and from other crates' types
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra_tests {
+    use matrix::{ColVector, Matrix, RowVector};
+
+    #[test]
+    fn nalgebra_matrix() {
+        let mat = Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let converted = nalgebra::SMatrix::<f64, 2, 3>::from(mat.clone());
+        assert_eq!(
+            converted,
+            nalgebra::Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0)
+        );
+        // nalgebra's storage is column-major
+        assert_eq!(converted.as_slice(), &[1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+        assert_eq!(Matrix::from(converted), mat);
+    }
+
+    #[test]
+    fn nalgebra_vectors() {
+        let col = ColVector::from([[1], [2], [3]]);
+        let converted: nalgebra::SVector<i32, 3> = col.clone().into();
+        assert_eq!(converted, nalgebra::Vector3::new(1, 2, 3));
+        assert_eq!(ColVector::from(converted), col);
+
+        let row = RowVector::from([[4, 5]]);
+        let converted: nalgebra::RowSVector<i32, 2> = row.clone().into();
+        assert_eq!(converted, nalgebra::RowVector2::new(4, 5));
+        assert_eq!(RowVector::from(converted), row);
+    }
+}
+
+#[cfg(feature = "ndarray")]
+mod ndarray_tests {
+    use matrix::{Error, Matrix};
+    use ndarray::{arr2, Array2};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn ndarray_round_trip() {
+        let mat = Matrix::from([[1.5, 2.0], [3.0, 4.0], [5.0, 6.5]]);
+        let array = Array2::from(mat.clone());
+        assert_eq!(array, arr2(&[[1.5, 2.0], [3.0, 4.0], [5.0, 6.5]]));
+        assert!(array.is_standard_layout());
+        assert_eq!(Matrix::try_from(array), Ok(mat));
+    }
+
+    #[test]
+    fn ndarray_dimension_check() {
+        let array = arr2(&[[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(
+            Matrix::<i32, 3, 2>::try_from(array.clone()),
+            Err(Error::DimensionMismatch)
+        );
+        assert_eq!(
+            Matrix::<i32, 2, 2>::try_from(array.clone()),
+            Err(Error::DimensionMismatch)
+        );
+        assert_eq!(
+            Matrix::<i32, 3, 2>::try_from(array.t()),
+            Ok(Matrix::from([[1, 4], [2, 5], [3, 6]]))
+        );
+        // sliced views have strides of their own
+        let column = array.slice(ndarray::s![.., 1..2]);
+        assert_eq!(
+            Matrix::<i32, 2, 1>::try_from(column),
+            Ok(Matrix::from([[2], [5]]))
+        );
+    }
+}
@@ -0,0 +1,142 @@
+#![cfg(feature = "std")]
+
+#[cfg(feature = "csv")]
+mod csv {
+    use matrix::{Error, Matrix};
+
+    #[test]
+    fn from_csv() {
+        let m: Matrix<f64, 2, 2> = Matrix::from_csv("1.5, 2\n-3,4\n".as_bytes()).unwrap();
+        assert_eq!(m, [[1.5, 2.0], [-3.0, 4.0]].into());
+    }
+
+    #[test]
+    fn from_csv_fail_dimensions() {
+        let data = "1,2\n3,4\n5,6\n";
+        assert_eq!(
+            Matrix::<u8, 2, 2>::from_csv(data.as_bytes()),
+            Err(Error::WrongLength)
+        );
+        assert_eq!(
+            Matrix::<u8, 3, 3>::from_csv(data.as_bytes()),
+            Err(Error::WrongLength)
+        );
+        assert_eq!(
+            Matrix::<u8, 4, 2>::from_csv(data.as_bytes()),
+            Err(Error::WrongLength)
+        );
+    }
+
+    #[test]
+    fn from_csv_fail_parse() {
+        let result = Matrix::<u8, 1, 2>::from_csv("1,x\n".as_bytes());
+        assert!(matches!(result, Err(Error::Parse(_))));
+        let result = Matrix::<u8, 1, 2>::from_csv(&b"1,\xff\n"[..]);
+        assert!(matches!(result, Err(Error::Parse(_))));
+    }
+
+    #[test]
+    fn csv_round_trip() {
+        let m = Matrix::from([[9u8, 8, 7], [6, 5, 4]]);
+        let mut data = Vec::new();
+        m.to_csv(&mut data).unwrap();
+        assert_eq!(Matrix::from_csv(&data[..]), Ok(m));
+    }
+}
+
+mod matrix_market {
+    use matrix::{Error, Matrix, MatrixMarketFormat};
+
+    #[test]
+    fn read_coordinate() {
+        let data = "%%MatrixMarket matrix coordinate integer general\n\
+                    %comment\n\
+                    \n\
+                    3 2 3\n\
+                    1 2 7\n\
+                    3 1 -2\n\
+                    2 2 5\n";
+        let m: Matrix<i32, 3, 2> = Matrix::from_matrix_market(data.as_bytes()).unwrap();
+        assert_eq!(m, [[0, 7], [0, 5], [-2, 0]].into());
+    }
+
+    #[test]
+    fn read_coordinate_symmetric() {
+        let data = "%%MatrixMarket matrix coordinate real symmetric\n2 2 2\n1 1 1.5\n2 1 3\n";
+        let m: Matrix<f64, 2, 2> = Matrix::from_matrix_market(data.as_bytes()).unwrap();
+        assert_eq!(m, [[1.5, 3.0], [3.0, 0.0]].into());
+    }
+
+    #[test]
+    fn read_array() {
+        let data = "%%MatrixMarket matrix array real general\n2 3\n1\n4\n2\n5\n3\n6\n";
+        let m: Matrix<f64, 2, 3> = Matrix::from_matrix_market(data.as_bytes()).unwrap();
+        assert_eq!(m, [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]].into());
+    }
+
+    #[test]
+    fn read_array_symmetric() {
+        let data = "%%MatrixMarket matrix array real symmetric\n2 2\n1\n2\n3\n";
+        let m: Matrix<f64, 2, 2> = Matrix::from_matrix_market(data.as_bytes()).unwrap();
+        assert_eq!(m, [[1.0, 2.0], [2.0, 3.0]].into());
+    }
+
+    #[test]
+    fn read_fail() {
+        let wrong_size = "%%MatrixMarket matrix array real general\n2 2\n1\n2\n3\n4\n";
+        assert_eq!(
+            Matrix::<f64, 2, 3>::from_matrix_market(wrong_size.as_bytes()),
+            Err(Error::WrongLength)
+        );
+        let missing = "%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1\n";
+        assert_eq!(
+            Matrix::<f64, 2, 2>::from_matrix_market(missing.as_bytes()),
+            Err(Error::WrongLength)
+        );
+        let outside = "%%MatrixMarket matrix coordinate real general\n2 2 1\n3 1 1\n";
+        assert_eq!(
+            Matrix::<f64, 2, 2>::from_matrix_market(outside.as_bytes()),
+            Err(Error::OutOfBounds)
+        );
+        let symmetric = "%%MatrixMarket matrix array real symmetric\n2 2\n1\n2\n3\n";
+        assert_eq!(
+            Matrix::<f64, 2, 3>::from_matrix_market(symmetric.as_bytes()),
+            Err(Error::WrongLength)
+        );
+        let double = "%%MatrixMarket matrix array double general\n1 1\n1\n";
+        assert!(matches!(
+            Matrix::<f64, 1, 1>::from_matrix_market(double.as_bytes()),
+            Err(Error::Parse(_))
+        ));
+        let header = "%%MatrixMarket matrix coordinate complex general\n1 1 1\n1 1 1 0\n";
+        assert!(matches!(
+            Matrix::<f64, 1, 1>::from_matrix_market(header.as_bytes()),
+            Err(Error::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn round_trip() {
+        let m = Matrix::from([[1.5, 0.0, -2.0], [0.0, 0.0, 4.0]]);
+        for &format in [MatrixMarketFormat::Array, MatrixMarketFormat::Coordinate].iter() {
+            let mut data = Vec::new();
+            m.to_matrix_market(&mut data, format).unwrap();
+            assert_eq!(Matrix::from_matrix_market(&data[..]), Ok(m.clone()));
+        }
+    }
+}
+
+#[test]
+fn to_latex() {
+    use matrix::Matrix;
+
+    let m = Matrix::from([[1.0, -2.5], [0.0, 4.0]]);
+    assert_eq!(
+        m.to_latex(),
+        "\\begin{pmatrix}\n1 & -2.5 \\\\\n0 & 4\n\\end{pmatrix}"
+    );
+    assert_eq!(
+        m.to_latex_with("vmatrix", Some(1)),
+        "\\begin{vmatrix}\n1.0 & -2.5 \\\\\n0.0 & 4.0\n\\end{vmatrix}"
+    );
+}
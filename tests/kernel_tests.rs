@@ -0,0 +1,56 @@
+#![cfg(feature = "alloc")]
+use matrix::Matrix;
+use num::rational::Ratio;
+
+#[test]
+fn exact() {
+    let r = |n: i64| Ratio::from_integer(n);
+    let a = Matrix::from([[1, 2, 0, -1, 3], [0, 0, 1, 2, -1], [1, 2, 1, 1, 2]]).map(r);
+    let basis = a.null_space();
+    assert_eq!(basis.len(), 5 - a.rank());
+    assert_eq!(basis.len(), 3);
+    for v in basis.iter() {
+        assert!((&a * v).iter().all(|c| *c == r(0)));
+    }
+    // the basis is linearly independent
+    let stacked = Matrix::<Ratio<i64>, 3, 5>::from_fn(|row, col| basis[row][(col, 0)]);
+    assert_eq!(stacked.rank(), 3);
+}
+
+#[test]
+fn full_rank() {
+    let a = Matrix::from([[2.0, 1.0], [1.0, 3.0], [0.0, 1.0]]);
+    assert!(a.null_space().is_empty());
+    assert_eq!(Matrix::<f64, 2, 2>::identity().null_space(), vec![]);
+}
+
+#[test]
+fn nil() {
+    let basis = Matrix::<f64, 2, 3>::default().null_space();
+    assert_eq!(
+        basis,
+        vec![
+            Matrix::from([[1.0], [0.0], [0.0]]),
+            Matrix::from([[0.0], [1.0], [0.0]]),
+            Matrix::from([[0.0], [0.0], [1.0]]),
+        ]
+    );
+}
+
+#[test]
+fn conic() {
+    // the conic ax² + bxy + cy² + dx + ey + f = 0 through five points of the unit circle
+    let points: [(f64, f64); 5] = [(1.0, 0.0), (0.0, 1.0), (-1.0, 0.0), (0.6, 0.8), (0.8, -0.6)];
+    let a = Matrix::<f64, 5, 6>::from_fn(|row, col| {
+        let (x, y) = points[row];
+        [x * x, x * y, y * y, x, y, 1.0][col]
+    });
+    let basis = a.null_space_with_tolerance(&1e-9);
+    assert_eq!(basis.len(), 1);
+    // x² + y² - 1 up to a factor
+    let conic = basis[0].clone() * (1.0 / basis[0][(0, 0)]);
+    assert!(conic.approx_eq(
+        &Matrix::from([[1.0], [0.0], [1.0], [0.0], [0.0], [-1.0]]),
+        1e-9
+    ));
+}
@@ -0,0 +1,26 @@
+use matrix::{ColMajorMatrix, Matrix};
+
+#[test]
+fn conversions() {
+    let m = Matrix::from([[1, 2], [3, 4], [5, 6]]);
+    let col_major = ColMajorMatrix::from(&m);
+    assert_eq!(
+        col_major,
+        ColMajorMatrix::from_columns([[1, 3, 5], [2, 4, 6]])
+    );
+    assert_eq!(col_major.as_slice(), m.transpose().as_slice());
+    assert_eq!(Matrix::from(&col_major), m);
+    assert_eq!(col_major.into_inner(), [[1, 3, 5], [2, 4, 6]]);
+}
+
+#[test]
+fn access() {
+    let mut m = ColMajorMatrix::from_columns([[1, 3, 5], [2, 4, 6]]);
+    assert_eq!(m.get(2, 1), Some(&6));
+    assert_eq!(m.get(1, 2), None);
+    *m.get_mut(0, 1).unwrap() = 0;
+    m[(2, 0)] = 7;
+    m.as_mut_slice()[1] += 10;
+    assert_eq!(m[(1, 0)], 13);
+    assert_eq!(Matrix::from(&m), [[1, 0], [13, 4], [7, 6]].into());
+}
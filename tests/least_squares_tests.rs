@@ -0,0 +1,51 @@
+use matrix::{Error, Matrix};
+
+#[test]
+fn quadratic_fit() {
+    // samples of 2 - x + 0.5x² are fitted exactly
+    let xs: [f64; 6] = [-2.0, -1.0, 0.0, 0.5, 1.0, 3.0];
+    let a = Matrix::<f64, 6, 3>::from_fn(|row, col| xs[row].powi(col as i32));
+    let y = Matrix::<f64, 6, 1>::from_fn(|row, _| 2.0 - xs[row] + 0.5 * xs[row] * xs[row]);
+    let fit = a.lstsq(y).unwrap();
+    assert!(fit.approx_eq(&Matrix::from([[2.0], [-1.0], [0.5]]), 1e-12));
+}
+
+#[test]
+fn normal_equations() {
+    // the residual is orthogonal to the columns
+    let a = Matrix::from([[1.0, 2.0], [3.0, -1.0], [0.0, 1.0], [2.0, 2.0], [-1.0, 0.5]]);
+    let b = Matrix::from([[1.0, 0.0], [2.0, 1.0], [-1.0, 0.0], [0.5, 1.0], [3.0, -2.0]]);
+    let x: Matrix<f64, 2, 2> = a.lstsq(b.clone()).unwrap();
+    let residual = &a * &x - b.clone();
+    assert!((&a.transpose() * &residual).approx_eq(&Matrix::nil(), 1e-12));
+
+    let ata = &a.transpose() * &a;
+    let atb = &a.transpose() * &b;
+    for col in 0..2 {
+        let column = Matrix::from_fn(|row, _| atb[(row, col)]);
+        let expected: Matrix<f64, 2, 1> = ata.solve(column).unwrap();
+        assert!((x[(0, col)] - expected[(0, 0)]).abs() < 1e-12);
+        assert!((x[(1, col)] - expected[(1, 0)]).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn square() {
+    let a = Matrix::from([[2.0, 1.0], [1.0, 3.0]]);
+    let x = a.lstsq(Matrix::from([[5.0], [10.0]])).unwrap();
+    assert!(x.approx_eq(&Matrix::from([[1.0], [3.0]]), 1e-12));
+}
+
+#[test]
+fn rank_deficient() {
+    let a = Matrix::from([[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]]);
+    assert_eq!(
+        a.lstsq(Matrix::from([[1.0], [2.0], [3.0]])),
+        Err(Error::RankDeficient)
+    );
+    let nil_column = Matrix::from([[1.0, 0.0], [2.0, 0.0], [3.0, 0.0]]);
+    assert_eq!(
+        nil_column.lstsq(Matrix::from([[1.0], [2.0], [3.0]])),
+        Err(Error::RankDeficient)
+    );
+}
@@ -0,0 +1,71 @@
+use matrix::{Error, FieldLinearAlgebra, Matrix, Mod};
+use num::traits::{One, Zero};
+
+type F = Mod<101>;
+
+#[test]
+fn arithmetic() {
+    let a = F::new(60);
+    let b = F::new(50);
+    assert_eq!(a + b, F::new(9));
+    assert_eq!(b - a, F::new(91));
+    assert_eq!(-a, F::new(41));
+    assert_eq!(a * b, F::new(3000 % 101));
+    assert_eq!(a / b * b, a);
+    assert_eq!(F::from(-1i64), F::new(100));
+    assert_eq!(F::new(205).value(), 3);
+    assert!(F::zero().inverse().is_none());
+    for i in 1..101 {
+        let x = F::new(i);
+        assert_eq!(x * x.inverse().unwrap(), F::one());
+    }
+}
+
+#[test]
+fn large_modulus() {
+    // products overflow u64 before being reduced
+    type G = Mod<18_446_744_073_709_551_557>;
+    let a = G::new(u64::MAX - 100);
+    assert_eq!(a * a.inverse().unwrap(), G::one());
+    assert_eq!(a + a - a, a);
+}
+
+#[test]
+#[should_panic]
+fn division_by_zero() {
+    let _ = F::new(3) / F::zero();
+}
+
+#[test]
+fn determinant() {
+    let m = Matrix::from([[3, 1, 4], [1, 5, 9], [2, 6, 5]]).map(F::new);
+    // the integer determinant is -90
+    assert_eq!(m.determinant(), F::from(-90i64));
+    let singular = Matrix::from([[2, 4], [3, 6]]).map(Mod::<7>::new);
+    assert_eq!(singular.determinant(), Mod::zero());
+    // singular modulo 5 only
+    let m = Matrix::from([[1, 2], [3, 1]]).map(Mod::<5>::new);
+    assert_eq!(m.determinant(), Mod::zero());
+    assert_eq!(m.inverse(), Err(Error::Singular));
+}
+
+#[test]
+fn inverse_solve() {
+    let m = Matrix::from([[2, 7, 1, 8], [2, 8, 1, 8], [1, 8, 2, 8], [4, 5, 9, 0]]).map(F::new);
+    assert_eq!(m.determinant(), F::from(-104i64));
+    let inverse = m.inverse().unwrap();
+    assert_eq!(&m * &inverse, Matrix::identity());
+    let b = Matrix::from([[1], [2], [3], [4]]).map(F::new);
+    let x = m.solve(b.clone()).unwrap();
+    assert_eq!(&m * &x, b);
+}
+
+#[test]
+fn rref_rank() {
+    let m = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]).map(F::new);
+    assert_eq!(m.rank(), 2);
+    assert_eq!(
+        m.rref(),
+        Matrix::from([[1, 0, -1], [0, 1, 2], [0, 0, 0]]).map(|c: i64| F::from(c))
+    );
+}
@@ -0,0 +1,48 @@
+//! Tests for the items gated behind the `nightly` feature
+#![cfg(feature = "nightly")]
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs)]
+
+use matrix::Matrix;
+
+#[test]
+fn kronecker() {
+    let left = Matrix::from([[1, 2], [3, 4]]);
+    let right = Matrix::from([[0, 5], [6, 7]]);
+    assert_eq!(
+        left.kronecker(&right),
+        [
+            [0, 5, 0, 10],
+            [6, 7, 12, 14],
+            [0, 15, 0, 20],
+            [18, 21, 24, 28]
+        ]
+        .into()
+    );
+}
+
+#[test]
+fn kronecker_non_square() {
+    let left = Matrix::from([[1, 2, 3]]);
+    let right = Matrix::from([[1], [-1]]);
+    assert_eq!(left.kronecker(&right), [[1, 2, 3], [-1, -2, -3]].into());
+}
+
+#[test]
+fn hcat() {
+    let left = Matrix::from([[1, 2], [3, 4], [5, 6]]);
+    let right = Matrix::from([[7], [8], [9]]);
+    assert_eq!(left.hcat(&right), [[1, 2, 7], [3, 4, 8], [5, 6, 9]].into());
+    assert_eq!(right.hcat(&left), [[7, 1, 2], [8, 3, 4], [9, 5, 6]].into());
+}
+
+#[test]
+fn vcat() {
+    let top = Matrix::from([[1, 2, 3]]);
+    let bottom = Matrix::from([[4, 5, 6], [7, 8, 9]]);
+    assert_eq!(top.vcat(&bottom), [[1, 2, 3], [4, 5, 6], [7, 8, 9]].into());
+    assert_eq!(
+        top.vcat(&bottom).transpose(),
+        top.transpose().hcat(&bottom.transpose())
+    );
+}
@@ -0,0 +1,51 @@
+use matrix::Matrix;
+
+fn setup_3x2() -> Matrix<f64, 3, 2> {
+    [[1.0, -2.0], [-3.0, 4.0], [5.0, 6.0]].into()
+}
+
+#[test]
+fn norm_frobenius() {
+    assert!((setup_3x2().norm_frobenius() - 91f64.sqrt()).abs() < 1e-12);
+    assert_eq!(Matrix::<f32, 2, 2>::nil().norm_frobenius(), 0.0);
+}
+
+#[test]
+fn norm_one() {
+    assert_eq!(setup_3x2().norm_one(), 12.0);
+}
+
+#[test]
+fn norm_inf() {
+    assert_eq!(setup_3x2().norm_inf(), 11.0);
+}
+
+#[test]
+fn norm_max() {
+    assert_eq!(setup_3x2().norm_max(), 6.0);
+    assert_eq!(Matrix::from([[-7.5f32]]).norm_max(), 7.5);
+}
+
+#[test]
+fn condition_number() {
+    let m = Matrix::from([[1.0f64, 2.0], [3.0, 4.0]]);
+    // ‖A‖₁ = 6 and A⁻¹ = [[-2, 1], [1.5, -0.5]] so ‖A⁻¹‖₁ = 3.5
+    assert!((m.condition_number() - 21.0).abs() < 1e-12);
+    let ill = Matrix::from([[1.0, 1.0], [1.0, 1.0 + 1e-10]]);
+    assert!(ill.condition_number() > 1e10);
+    assert_eq!(Matrix::<f64, 2, 2>::nil().condition_number(), f64::INFINITY);
+}
+
+#[test]
+fn nan() {
+    let mat = Matrix::from([[f64::NAN, 1.0], [1.0, 1.0]]);
+    assert!(mat.norm_one().is_nan());
+    assert!(mat.norm_inf().is_nan());
+    assert!(mat.norm_max().is_nan());
+    assert!(mat.norm_frobenius().is_nan());
+    // the NaN isn't the first coefficient folded either
+    let mat = Matrix::from([[1.0, 2.0], [3.0, f32::NAN]]);
+    assert!(mat.norm_one().is_nan());
+    assert!(mat.norm_inf().is_nan());
+    assert!(mat.norm_max().is_nan());
+}
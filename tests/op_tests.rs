@@ -1,4 +1,5 @@
 use matrix::{Error, Matrix};
+use std::convert::TryFrom;
 
 mod matrix_setup {
     use matrix::Matrix;
@@ -25,6 +26,123 @@ mod matrix_setup {
     }
 }
 
+#[test]
+fn from_fn() {
+    let hilbert: Matrix<f64, 2, 2> = Matrix::from_fn(|i, j| 1.0 / (i + j + 1) as f64);
+    assert_eq!(hilbert, [[1.0, 0.5], [0.5, 1.0 / 3.0]].into());
+    let mut calls = 0;
+    let m: Matrix<u8, 3, 2> = Matrix::from_fn(|_, _| {
+        calls += 1;
+        calls
+    });
+    assert_eq!(m, matrix_setup::setup_3x2());
+}
+
+#[test]
+fn try_from_iter() {
+    assert_eq!(Matrix::try_from_iter(1..=6), Ok(matrix_setup::setup_3x2()));
+    assert_eq!(
+        Matrix::<u8, 3, 2>::try_from_iter(1..6),
+        Err(Error::WrongLength)
+    );
+    assert_eq!(
+        Matrix::<u8, 3, 2>::try_from_iter(1..),
+        Err(Error::WrongLength)
+    );
+}
+
+#[test]
+fn try_from_slice() {
+    let buffer: Vec<u8> = vec![9, 8, 7, 6, 5, 4];
+    assert_eq!(Matrix::try_from(&buffer[..]), Ok(matrix_setup::setup_2x3()));
+    assert_eq!(
+        Matrix::<u8, 2, 2>::try_from(&buffer[..]),
+        Err(Error::WrongLength)
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn try_from_vec() {
+    let buffer: Vec<u8> = vec![9, 8, 7, 6, 5, 4];
+    assert_eq!(
+        Matrix::try_from(buffer.clone()),
+        Ok(matrix_setup::setup_2x3())
+    );
+    assert_eq!(
+        Matrix::<u8, 3, 3>::try_from(buffer),
+        Err(Error::WrongLength)
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn try_from_nested_vec() {
+    let rows: Vec<Vec<u8>> = vec![vec![1, 2], vec![3, 4], vec![5, 6]];
+    assert_eq!(
+        Matrix::try_from(rows.clone()),
+        Ok(matrix_setup::setup_3x2())
+    );
+    assert_eq!(
+        Matrix::<u8, 2, 3>::try_from(rows.clone()),
+        Err(Error::DimensionMismatch)
+    );
+    assert_eq!(
+        Matrix::<u8, 2, 2>::try_from(rows),
+        Err(Error::DimensionMismatch)
+    );
+    let ragged: Vec<Vec<u8>> = vec![vec![1, 2], vec![3], vec![5, 6, 7]];
+    assert_eq!(
+        Matrix::<u8, 3, 2>::try_from(ragged),
+        Err(Error::DimensionMismatch)
+    );
+}
+
+#[test]
+fn display() {
+    let m = matrix_setup::setup_3x2() * matrix_setup::setup_2x3();
+    assert_eq!(m.to_string(), "[21 18 15]\n[51 44 37]\n[81 70 59]");
+    let m = Matrix::from([[1.5, -10.0], [0.125, 2.0]]);
+    assert_eq!(format!("{}", m), "[  1.5 -10]\n[0.125   2]");
+    assert_eq!(format!("{:.2}", m), "[1.50 -10.00]\n[0.12   2.00]");
+}
+
+#[test]
+fn iter() {
+    let mut m = matrix_setup::setup_3x2();
+    assert_eq!(
+        m.iter().copied().collect::<Vec<_>>(),
+        vec![1, 2, 3, 4, 5, 6]
+    );
+    m.iter_mut().for_each(|c| *c *= 2);
+    assert_eq!(m, [[2, 4], [6, 8], [10, 12]].into());
+}
+
+#[test]
+fn into_iter() {
+    let mut m = matrix_setup::setup_2x3();
+    for c in &mut m {
+        *c -= 1;
+    }
+    assert_eq!((&m).into_iter().max(), Some(&8));
+    assert_eq!(m.into_iter().collect::<Vec<_>>(), vec![8, 7, 6, 5, 4, 3]);
+}
+
+#[test]
+fn index() {
+    let mut m = matrix_setup::setup_3x2();
+    assert_eq!(m[(2, 1)], 6);
+    m[(0, 1)] = 7;
+    assert_eq!(m, [[1, 7], [3, 4], [5, 6]].into());
+}
+
+#[test]
+#[should_panic]
+fn index_fail_bounds() {
+    let m = matrix_setup::setup_3x2();
+    let _ = m[(0, 2)];
+}
+
 #[test]
 fn add() {
     let mut m = matrix_setup::setup_3x2();
@@ -32,6 +150,44 @@ fn add() {
     assert_eq!(m, Matrix::from([[2, 4], [6, 8], [10, 12]]));
 }
 
+#[test]
+fn add_reference() {
+    let mut m = matrix_setup::setup_3x2();
+    let other = matrix_setup::setup_3x2();
+    m += &other;
+    m += &other;
+    assert_eq!(m, Matrix::from([[3, 6], [9, 12], [15, 18]]));
+}
+
+#[test]
+fn add_non_assigning() {
+    let m = matrix_setup::setup_3x2() + matrix_setup::setup_3x2();
+    assert_eq!(m, Matrix::from([[2, 4], [6, 8], [10, 12]]));
+}
+
+#[test]
+fn sub() {
+    let mut m = matrix_setup::setup_3x2();
+    m -= [[1, 1], [1, 1], [1, 1]].into();
+    assert_eq!(m, Matrix::from([[0, 1], [2, 3], [4, 5]]));
+    let m = Matrix::from([[1, -2], [3, 4]]) - Matrix::from([[2, 2], [-3, 4]]);
+    assert_eq!(m, Matrix::from([[-1, -4], [6, 0]]));
+}
+
+#[test]
+fn neg() {
+    let m = Matrix::from([[1, -2], [0, 4]]);
+    assert_eq!(-m, Matrix::from([[-1, 2], [0, -4]]));
+}
+
+#[test]
+fn mul_scalar() {
+    let m = matrix_setup::setup_3x2();
+    assert_eq!(m.clone() * 2, Matrix::from([[2, 4], [6, 8], [10, 12]]));
+    assert_eq!(2 * m, Matrix::from([[2, 4], [6, 8], [10, 12]]));
+    assert_eq!(0.5 * Matrix::from([[1.0, 3.0]]), Matrix::from([[0.5, 1.5]]));
+}
+
 #[test]
 fn mul() {
     let m = matrix_setup::setup_3x2();
@@ -41,6 +197,48 @@ fn mul() {
     );
 }
 
+#[test]
+fn ops_on_references() {
+    let a = matrix_setup::setup_3x2();
+    let b = matrix_setup::setup_2x3();
+    assert_eq!(&a * &b, a.clone() * b.clone());
+    assert_eq!(&a + &a, a.clone() + a.clone());
+    assert_eq!(&(&a + &a) - &a, a);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn mul_non_copy() {
+    use num::BigInt;
+
+    let left = Matrix::from([[BigInt::from(1), BigInt::from(2)]]);
+    let right = Matrix::from([[BigInt::from(3)], [BigInt::from(4)]]);
+    assert_eq!(left * right, [[BigInt::from(11)]].into());
+}
+
+#[test]
+fn pow() {
+    let m = matrix_setup::setup_3x3();
+    assert_eq!(m.clone().pow(0), Matrix::identity());
+    assert_eq!(m.clone().pow(1), m);
+    let m = Matrix::from([[1u32, 2, 1], [3, 4, 1], [1, 5, 6]]);
+    assert_eq!(
+        m.clone().pow(5),
+        m.clone() * m.clone() * m.clone() * m.clone() * m
+    );
+}
+
+#[test]
+fn component_mul() {
+    let mut m = matrix_setup::setup_3x2();
+    assert_eq!(
+        m.component_mul(&matrix_setup::setup_3x2()),
+        [[1, 4], [9, 16], [25, 36]].into()
+    );
+    m.component_mul_assign(&[[2, 0], [0, 2], [1, 1]].into());
+    assert_eq!(m, [[2, 0], [0, 8], [5, 6]].into());
+}
+
 #[test]
 fn dilate() {
     let mut m = matrix_setup::setup_3x3();
@@ -85,3 +283,405 @@ fn permute_fail_bounds() {
     let mut m = matrix_setup::setup_3x3();
     assert_eq!(m.permute(4, 0), Err(Error::OutOfBounds));
 }
+
+#[test]
+fn solve() {
+    let m = Matrix::from([[2.0, 1.0, -1.0], [-3.0, -1.0, 2.0], [-2.0, 1.0, 2.0]]);
+    let x = m.solve([[8.0], [-11.0], [-3.0]].into()).unwrap();
+    x.get_lines()
+        .zip([2.0f64, 3.0, -1.0].iter())
+        .for_each(|(a, b)| assert!((a[0] - b).abs() < 1e-10));
+}
+
+#[test]
+fn solve_fail_singular() {
+    let m = Matrix::from([[1.0, 2.0], [2.0, 4.0]]);
+    assert_eq!(m.solve([[1.0], [2.0]].into()), Err(Error::Singular));
+}
+
+#[test]
+fn rref() {
+    let m = Matrix::from([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 0.0, 1.0]]);
+    assert_eq!(
+        m.rref(),
+        [[1.0, 0.0, 1.0], [0.0, 1.0, 1.0], [0.0, 0.0, 0.0]].into()
+    );
+}
+
+#[test]
+fn reduce_wide() {
+    let mut m = Matrix::from([[0.0, 2.0, 4.0, 2.0], [1.0, 1.0, 1.0, 1.0]]);
+    m.reduce();
+    assert_eq!(m, [[1.0, 0.0, -1.0, 0.0], [0.0, 1.0, 2.0, 1.0]].into());
+}
+
+#[test]
+fn rank() {
+    let m = Matrix::from([[1.0, 2.0, 1.0], [3.0, 4.0, 1.0], [1.0, 5.0, 6.0]]);
+    assert_eq!(m.rank(), 3);
+    let m = Matrix::from([[1.0, 2.0, 1.0], [2.0, 4.0, 2.0], [0.0, 0.0, 0.0]]);
+    assert_eq!(m.rank(), 1);
+}
+
+#[test]
+fn rank_with_tolerance() {
+    let m = Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    assert_eq!(m.rank_with_tolerance(&1e-10), 2);
+}
+
+#[test]
+fn diagonal() {
+    let m = Matrix::from_diagonal([2.0, -1.0]);
+    assert_eq!(m, [[2.0, 0.0], [0.0, -1.0]].into());
+    assert_eq!(m.diagonal(), [2.0, -1.0]);
+    assert_eq!(matrix_setup::setup_3x3().diagonal(), [1, 4, 6]);
+}
+
+#[test]
+fn trace() {
+    let m = matrix_setup::setup_3x3();
+    assert_eq!(m.trace(), 11);
+}
+
+#[test]
+fn get_columns() {
+    let m = matrix_setup::setup_2x3();
+    let cols: Vec<_> = m.get_columns().map(|c| [*c[0], *c[1]]).collect();
+    assert_eq!(cols, vec![[9, 6], [8, 5], [7, 4]]);
+    assert_eq!(m.get_columns().next_back(), Some([&7, &4]));
+}
+
+#[test]
+fn get_mut_columns() {
+    let mut m = matrix_setup::setup_2x3();
+    for (i, col) in m.get_mut_columns().enumerate() {
+        for c in col {
+            *c += i as u8;
+        }
+    }
+    assert_eq!(m, [[9, 9, 9], [6, 6, 6]].into());
+}
+
+#[test]
+fn get_column() {
+    let mut m = matrix_setup::setup_3x2();
+    assert_eq!(m.get_column(1), Some([&2, &4, &6]));
+    assert_eq!(m.get_column(2), None);
+    *m.get_mut_column(0).unwrap()[2] = 0;
+    assert_eq!(m, [[1, 2], [3, 4], [0, 6]].into());
+}
+
+#[test]
+fn permute_cols() {
+    let mut m = matrix_setup::setup_3x3();
+    m.permute_cols(0, 2).unwrap();
+    assert_eq!(m, [[1, 2, 1], [1, 4, 3], [6, 5, 1]].into());
+}
+
+#[test]
+fn permute_cols_fail_bounds() {
+    let mut m = matrix_setup::setup_3x3();
+    assert_eq!(m.permute_cols(0, 3), Err(Error::OutOfBounds));
+}
+
+#[test]
+fn dilate_col() {
+    let mut m = matrix_setup::setup_3x3();
+    m.dilate_col(1, &2u8).unwrap();
+    assert_eq!(m, [[1, 4, 1], [3, 8, 1], [1, 10, 6]].into());
+}
+
+#[test]
+fn dilate_col_fail_bounds() {
+    let mut m = matrix_setup::setup_3x3();
+    assert_eq!(m.dilate_col(3, &1), Err(Error::OutOfBounds));
+}
+
+#[test]
+fn transvect_col() {
+    let mut m = matrix_setup::setup_3x3();
+    m.transvect_col(2, 0).unwrap();
+    assert_eq!(m, [[1, 2, 2], [3, 4, 4], [1, 5, 7]].into());
+}
+
+#[test]
+fn transvect_col_fail_bounds() {
+    let mut m = matrix_setup::setup_3x3();
+    assert_eq!(m.transvect_col(0, 3), Err(Error::OutOfBounds));
+}
+
+#[test]
+fn transvect_col_fail_op() {
+    let mut m = matrix_setup::setup_3x3();
+    assert_eq!(m.transvect_col(1, 1), Err(Error::WrongOperation));
+}
+
+#[test]
+fn transvect_scaled() {
+    let mut m = matrix_setup::setup_3x3();
+    m.transvect_scaled(0, 2, &2).unwrap();
+    assert_eq!(m, [[3, 12, 13], [3, 4, 1], [1, 5, 6]].into())
+}
+
+#[test]
+fn transvect_scaled_fail_bounds() {
+    let mut m = matrix_setup::setup_3x3();
+    assert_eq!(m.transvect_scaled(0, 3, &1), Err(Error::OutOfBounds));
+}
+
+#[test]
+fn transvect_scaled_fail_op() {
+    let mut m = matrix_setup::setup_3x3();
+    assert_eq!(m.transvect_scaled(2, 2, &1), Err(Error::WrongOperation));
+}
+
+#[test]
+fn row_operations_non_square() {
+    let mut m = matrix_setup::setup_3x2();
+    m.permute(0, 2).unwrap();
+    m.dilate(1, &2).unwrap();
+    m.transvect(1, 0).unwrap();
+    assert_eq!(m, [[5, 6], [11, 14], [1, 2]].into());
+    assert_eq!(m.permute(0, 3), Err(Error::OutOfBounds));
+    assert_eq!(m.transvect_col(0, 2), Err(Error::OutOfBounds));
+}
+
+#[test]
+fn inverse() {
+    let m = Matrix::from([[1.0, 2.0, 1.0], [3.0, 4.0, 1.0], [1.0, 5.0, 6.0]]);
+    let product = &m * &m.inverse().unwrap();
+    assert!(product
+        .iter()
+        .zip(Matrix::<f64, 3, 3>::identity().iter())
+        .all(|(a, b)| (a - b).abs() < 1e-12));
+}
+
+#[test]
+fn inverse_fail_singular() {
+    let m = Matrix::from([[1.0, 2.0, 1.0], [2.0, 4.0, 2.0], [1.0, 5.0, 6.0]]);
+    assert_eq!(m.inverse(), Err(Error::Singular));
+}
+
+#[test]
+fn transpose() {
+    let m = matrix_setup::setup_3x2();
+    assert_eq!(m.transpose(), [[1, 3, 5], [2, 4, 6]].into());
+    assert_eq!(m.transpose().transpose(), m);
+}
+
+#[test]
+fn as_slice() {
+    let mut m = matrix_setup::setup_3x2();
+    assert_eq!(m.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    m.as_mut_slice()[3] = 0;
+    assert_eq!(m, [[1, 2], [3, 0], [5, 6]].into());
+    m.as_mut_slice().reverse();
+    assert_eq!(m.into_inner(), [[6, 5], [0, 3], [2, 1]]);
+    assert!(Matrix::<u8, 0, 4>::from([]).as_slice().is_empty());
+    assert!(Matrix::<u8, 4, 0>::from([[], [], [], []])
+        .as_slice()
+        .is_empty());
+}
+
+#[test]
+fn map() {
+    let m = matrix_setup::setup_3x2();
+    assert_eq!(
+        m.clone().map(f64::from),
+        [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]].into()
+    );
+    let mut visited = Vec::new();
+    let squares = m.map(|c| {
+        visited.push(c);
+        u32::from(c) * u32::from(c)
+    });
+    assert_eq!(squares, [[1, 4], [9, 16], [25, 36]].into());
+    assert_eq!(visited, vec![1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn zip_map() {
+    let m = matrix_setup::setup_3x2();
+    let other = Matrix::from([[6, 5], [4, 3], [2, 1]]);
+    assert_eq!(
+        m.zip_map(&other, |a, b| *a.max(b)),
+        [[6, 5], [4, 4], [5, 6]].into()
+    );
+    assert_eq!(m.zip_map(&other, |a, b| a * b), m.component_mul(&other));
+    assert_eq!(m.zip_map(&other, |a, b| (*a, *b)).get(1, 0), Some(&(3, 4)));
+}
+
+#[test]
+fn fold() {
+    let m = matrix_setup::setup_2x3();
+    assert_eq!(
+        m.fold(String::new(), |acc, c| acc + &c.to_string()),
+        "987654"
+    );
+    assert_eq!(m.fold(0u32, |acc, c| acc + u32::from(*c)), 39);
+}
+
+#[test]
+fn sum_product() {
+    let m = Matrix::from([[1.5, 2.0], [-1.0, 4.0]]);
+    assert_eq!(m.sum(), 6.5);
+    assert_eq!(m.product(), -12.0);
+    assert_eq!(Matrix::<i32, 0, 3>::from([]).sum(), 0);
+    assert_eq!(Matrix::<i32, 0, 3>::from([]).product(), 1);
+}
+
+#[test]
+fn count_where() {
+    let m = matrix_setup::setup_3x3();
+    assert_eq!(m.count_where(|c| *c == 1), 4);
+    assert_eq!(m.count_where(|c| c % 2 == 0), 3);
+    assert_eq!(m.count_where(|_| false), 0);
+}
+
+#[test]
+fn cast() {
+    let m = matrix_setup::setup_3x2();
+    assert_eq!(m.clone().cast::<i32>(), [[1, 2], [3, 4], [5, 6]].into());
+    assert_eq!(m.cast::<f32>().get(2, 1), Some(&6.0));
+}
+
+#[test]
+fn try_cast() {
+    let m = Matrix::from([[-1i64, 2], [300, 4]]);
+    assert_eq!(m.clone().try_cast::<i16>(), Ok([[-1, 2], [300, 4]].into()));
+    assert!(m.clone().try_cast::<u16>().is_err());
+    assert!(m.try_cast::<i8>().is_err());
+}
+
+#[test]
+fn zero_one() {
+    use num::traits::{One, Zero};
+    assert_eq!(Matrix::<u8, 3, 2>::zero(), [[0, 0], [0, 0], [0, 0]].into());
+    assert!(!matrix_setup::setup_3x2().is_zero());
+    assert_eq!(Matrix::<u8, 3, 3>::one(), Matrix::identity());
+    assert!(Matrix::<f64, 2, 2>::one().is_one());
+    // matrices of matrices
+    let nested = Matrix::<Matrix<i32, 2, 2>, 2, 1>::zero();
+    assert!(nested.is_zero());
+    assert_eq!(nested.get(1, 0), Some(&Matrix::nil()));
+}
+
+#[test]
+fn add_non_copy() {
+    // matrices aren't Copy, which doesn't prevent adding matrices of matrices
+    let block = Matrix::from([[1, 2], [3, 4]]);
+    let m = Matrix::from([[block.clone()], [-block.clone()]]);
+    let mut sum = &m + &m;
+    assert_eq!(sum, [[&block + &block], [-(&block + &block)]].into());
+    sum -= m;
+    assert_eq!(sum, [[block.clone()], [-block]].into());
+}
+
+#[test]
+fn sum_matrices() {
+    let matrices = vec![matrix_setup::setup_3x2(); 3];
+    assert_eq!(
+        matrices.iter().sum::<Matrix<u8, 3, 2>>(),
+        [[3, 6], [9, 12], [15, 18]].into()
+    );
+    assert_eq!(
+        matrices.into_iter().skip(3).sum::<Matrix<u8, 3, 2>>(),
+        [[0, 0], [0, 0], [0, 0]].into()
+    );
+}
+
+#[test]
+fn product_matrices() {
+    let m = matrix_setup::setup_3x3().cast::<i64>();
+    let matrices = [m.clone(), m.transpose(), Matrix::identity()];
+    assert_eq!(
+        matrices.iter().product::<Matrix<i64, 3, 3>>(),
+        &m * &m.transpose()
+    );
+    assert_eq!(
+        std::iter::repeat(m.clone())
+            .take(3)
+            .product::<Matrix<i64, 3, 3>>(),
+        m.pow(3)
+    );
+    assert_eq!(
+        std::iter::empty::<Matrix<i64, 3, 3>>().product::<Matrix<i64, 3, 3>>(),
+        Matrix::identity()
+    );
+}
+
+#[test]
+fn mul_nested() {
+    use num::traits::Zero;
+    // a block diagonal matrix whose blocks are matrices
+    let block = Matrix::from([[1, 2], [3, 4]]);
+    let diagonal = Matrix::from([
+        [block.clone(), Matrix::zero()],
+        [Matrix::zero(), block.clone()],
+    ]);
+    let square = &block * &block;
+    assert_eq!(
+        &diagonal * &diagonal,
+        [[square.clone(), Matrix::zero()], [Matrix::zero(), square]].into()
+    );
+}
+
+#[test]
+fn default() {
+    assert_eq!(
+        Matrix::<u8, 3, 2>::default(),
+        [[0, 0], [0, 0], [0, 0]].into()
+    );
+    assert_eq!(
+        Matrix::<String, 1, 2>::default(),
+        [[String::new(), String::new()]].into()
+    );
+}
+
+#[test]
+fn hash() {
+    use std::collections::HashSet;
+    let m = matrix_setup::setup_3x3();
+    let generated = vec![m.clone(), &m * &m, m.transpose(), m.clone()];
+    let unique: HashSet<_> = generated.into_iter().collect();
+    assert_eq!(unique.len(), 3);
+    assert!(unique.contains(&m));
+}
+
+#[test]
+fn mul_large() {
+    // large enough for the blocked kernel, with dimensions which aren't multiples of the tiles
+    let left = Box::new(Matrix::<i64, 45, 70>::from_fn(|row, col| {
+        (row as i64 * 5 - col as i64) % 9
+    }));
+    let right = Box::new(Matrix::<i64, 70, 67>::from_fn(|row, col| {
+        (row as i64 + col as i64 * 3) % 7 - 3
+    }));
+    let product = &*left * &*right;
+    for (row, col) in [(0, 0), (44, 66), (31, 32), (17, 5)].iter().copied() {
+        let expected: i64 = (0..70).map(|k| left[(row, k)] * right[(k, col)]).sum();
+        assert_eq!(product[(row, col)], expected);
+    }
+    assert_eq!(product.transpose(), &right.transpose() * &left.transpose());
+
+    // the terms of each coefficient are added in the same order as the naive product, which rounds floats the same
+    let left = Box::new(left.map(|c| c as f64 / 7.0));
+    let right = Box::new(right.map(|c| c as f64 / 3.0));
+    let product = &*left * &*right;
+    for row in 0..45 {
+        for col in 0..67 {
+            let expected: f64 = (0..70).map(|k| left[(row, k)] * right[(k, col)]).sum();
+            assert_eq!(product[(row, col)], expected);
+        }
+    }
+}
+
+#[test]
+fn determinant() {
+    // the first pivot is zero, so rows are swapped
+    let m: Matrix<f64, 3, 3> = Matrix::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0], [6.0, 7.0, 9.0]]);
+    assert!((m.determinant() + 3.0).abs() < 1e-12);
+    assert!((m.transpose().determinant() - m.determinant()).abs() < 1e-12);
+    assert_eq!(Matrix::<f64, 4, 4>::identity().determinant(), 1.0);
+    assert_eq!(Matrix::from([[1.0, 2.0], [2.0, 4.0]]).determinant(), 0.0);
+}
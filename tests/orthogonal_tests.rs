@@ -0,0 +1,115 @@
+use matrix::{ColVector, Error, Matrix};
+
+#[test]
+fn orthogonalize() {
+    let m = Matrix::from([[1.0, 1.0, 0.0], [1.0, 0.0, 1.0], [0.0, 1.0, 1.0]]);
+    let q = m.orthogonalize().unwrap();
+    assert!((&q.transpose() * &q).approx_eq(&Matrix::identity(), 1e-12));
+    // the first column keeps its direction
+    let s = 0.5f64.sqrt();
+    assert!(q
+        .get_column(0)
+        .unwrap()
+        .iter()
+        .zip([s, s, 0.0].iter())
+        .all(|(a, b)| (*a - b).abs() < 1e-12));
+}
+
+#[test]
+fn orthogonalize_rows() {
+    let m = Matrix::from([[2.0, 0.0, 0.0], [1.0, 1.0, 0.0]]);
+    let q = m.orthogonalize_rows().unwrap();
+    assert!(q.approx_eq(&[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]].into(), 1e-12));
+}
+
+#[test]
+fn orthogonalize_fail_rank_deficient() {
+    let m = Matrix::from([[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]]);
+    assert_eq!(m.orthogonalize(), Err(Error::RankDeficient));
+    let m = Matrix::from([[1.0, 0.0, 0.0], [0.0, 0.0, 0.0]]);
+    assert_eq!(m.orthogonalize_rows(), Err(Error::RankDeficient));
+    // three vectors can't be independent in a plane
+    let m = Matrix::from([[1.0, 0.0, 1.0], [0.0, 1.0, 1.0]]);
+    assert_eq!(m.orthogonalize(), Err(Error::RankDeficient));
+}
+
+#[test]
+fn householder() {
+    let v = ColVector::from([[1.0], [2.0], [2.0]]);
+    let h = Matrix::householder(&v).unwrap();
+    // a reflector is symmetric, orthogonal and maps v to -v
+    assert!(h.approx_eq(&h.transpose(), 1e-12));
+    assert!((&h * &h).approx_eq(&Matrix::identity(), 1e-12));
+    assert!((&h * &v).approx_eq(&-v, 1e-12));
+    assert_eq!(
+        Matrix::<f64, 2, 2>::householder(&[[0.0], [0.0]].into()),
+        Err(Error::NilVector)
+    );
+}
+
+#[test]
+fn apply_householder() {
+    let v = ColVector::from([[1.0], [-1.0], [3.0]]);
+    let h = Matrix::householder(&v).unwrap();
+    let m = Matrix::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+    let mut left = m.clone();
+    left.apply_householder(&v).unwrap();
+    assert!(left.approx_eq(&(&h * &m), 1e-12));
+    let mut right = m.transpose();
+    right.apply_householder_right(&v).unwrap();
+    assert!(right.approx_eq(&(&m.transpose() * &h), 1e-12));
+    let mut m = m;
+    assert_eq!(
+        m.apply_householder(&[[0.0], [0.0], [0.0]].into()),
+        Err(Error::NilVector)
+    );
+    assert_eq!(m, Matrix::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]));
+}
+
+#[test]
+fn givens() {
+    let g = Matrix::<f64, 3, 3>::givens(1, 2, 0.3).unwrap();
+    assert!((&g.transpose() * &g).approx_eq(&Matrix::identity(), 1e-12));
+    assert_eq!(g[(0, 0)], 1.0);
+    assert_eq!(g[(1, 1)], 0.3f64.cos());
+    assert_eq!(g[(2, 1)], 0.3f64.sin());
+    assert_eq!(
+        Matrix::<f64, 3, 3>::givens(1, 3, 0.3),
+        Err(Error::OutOfBounds)
+    );
+    assert_eq!(
+        Matrix::<f64, 3, 3>::givens(1, 1, 0.3),
+        Err(Error::WrongOperation)
+    );
+}
+
+#[test]
+fn apply_givens() {
+    let m = Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0]]);
+    let (c, s): (f64, f64) = (0.6, 0.8);
+    let g = Matrix::<f64, 3, 3>::givens(0, 2, s.atan2(c)).unwrap();
+    let mut left = m.clone();
+    left.apply_givens(0, 2, c, s).unwrap();
+    assert!(left.approx_eq(&(&g * &m), 1e-12));
+    let mut right = m.clone();
+    right.apply_givens_right(0, 2, c, s).unwrap();
+    assert!(right.approx_eq(&(&m * &g), 1e-12));
+    assert_eq!(left.apply_givens(0, 3, c, s), Err(Error::OutOfBounds));
+    assert_eq!(
+        right.apply_givens_right(2, 2, c, s),
+        Err(Error::WrongOperation)
+    );
+}
+
+#[test]
+fn givens_parameters() {
+    let mut m = Matrix::from([[1.0, 2.0], [3.0, 4.0], [-2.0, 1.0]]);
+    let (c, s, r) = Matrix::<f64, 3, 2>::givens_parameters(m[(0, 0)], m[(2, 0)]);
+    m.apply_givens(0, 2, c, s).unwrap();
+    assert!((m[(0, 0)] - r).abs() < 1e-12);
+    assert!(m[(2, 0)].abs() < 1e-12);
+    assert_eq!(
+        Matrix::<f64, 3, 2>::givens_parameters(0.0, 0.0),
+        (1.0, 0.0, 0.0)
+    );
+}
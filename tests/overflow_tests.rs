@@ -0,0 +1,62 @@
+use matrix::Matrix;
+
+#[test]
+fn checked_add() {
+    let m = Matrix::from([[100u8, 200], [0, 55]]);
+    assert_eq!(
+        m.checked_add(&[[155, 55], [255, 200]].into()),
+        Some([[255, 255], [255, 255]].into())
+    );
+    assert_eq!(m.checked_add(&[[0, 56], [0, 0]].into()), None);
+}
+
+#[test]
+fn checked_mul() {
+    let left = Matrix::from([[1u8, 2], [3, 4], [5, 6]]);
+    let right = Matrix::from([[9u8, 8, 7], [6, 5, 4]]);
+    assert_eq!(
+        left.checked_mul(&right),
+        Some([[21, 18, 15], [51, 44, 37], [81, 70, 59]].into())
+    );
+    let right = Matrix::from([[9u8, 8, 7], [60, 5, 4]]);
+    assert_eq!(left.checked_mul(&right), None);
+}
+
+#[test]
+fn checked_scale() {
+    let m = Matrix::from([[-64i8, 63]]);
+    assert_eq!(m.checked_scale(&2), Some([[-128, 126]].into()));
+    assert_eq!(m.checked_scale(&-2), None);
+}
+
+#[test]
+fn wrapping_add() {
+    let m = Matrix::from([[100u8, 200], [0, 55]]);
+    assert_eq!(m.wrapping_add(&m), [[200, 144], [0, 110]].into());
+}
+
+#[test]
+fn wrapping_mul() {
+    let left = Matrix::from([[1u8, 2], [3, 4], [5, 6]]);
+    let right = Matrix::from([[9u8, 8, 7], [60, 5, 4]]);
+    assert_eq!(
+        left.wrapping_mul(&right),
+        [[129, 18, 15], [11, 44, 37], [149, 70, 59]].into()
+    );
+}
+
+#[test]
+fn saturating_add() {
+    let m = Matrix::from([[-100i8, 100], [0, 55]]);
+    assert_eq!(m.saturating_add(&m), [[-128, 127], [0, 110]].into());
+}
+
+#[test]
+fn saturating_mul() {
+    let left = Matrix::from([[1u8, 2], [3, 4], [5, 6]]);
+    let right = Matrix::from([[9u8, 8, 7], [60, 5, 4]]);
+    assert_eq!(
+        left.saturating_mul(&right),
+        [[129, 18, 15], [255, 44, 37], [255, 70, 59]].into()
+    );
+}
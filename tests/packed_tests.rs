@@ -0,0 +1,22 @@
+//! Tests for the items gated behind the `matrixmultiply` feature
+#![cfg(feature = "matrixmultiply")]
+
+use matrix::Matrix;
+
+#[test]
+fn packed_mul() {
+    let left = Matrix::<f64, 37, 70>::from_fn(|row, col| ((row * 7 + col * 3) % 13) as f64 - 6.0);
+    let right = Matrix::<f64, 70, 19>::from_fn(|row, col| ((row * 5 + col) % 9) as f64 * 0.5);
+    // small integers and halves are summed exactly in any order
+    assert_eq!(left.packed_mul(&right), &left * &right);
+    let left = left.map(|c| c as f32);
+    let right = right.map(|c| c as f32);
+    assert_eq!(left.packed_mul(&right), &left * &right);
+}
+
+#[test]
+fn packed_mul_empty() {
+    let left = Matrix::<f32, 2, 0>::from_fn(|_, _| 1.0);
+    let right = Matrix::<f32, 0, 3>::from_fn(|_, _| 1.0);
+    assert_eq!(left.packed_mul(&right), Matrix::from([[0.0; 3]; 2]));
+}
@@ -0,0 +1,34 @@
+//! Tests for the items gated behind the `parallel` feature
+#![cfg(feature = "parallel")]
+
+use matrix::Matrix;
+
+// large enough for the work to be split between threads
+fn setup_large() -> Box<Matrix<i16, 192, 192>> {
+    Box::new(Matrix::from_fn(|row, col| {
+        (row as i16 * 31 + col as i16 * 17) % 23 - 11
+    }))
+}
+
+#[test]
+fn par_mul() {
+    let m = setup_large();
+    let t = Box::new(m.transpose());
+    assert_eq!(m.par_mul(&t), &*m * &*t);
+    let small = Matrix::from([[1, 2], [3, 4]]);
+    assert_eq!(small.par_mul(&small), [[7, 10], [15, 22]].into());
+}
+
+#[test]
+fn par_add() {
+    let m = setup_large();
+    assert_eq!(m.par_add(&m), &*m + &*m);
+    let small = Matrix::from([[1, 2], [3, 4]]);
+    assert_eq!(small.par_add(&small), [[2, 4], [6, 8]].into());
+}
+
+#[test]
+fn par_map() {
+    let m = setup_large();
+    assert_eq!(m.clone().par_map(|c| c * 2), m.map(|c| c * 2));
+}
@@ -0,0 +1,77 @@
+use matrix::{Error, Matrix, Permutation};
+use std::convert::TryFrom;
+
+#[test]
+fn new() {
+    assert!(Permutation::new([3, 1, 0, 2]).is_ok());
+    assert_eq!(Permutation::new([0, 1, 1]), Err(Error::NotAPermutation));
+    assert_eq!(Permutation::new([0, 3, 1]), Err(Error::NotAPermutation));
+    assert_eq!(Permutation::try_from([1, 0]), Permutation::new([1, 0]));
+    assert_eq!(Permutation::<3>::default(), Permutation::identity());
+    assert_eq!(Permutation::<3>::identity().indices(), &[0, 1, 2]);
+    assert_eq!(
+        Permutation::<2>::transposition(0, 2),
+        Err(Error::OutOfBounds)
+    );
+}
+
+#[test]
+fn apply() {
+    let p = Permutation::new([1, 3, 0, 2]).unwrap();
+    let a = Matrix::<i32, 4, 3>::from_fn(|row, col| (10 * row + col) as i32);
+    assert_eq!(&p * &a, &p.to_matrix() * &a);
+    let b = a.transpose();
+    assert_eq!(&b * &p, &b * &p.to_matrix());
+
+    // vectors
+    let v = Matrix::from([[1.5], [2.5], [3.5], [4.5]]);
+    assert_eq!(&p * &v, Matrix::from([[2.5], [4.5], [1.5], [3.5]]));
+
+    // transpositions are the permute row operation
+    let mut permuted = a.clone();
+    permuted.permute(0, 2).unwrap();
+    assert_eq!(&Permutation::transposition(0, 2).unwrap() * &a, permuted);
+}
+
+#[test]
+fn compose() {
+    let p = Permutation::new([1, 3, 0, 2]).unwrap();
+    let q = Permutation::new([2, 0, 3, 1]).unwrap();
+    let a = Matrix::<i32, 4, 2>::from_fn(|row, col| (10 * row + col) as i32);
+    assert_eq!(&(&p * &q) * &a, &p * &(&q * &a));
+    assert_eq!(
+        (&p * &q).to_matrix::<i32>(),
+        &p.to_matrix::<i32>() * &q.to_matrix()
+    );
+    assert_eq!(&p * &Permutation::identity(), p);
+}
+
+#[test]
+fn inverse() {
+    let p = Permutation::new([4, 2, 0, 1, 3]).unwrap();
+    assert_eq!(&p * &p.inverse(), Permutation::identity());
+    assert_eq!(&p.inverse() * &p, Permutation::identity());
+    assert_eq!(
+        p.inverse().to_matrix::<f64>(),
+        p.to_matrix::<f64>().transpose()
+    );
+    assert_eq!(p.inverse().inverse(), p);
+}
+
+#[test]
+fn sign() {
+    assert_eq!(Permutation::<4>::identity().sign(), 1);
+    assert_eq!(Permutation::<1>::identity().sign(), 1);
+    assert_eq!(Permutation::<0>::identity().sign(), 1);
+    let t = Permutation::<4>::transposition(1, 3).unwrap();
+    assert_eq!(t.sign(), -1);
+    assert!(!t.is_even());
+    // a 4-cycle is odd, a 3-cycle is even
+    assert_eq!(Permutation::new([1, 2, 3, 0]).unwrap().sign(), -1);
+    assert!(Permutation::new([1, 2, 0, 3]).unwrap().is_even());
+
+    let p = Permutation::new([4, 2, 0, 1, 3]).unwrap();
+    let q = Permutation::new([1, 0, 4, 2, 3]).unwrap();
+    assert_eq!((&p * &q).sign(), p.sign() * q.sign());
+    assert_eq!(p.to_matrix::<f64>().determinant(), f64::from(p.sign()));
+}
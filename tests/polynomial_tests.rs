@@ -0,0 +1,104 @@
+use matrix::Matrix;
+use num::rational::Ratio;
+
+#[test]
+fn char_poly() {
+    // triangular: the roots are the diagonal, (λ - 2)(λ + 1)(λ - 3) = λ³ - 4λ² + λ + 6
+    let a = Matrix::from([[2, 5, -7], [0, -1, 4], [0, 0, 3]]);
+    assert_eq!(a.char_poly(), [6i32, 1, -4]);
+
+    // c₀ is (-1)ⁿ det(A) and cₙ₋₁ is -tr(A)
+    let b = Matrix::from([[1, 2, 0, 3], [-1, 4, 2, 2], [0, 1, 1, -2], [5, 0, 3, 1]]);
+    let c = b.char_poly();
+    assert_eq!(c[3], -b.trace());
+    let det = b.clone().map(f64::from).determinant().round() as i32;
+    assert_eq!(c[0], det);
+
+    assert_eq!(Matrix::<i32, 1, 1>::from([[7]]).char_poly(), [-7i32]);
+    assert_eq!(Matrix::<i32, 0, 0>::from([]).char_poly(), [0i32; 0]);
+}
+
+#[test]
+fn cayley_hamilton() {
+    // every matrix is a root of its characteristic polynomial
+    let r = |n: i64, d: i64| Ratio::new(n, d);
+    let a = Matrix::from([
+        [r(1, 2), r(2, 3), r(0, 1)],
+        [r(-1, 1), r(3, 1), r(1, 4)],
+        [r(2, 5), r(0, 1), r(-1, 3)],
+    ]);
+    let c = a.char_poly();
+    let mut p = Matrix::identity() * c[0];
+    let mut power = Matrix::identity();
+    for coefficient in c.iter().skip(1) {
+        power = &power * &a;
+        p += power.clone() * *coefficient;
+    }
+    p += &power * &a;
+    assert_eq!(p, Matrix::nil());
+}
+
+#[test]
+fn polyval() {
+    let a = Matrix::from([[2, -1, 0], [1, 0, 3], [0, 4, 1]]);
+    let squared = &a * &a;
+    let cubed = &squared * &a;
+    let expected = Matrix::identity() * 5 - a.clone() * 3 + cubed * 2;
+    assert_eq!(a.polyval(&[5, -3, 0, 2]), expected);
+
+    assert_eq!(a.polyval(&[7]), Matrix::identity() * 7);
+    assert_eq!(a.polyval(&[]), Matrix::nil());
+    // trailing zeros don't change the polynomial
+    assert_eq!(a.polyval(&[1, 1, 0, 0]), a.polyval(&[1, 1]));
+}
+
+#[test]
+fn companion() {
+    // (λ - 1)(λ + 2)(λ - 3) = λ³ - 2λ² - 5λ + 6
+    let coeffs = [6, -5, -2];
+    let companion = Matrix::companion(coeffs);
+    assert_eq!(companion, Matrix::from([[0, 0, -6], [1, 0, 5], [0, 1, 2]]));
+    assert_eq!(companion.char_poly(), coeffs);
+    for root in [1, -2, 3] {
+        assert_eq!(
+            (companion.clone() - Matrix::identity() * root)
+                .map(f64::from)
+                .determinant(),
+            0.0
+        );
+    }
+
+    assert_eq!(Matrix::companion([4]), Matrix::from([[-4]]));
+}
+
+#[test]
+fn vandermonde() {
+    let v: Matrix<i64, 4, 3> = Matrix::vandermonde([-1, 0, 2, 5]);
+    assert_eq!(
+        v,
+        Matrix::from([[1, -1, 1], [1, 0, 0], [1, 2, 4], [1, 5, 25]])
+    );
+    // evaluating 3 - x + 2x² at the points
+    assert_eq!(
+        &v * &Matrix::from([[3], [-1], [2]]),
+        Matrix::from([[6], [3], [9], [48]])
+    );
+
+    // the determinant is the product of the differences of the points
+    let square: Matrix<i64, 3, 3> = Matrix::vandermonde([2, 3, 7]);
+    assert_eq!(square.map(|c| c as f64).determinant().round(), 20.0);
+
+    // least squares fit of a degree one polynomial
+    let x = [0.0, 1.0, 2.0, 3.0, 4.0];
+    let v: Matrix<f64, 5, 2> = Matrix::vandermonde(x);
+    let y = Matrix::from(x.map(|x| [1.0 + 2.0 * x]));
+    let fit = v.lstsq(y).unwrap();
+    assert!(fit.approx_eq(&Matrix::from([[1.0], [2.0]]), 1e-12));
+
+    // the last column is the highest power computed, which may be close to the maximum of the type
+    let v: Matrix<i32, 1, 4> = Matrix::vandermonde([1000]);
+    assert_eq!(v, Matrix::from([[1, 1000, 1_000_000, 1_000_000_000]]));
+
+    let empty: Matrix<i32, 2, 0> = Matrix::vandermonde([4, 5]);
+    assert_eq!(empty.iter().count(), 0);
+}
@@ -0,0 +1,71 @@
+//! Exact linear algebra with rational coefficients, which are `Clone` but not `Copy`
+#![cfg(feature = "std")]
+
+use matrix::{Error, Matrix};
+use num::bigint::BigInt;
+use num::rational::{BigRational, Ratio};
+
+fn ratio(n: i64, d: i64) -> Ratio<i64> {
+    Ratio::new(n, d)
+}
+
+fn big(n: i64, d: i64) -> BigRational {
+    BigRational::new(BigInt::from(n), BigInt::from(d))
+}
+
+#[test]
+fn inverse_exact() {
+    // the Hilbert matrix is notoriously ill-conditioned, its inverse has integer coefficients
+    let hilbert = Matrix::<BigRational, 4, 4>::from_fn(|row, col| big(1, (row + col + 1) as i64));
+    let inverse = hilbert.inverse().unwrap();
+    let expected = Matrix::from([
+        [16, -120, 240, -140],
+        [-120, 1200, -2700, 1680],
+        [240, -2700, 6480, -4200],
+        [-140, 1680, -4200, 2800],
+    ])
+    .map(|c| big(c, 1));
+    assert_eq!(inverse, expected);
+    assert_eq!(&hilbert * &inverse, Matrix::identity());
+    assert_eq!(hilbert.determinant(), big(1, 6_048_000));
+}
+
+#[test]
+fn solve_exact() {
+    let a = Matrix::from([[ratio(1, 2), ratio(1, 3)], [ratio(1, 4), ratio(1, 5)]]);
+    let b = Matrix::from([[ratio(1, 1)], [ratio(1, 1)]]);
+    let x = a.solve(b.clone()).unwrap();
+    assert_eq!(x, Matrix::from([[ratio(-8, 1)], [ratio(15, 1)]]));
+    assert_eq!(&a * &x, b);
+
+    let singular = Matrix::from([[ratio(1, 3), ratio(2, 3)], [ratio(1, 2), ratio(1, 1)]]);
+    assert_eq!(singular.solve(b), Err(Error::Singular));
+    assert_eq!(singular.inverse(), Err(Error::Singular));
+}
+
+#[test]
+fn rref_exact() {
+    let m = Matrix::from([[2, 4, 1], [1, 3, 2]]).map(|c| big(c, 1));
+    let expected =
+        Matrix::from([[(1, 1), (0, 1), (-5, 2)], [(0, 1), (1, 1), (3, 2)]]).map(|(n, d)| big(n, d));
+    assert_eq!(m.rref(), expected);
+    assert_eq!(m.rank(), 2);
+}
+
+#[test]
+fn non_copy_helpers() {
+    let identity = Matrix::<BigRational, 3, 3>::identity();
+    assert_eq!(identity.trace(), big(3, 1));
+    assert_eq!(
+        Matrix::<BigRational, 2, 2>::nil(),
+        Matrix::from_fn(|_, _| big(0, 1))
+    );
+    let m = Matrix::from([[big(1, 2), big(1, 1)], [big(0, 1), big(1, 3)]]);
+    assert_eq!(m.clone().pow(3), &(&m * &m) * &m);
+    let mut scaled = m.clone();
+    scaled *= &big(6, 1);
+    assert_eq!(
+        scaled,
+        Matrix::from([[big(3, 1), big(6, 1)], [big(0, 1), big(2, 1)]])
+    );
+}
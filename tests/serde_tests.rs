@@ -0,0 +1,29 @@
+#![cfg(feature = "serde")]
+
+use matrix::Matrix;
+
+#[test]
+fn serialize() {
+    let m = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(serde_json::to_string(&m).unwrap(), "[[1,2,3],[4,5,6]]");
+}
+
+#[test]
+fn deserialize() {
+    let m: Matrix<f64, 2, 2> = serde_json::from_str("[[1.0, 2.5], [-3, 4]]").unwrap();
+    assert_eq!(m, [[1.0, 2.5], [-3.0, 4.0]].into());
+}
+
+#[test]
+fn round_trip() {
+    let m = Matrix::from([[9u8, 8, 7], [6, 5, 4]]);
+    let json = serde_json::to_string(&m).unwrap();
+    assert_eq!(serde_json::from_str::<Matrix<u8, 2, 3>>(&json).unwrap(), m);
+}
+
+#[test]
+fn deserialize_fail_dimensions() {
+    assert!(serde_json::from_str::<Matrix<u8, 2, 2>>("[[1, 2], [3]]").is_err());
+    assert!(serde_json::from_str::<Matrix<u8, 2, 2>>("[[1, 2]]").is_err());
+    assert!(serde_json::from_str::<Matrix<u8, 2, 2>>("[[1, 2], [3, 4], [5, 6]]").is_err());
+}
@@ -0,0 +1,29 @@
+//! Tests for the items gated behind the `simd` feature
+#![cfg(feature = "simd")]
+
+use matrix::Matrix;
+
+#[test]
+fn simd_mul() {
+    let left = Matrix::<i64, 5, 17>::from_fn(|row, col| (row as i64 * 7 - col as i64 * 3) % 11);
+    let right = Matrix::<i64, 17, 9>::from_fn(|row, col| (row as i64 + col as i64 * 5) % 13 - 6);
+    assert_eq!(left.simd_mul(&right), &left * &right);
+    let left = left.map(|c| c as f64 / 3.0);
+    let right = right.map(|c| c as f64 * 0.1);
+    assert_eq!(left.simd_mul(&right), &left * &right);
+}
+
+#[test]
+fn simd_mul_small() {
+    // dimensions smaller than a vector only go through the scalar remainder
+    let m = Matrix::from([[1u8, 2], [3, 4]]);
+    assert_eq!(m.simd_mul(&m), [[7, 10], [15, 22]].into());
+}
+
+#[test]
+fn simd_scale() {
+    let mut m = Matrix::<f32, 4, 7>::from_fn(|row, col| row as f32 * 0.5 + col as f32);
+    let expected = m.clone() * 1.5;
+    m.simd_scale(1.5);
+    assert_eq!(m, expected);
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "alloc")]
+
+use matrix::{Error, Matrix, SparseMatrix};
+
+#[test]
+fn from_dense() {
+    let dense = Matrix::from([[0, 0, 5], [0, 0, 0], [7, 0, 0], [0, 1, 2]]);
+    let sparse = SparseMatrix::from(&dense);
+    assert_eq!(sparse.nnz(), 4);
+    assert_eq!(
+        sparse.iter().collect::<Vec<_>>(),
+        vec![(0, 2, &5), (2, 0, &7), (3, 1, &1), (3, 2, &2)]
+    );
+    assert_eq!(sparse.get(3, 2), Some(&2));
+    assert_eq!(sparse.get(1, 1), None);
+    assert_eq!(sparse.get(4, 0), None);
+    assert_eq!(sparse.to_dense(), dense);
+}
+
+#[test]
+fn from_triplets() {
+    let sparse =
+        SparseMatrix::<i32, 3, 3>::from_triplets(vec![(2, 1, 3), (0, 1, 1), (2, 1, -3), (1, 0, 2)])
+            .unwrap();
+    // the cancelled coefficient isn't stored
+    assert_eq!(sparse.nnz(), 2);
+    assert_eq!(sparse.to_dense(), [[0, 1, 0], [2, 0, 0], [0, 0, 0]].into());
+    assert_eq!(sparse, SparseMatrix::from(&sparse.to_dense()));
+    assert_eq!(
+        SparseMatrix::<i32, 3, 3>::from_triplets(vec![(0, 3, 1)]),
+        Err(Error::OutOfBounds)
+    );
+    assert_eq!(SparseMatrix::<i32, 2, 2>::new().to_dense(), Matrix::nil());
+}
+
+#[test]
+fn mul_dense() {
+    let dense = Matrix::from([[1, 0, 0, 2], [0, 0, 0, 0], [0, -3, 4, 0]]);
+    let other = Matrix::from([[1, 2], [3, 4], [5, 6], [7, 8]]);
+    assert_eq!(&SparseMatrix::from(&dense) * &other, &dense * &other);
+}
@@ -0,0 +1,61 @@
+use matrix::Matrix;
+
+#[test]
+fn row_col_sums() {
+    let m = Matrix::from([[1, 2], [3, 4], [5, 6]]);
+    assert_eq!(m.row_sums(), [3, 7, 11]);
+    assert_eq!(m.col_sums(), [9, 12]);
+    assert_eq!(Matrix::<i32, 2, 0>::from([[], []]).row_sums(), [0, 0]);
+}
+
+#[test]
+fn stochastic() {
+    // the rows of a stochastic matrix sum to one
+    let m = Matrix::from([[0.5, 0.25, 0.25], [0.0, 1.0, 0.0]]);
+    assert_eq!(m.row_sums(), [1.0, 1.0]);
+    assert_eq!(m.col_sums(), [0.5, 1.25, 0.25]);
+}
+
+#[test]
+fn row_col_means() {
+    let m = Matrix::from([[1.0, 2.0], [3.0, 4.0], [5.0, 9.0]]);
+    assert_eq!(m.row_means(), [1.5, 3.5, 7.0]);
+    assert_eq!(m.col_means(), [3.0, 5.0]);
+    assert!(Matrix::<f64, 2, 0>::from([[], []])
+        .row_means()
+        .iter()
+        .all(|m| m.is_nan()));
+}
+
+#[test]
+fn min_max() {
+    let m = Matrix::from([[2, 7, 1], [8, 2, 8], [1, 8, 2]]);
+    assert_eq!(m.min(), Some(&1));
+    assert_eq!(m.max(), Some(&8));
+    // ties go to the first coefficient row after row
+    assert_eq!(m.argmin(), Some((0, 2)));
+    assert_eq!(m.argmax(), Some((1, 0)));
+    assert_eq!(Matrix::<i32, 0, 0>::from([]).max(), None);
+}
+
+#[test]
+fn min_max_nan() {
+    let m = Matrix::from([[f64::NAN, 2.0], [-1.0, f64::NAN]]);
+    assert_eq!(m.argmin(), Some((1, 0)));
+    assert_eq!(m.argmax(), Some((0, 1)));
+    assert_eq!(m.row_argmax(), [Some(1), Some(0)]);
+    assert_eq!(Matrix::from([[f64::NAN]]).max(), None);
+}
+
+#[test]
+fn row_col_argmax() {
+    let m = Matrix::from([[2, 7, 1], [8, 2, 8], [1, 8, 2]]);
+    assert_eq!(m.row_argmin(), [Some(2), Some(1), Some(0)]);
+    assert_eq!(m.row_argmax(), [Some(1), Some(0), Some(1)]);
+    assert_eq!(m.col_argmin(), [Some(2), Some(1), Some(0)]);
+    assert_eq!(m.col_argmax(), [Some(1), Some(2), Some(1)]);
+    assert_eq!(
+        Matrix::<i32, 2, 0>::from([[], []]).row_argmax(),
+        [None, None]
+    );
+}
@@ -0,0 +1,42 @@
+//! Tests for the items gated behind the `strassen` feature
+#![cfg(feature = "strassen")]
+
+use matrix::Matrix;
+
+#[test]
+fn strassen_mul() {
+    let left = Matrix::<i32, 128, 128>::from_fn(|row, col| ((row * 7 + col * 3) % 13) as i32 - 6);
+    let right = Matrix::<i32, 128, 128>::from_fn(|row, col| ((row * 5 + col) % 9) as i32 - 4);
+    assert_eq!(left.strassen_mul(&right), &left * &right);
+}
+
+#[test]
+fn strassen_mul_two_levels() {
+    let left = Box::new(Matrix::<i16, 256, 256>::from_fn(|row, col| {
+        ((row + col) % 3) as i16 - 1
+    }));
+    let right = Box::new(Matrix::<i16, 256, 256>::from_fn(|row, col| {
+        ((row * col) % 5) as i16 - 2
+    }));
+    assert_eq!(left.strassen_mul(&right), &*left * &*right);
+}
+
+#[test]
+fn strassen_mul_fallback() {
+    // not a power of two
+    let m = Matrix::<f64, 3, 3>::from_fn(|row, col| (row * 3 + col) as f64);
+    assert_eq!(m.strassen_mul(&m), &m * &m);
+    let m = Matrix::from([[1, 2], [3, 4]]);
+    assert_eq!(m.strassen_mul(&m), [[7, 10], [15, 22]].into());
+}
+
+#[test]
+fn strassen_mul_float() {
+    let left = Matrix::<f64, 128, 128>::from_fn(|row, col| (row as f64 - col as f64) / 16.0);
+    let right = Matrix::<f64, 128, 128>::from_fn(|row, col| ((row * col) % 10) as f64 * 0.25);
+    let expected = &left * &right;
+    let result = left.strassen_mul(&right);
+    for (a, b) in result.iter().zip(expected.iter()) {
+        assert!((a - b).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,82 @@
+use matrix::Matrix;
+
+#[test]
+fn triangular() {
+    let upper = Matrix::from([[1, 2, 3], [0, 4, 5], [0, 0, 6]]);
+    assert!(upper.is_upper_triangular());
+    assert!(!upper.is_lower_triangular());
+    assert!(upper.transpose().is_lower_triangular());
+    assert!(!upper.transpose().is_upper_triangular());
+
+    // rectangular matrices
+    assert!(Matrix::from([[1, 2], [0, 3], [0, 0]]).is_upper_triangular());
+    assert!(!Matrix::from([[1, 2], [0, 3], [0, 1]]).is_upper_triangular());
+    assert!(Matrix::from([[1, 0, 0], [2, 3, 0]]).is_lower_triangular());
+
+    // row echelon forms are upper triangular
+    let rref = Matrix::from([[2.0, 4.0, 2.0], [1.0, 3.0, 2.0]]).rref();
+    assert!(rref.is_upper_triangular());
+}
+
+#[test]
+fn diagonal() {
+    let diagonal = Matrix::from_diagonal([1, 0, 3]);
+    assert!(diagonal.is_diagonal());
+    assert!(diagonal.is_upper_triangular() && diagonal.is_lower_triangular());
+    assert!(diagonal.is_symmetric());
+    assert!(Matrix::<i32, 2, 3>::default().is_diagonal());
+    assert!(!Matrix::from([[1, 0], [0, 1], [1, 0]]).is_diagonal());
+}
+
+#[test]
+fn symmetric() {
+    let a = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+    assert!((&a * &a.transpose()).is_symmetric());
+    assert!((&a.transpose() * &a).is_symmetric());
+    assert!(!Matrix::from([[1, 2, 3], [2, 4, 5], [3, 6, 6]]).is_symmetric());
+    assert!(Matrix::from([[7]]).is_symmetric());
+}
+
+#[test]
+fn with_tolerance() {
+    let rotation = Matrix::from([[0.6, -0.8], [0.8, 0.6]]);
+    let product = &rotation * &rotation.transpose();
+    assert!(product.is_diagonal_with_tolerance(&1e-12));
+    assert!(product.is_symmetric_with_tolerance(&1e-12));
+
+    let almost = Matrix::from([[1.0, 1e-10, 0.0], [2.0, 1.0, -1e-10], [3.0, 4.0, 5.0]]);
+    assert!(!almost.is_lower_triangular());
+    assert!(almost.is_lower_triangular_with_tolerance(&1e-9));
+    assert!(!almost.is_lower_triangular_with_tolerance(&1e-11));
+    assert!(!almost.is_upper_triangular_with_tolerance(&1.0));
+    assert!(almost.transpose().is_upper_triangular_with_tolerance(&1e-9));
+
+    assert!(!Matrix::from([[1.0, 2.0], [2.5, 1.0]]).is_symmetric_with_tolerance(&0.1));
+    assert!(Matrix::from([[1.0, 2.0], [2.5, 1.0]]).is_symmetric_with_tolerance(&0.5));
+}
+
+#[test]
+fn identity() {
+    assert!(Matrix::<f64, 4, 4>::identity().is_identity(&0.0));
+    assert!(!Matrix::<f64, 2, 2>::nil().is_identity(&0.5));
+    assert!(Matrix::from([[1.0, 1e-13], [0.0, 1.0 - 1e-13]]).is_identity(&1e-12));
+    assert!(!Matrix::from([[1.0, 1e-13], [0.0, 1.0 - 1e-13]]).is_identity(&1e-14));
+    assert!(!Matrix::from([[0, 1], [1, 0]]).is_identity(&0));
+}
+
+#[test]
+fn orthogonal() {
+    let householder = Matrix::householder(&[[1.0], [2.0], [2.0]].into()).unwrap();
+    assert!(householder.is_orthogonal(&1e-12));
+    assert!(householder.is_symmetric_with_tolerance(&1e-12));
+
+    let givens = Matrix::<f64, 3, 3>::givens(0, 2, 0.7).unwrap();
+    assert!(givens.is_orthogonal(&1e-12));
+    assert!((&givens * &householder).is_orthogonal(&1e-12));
+
+    // permutations are orthogonal, even with integer coefficients
+    assert!(Matrix::from([[0, 1, 0], [0, 0, 1], [1, 0, 0]]).is_orthogonal(&0));
+    // orthogonal columns aren't enough, they must be normalized
+    assert!(!Matrix::from([[1.0, 1.0], [1.0, -1.0]]).is_orthogonal(&1e-12));
+    assert!(!Matrix::from([[1.0, 0.5], [0.0, 1.0]]).is_orthogonal(&1e-3));
+}
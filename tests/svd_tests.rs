@@ -0,0 +1,73 @@
+use matrix::Matrix;
+
+// the four Penrose conditions characterize the pseudo-inverse
+fn check_penrose<const ROWS: usize, const COLS: usize>(a: &Matrix<f64, ROWS, COLS>) {
+    let p = a.pinv();
+    assert!((&(a * &p) * a).approx_eq(a, 1e-10));
+    assert!((&(&p * a) * &p).approx_eq(&p, 1e-10));
+    assert!((a * &p).is_symmetric_with_tolerance(&1e-10));
+    assert!((&p * a).is_symmetric_with_tolerance(&1e-10));
+}
+
+#[test]
+fn invertible() {
+    let a = Matrix::from([[4.0, 7.0, 2.0], [3.0, 6.0, 1.0], [2.0, 5.0, 3.0]]);
+    assert!(a.pinv().approx_eq(&a.inverse().unwrap(), 1e-12));
+    check_penrose(&a);
+}
+
+#[test]
+fn overdetermined() {
+    let a = Matrix::from([[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]]);
+    let y = Matrix::from([[1.0], [3.0], [4.0], [7.0]]);
+    assert!((&a.pinv() * &y).approx_eq(&a.lstsq(y).unwrap(), 1e-12));
+    check_penrose(&a);
+}
+
+#[test]
+fn underdetermined() {
+    let a = Matrix::from([[1.0, 2.0, 3.0], [0.0, 1.0, -1.0]]);
+    let b = Matrix::from([[6.0], [0.0]]);
+    let x = &a.pinv() * &b;
+    assert!((&a * &x).approx_eq(&b, 1e-12));
+    // the minimum norm solution is orthogonal to the kernel, spanned by (-5, 1, 1)
+    let kernel = Matrix::from([[-5.0, 1.0, 1.0]]);
+    assert!((&kernel * &x).approx_eq(&Matrix::nil(), 1e-12));
+    check_penrose(&a);
+}
+
+#[test]
+fn rank_deficient() {
+    let a = Matrix::from([
+        [1.0, 2.0, 3.0],
+        [2.0, 4.0, 6.0],
+        [1.0, 1.0, 1.0],
+        [0.0, 1.0, 2.0],
+    ]);
+    check_penrose(&a);
+    check_penrose(&a.transpose());
+
+    // rank one: the pseudo-inverse of uvᵀ is vuᵀ/(‖u‖²‖v‖²)
+    let rank_one = Matrix::from([[1.0, 2.0], [2.0, 4.0]]);
+    assert!(rank_one
+        .pinv()
+        .approx_eq(&(rank_one.transpose() * (1.0 / 25.0)), 1e-12));
+}
+
+#[test]
+fn nil() {
+    assert_eq!(
+        Matrix::<f64, 3, 2>::default().pinv(),
+        Matrix::<f64, 2, 3>::default()
+    );
+}
+
+#[test]
+fn tolerance() {
+    let a: Matrix<f64, 3, 2> = Matrix::from([[3.0, 0.0], [0.0, 1e-8], [0.0, 0.0]]);
+    assert!(a.pinv_with_tolerance(&1e-6).approx_eq(
+        &Matrix::from([[1.0 / 3.0, 0.0, 0.0], [0.0, 0.0, 0.0]]),
+        1e-12
+    ));
+    assert!((a.pinv()[(1, 1)] - 1e8).abs() < 1e-3);
+}
@@ -0,0 +1,197 @@
+use matrix::{ColVector, DepthRange, Error, Handedness, Matrix};
+use std::f64::consts::{FRAC_PI_2, PI};
+
+#[test]
+fn rotation() {
+    let r = Matrix::rotation(PI / 6.0);
+    assert!((&r * &r * r.clone()).approx_eq(&Matrix::rotation(FRAC_PI_2), 1e-12));
+    assert!((&r.transpose() * &r).approx_eq(&Matrix::identity(), 1e-12));
+}
+
+#[test]
+fn rotation_axes() {
+    let x = ColVector::from([[1.0], [0.0], [0.0]]);
+    let y = ColVector::from([[0.0], [1.0], [0.0]]);
+    let z = ColVector::from([[0.0], [0.0], [1.0]]);
+    assert!((&Matrix::rotation_x(FRAC_PI_2) * &y).approx_eq(&z, 1e-12));
+    assert!((&Matrix::rotation_y(FRAC_PI_2) * &z).approx_eq(&x, 1e-12));
+    assert!((&Matrix::rotation_z(FRAC_PI_2) * &x).approx_eq(&y, 1e-12));
+}
+
+#[test]
+fn from_axis_angle() {
+    let angle = 0.7;
+    let z = ColVector::from([[0.0], [0.0], [2.0]]);
+    assert!(Matrix::from_axis_angle(&z, angle)
+        .unwrap()
+        .approx_eq(&Matrix::rotation_z(angle), 1e-12));
+    let x = ColVector::from([[-1.0], [0.0], [0.0]]);
+    assert!(Matrix::from_axis_angle(&x, angle)
+        .unwrap()
+        .approx_eq(&Matrix::rotation_x(-angle), 1e-12));
+    let axis = ColVector::from([[1.0], [2.0], [-2.0]]);
+    let r = Matrix::from_axis_angle(&axis, angle).unwrap();
+    assert!((&r * &axis).approx_eq(&axis, 1e-12));
+    assert!((&r.transpose() * &r).approx_eq(&Matrix::identity(), 1e-12));
+    assert_eq!(
+        Matrix::from_axis_angle(&ColVector::from([[0.0], [0.0], [0.0]]), angle),
+        Err(Error::NilVector)
+    );
+}
+
+#[test]
+fn affine_2d() {
+    let p = ColVector::from([[1.0], [0.0]]);
+    let m = &Matrix::translation_2d(2.0, 3.0) * &Matrix::rotation_2d(FRAC_PI_2);
+    assert!(m
+        .transform_point(&p)
+        .approx_eq(&[[2.0], [4.0]].into(), 1e-12));
+    assert!(m
+        .transform_vector(&p)
+        .approx_eq(&[[0.0], [1.0]].into(), 1e-12));
+    let s = Matrix::scaling_2d(2.0, -1.0);
+    assert_eq!(
+        s.transform_point(&[[3.0], [4.0]].into()),
+        [[6.0], [-4.0]].into()
+    );
+    assert_eq!(
+        Matrix::rotation(0.4).to_homogeneous(),
+        Matrix::rotation_2d(0.4)
+    );
+}
+
+#[test]
+fn affine_3d() {
+    let p = ColVector::from([[1.0], [2.0], [3.0]]);
+    let t = Matrix::translation_3d(-1.0, 0.0, 1.0);
+    let s = Matrix::scaling_3d(2.0, 2.0, 0.5);
+    assert_eq!((&t * &s).transform_point(&p), [[1.0], [4.0], [2.5]].into());
+    assert_eq!((&s * &t).transform_point(&p), [[0.0], [4.0], [2.0]].into());
+    assert_eq!((&t * &s).transform_vector(&p), [[2.0], [4.0], [1.5]].into());
+    let r = Matrix::rotation_y(0.3);
+    assert!(r
+        .to_homogeneous()
+        .transform_point(&p)
+        .approx_eq(&(&r * &p), 1e-12));
+}
+
+#[test]
+fn transform_point_projective() {
+    // the last row scales the homogeneous coordinate
+    let mut m = Matrix::<f64, 3, 3>::identity();
+    m[(2, 2)] = 2.0;
+    assert_eq!(
+        m.transform_point(&[[4.0], [2.0]].into()),
+        [[2.0], [1.0]].into()
+    );
+}
+
+fn assert_quaternion_eq(a: [f64; 4], b: [f64; 4]) {
+    assert!(
+        a.iter().zip(b.iter()).all(|(a, b)| (a - b).abs() < 1e-12),
+        "{:?} != {:?}",
+        a,
+        b
+    );
+}
+
+#[test]
+fn from_quaternion() {
+    let axis = ColVector::from([[1.0], [-2.0], [2.0]]);
+    let angle = 1.2f64;
+    let (s, c) = (angle / 2.0).sin_cos();
+    // not normalized on purpose
+    let q = [2.0 * c, 2.0 * s / 3.0, -4.0 * s / 3.0, 4.0 * s / 3.0];
+    assert!(Matrix::from_quaternion(q)
+        .unwrap()
+        .approx_eq(&Matrix::from_axis_angle(&axis, angle).unwrap(), 1e-12));
+    assert_eq!(
+        Matrix::from_quaternion([1.0, 0.0, 0.0, 0.0]),
+        Ok(Matrix::identity())
+    );
+    assert_eq!(
+        Matrix::<f64, 3, 3>::from_quaternion([0.0; 4]),
+        Err(Error::NilVector)
+    );
+}
+
+#[test]
+fn to_quaternion() {
+    assert_quaternion_eq(Matrix::identity().to_quaternion(), [1.0, 0.0, 0.0, 0.0]);
+    assert_quaternion_eq(Matrix::rotation_y(PI).to_quaternion(), [0.0, 0.0, 1.0, 0.0]);
+    assert_quaternion_eq(Matrix::rotation_z(PI).to_quaternion(), [0.0, 0.0, 0.0, 1.0]);
+    // a negative scalar part is flipped, both quaternions being the same rotation
+    assert_quaternion_eq(
+        Matrix::from_quaternion([-0.5, 0.5, 0.5, -0.5])
+            .unwrap()
+            .to_quaternion(),
+        [0.5, -0.5, -0.5, 0.5],
+    );
+    let q = [0.1, 0.7, -0.1, 0.7];
+    let norm = q.iter().map(|c: &f64| c * c).sum::<f64>().sqrt();
+    assert_quaternion_eq(
+        Matrix::from_quaternion(q).unwrap().to_quaternion(),
+        [q[0] / norm, q[1] / norm, q[2] / norm, q[3] / norm],
+    );
+}
+
+fn project(p: &Matrix<f64, 4, 4>, point: [f64; 3]) -> ColVector<f64, 3> {
+    p.transform_point(&[[point[0]], [point[1]], [point[2]]].into())
+}
+
+#[test]
+fn perspective_with() {
+    let (near, far) = (0.5, 20.0);
+    let conventions = [
+        (Handedness::Right, DepthRange::ZeroToOne, -1.0, 0.0),
+        (Handedness::Right, DepthRange::MinusOneToOne, -1.0, -1.0),
+        (Handedness::Left, DepthRange::ZeroToOne, 1.0, 0.0),
+        (Handedness::Left, DepthRange::MinusOneToOne, 1.0, -1.0),
+    ];
+    for &(handedness, depth, forward, lowest) in conventions.iter() {
+        let p = Matrix::perspective_with(FRAC_PI_2, 1.5, near, far, handedness, depth);
+        let on_near = project(&p, [1.5 * near, -near, forward * near]);
+        let on_far = project(&p, [0.0, far, forward * far]);
+        assert!(on_near.approx_eq(&[[1.0], [-1.0], [lowest]].into(), 1e-12));
+        assert!(on_far.approx_eq(&[[0.0], [1.0], [1.0]].into(), 1e-12));
+    }
+    assert_eq!(
+        Matrix::perspective(1.0f32, 1.0, 0.1, 100.0),
+        Matrix::perspective_with(
+            1.0,
+            1.0,
+            0.1,
+            100.0,
+            Handedness::Right,
+            DepthRange::ZeroToOne
+        )
+    );
+}
+
+#[test]
+fn orthographic_with() {
+    let p = Matrix::orthographic_with(
+        -2.0,
+        2.0,
+        -1.0,
+        3.0,
+        1.0,
+        5.0,
+        Handedness::Left,
+        DepthRange::MinusOneToOne,
+    );
+    assert_eq!(
+        project(&p, [-2.0, 3.0, 1.0]),
+        [[-1.0], [1.0], [-1.0]].into()
+    );
+    assert_eq!(project(&p, [0.0, 1.0, 3.0]), [[0.0], [0.0], [0.0]].into());
+    let p = Matrix::orthographic(-2.0, 2.0, -1.0, 3.0, 1.0, 5.0);
+    assert_eq!(
+        project(&p, [2.0, -1.0, -1.0]),
+        [[1.0], [-1.0], [0.0]].into()
+    );
+    assert_eq!(
+        project(&p, [2.0, -1.0, -5.0]),
+        [[1.0], [-1.0], [1.0]].into()
+    );
+}
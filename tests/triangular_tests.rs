@@ -0,0 +1,67 @@
+use matrix::{Error, Matrix};
+use num::rational::Ratio;
+
+#[test]
+fn lower() {
+    let l = Matrix::<f64, 4, 4>::from_fn(|row, col| {
+        if col <= row {
+            (row + col + 1) as f64
+        } else {
+            0.0
+        }
+    });
+    let x = Matrix::<f64, 4, 2>::from_fn(|row, col| row as f64 - col as f64 * 0.5);
+    assert!(l
+        .solve_lower_triangular(&l * &x)
+        .unwrap()
+        .approx_eq(&x, 1e-12));
+    assert_eq!(
+        l.solve_lower_triangular(Matrix::from([[1.0], [2.0], [3.0], [4.0]])),
+        l.solve(Matrix::from([[1.0], [2.0], [3.0], [4.0]]))
+    );
+}
+
+#[test]
+fn upper() {
+    let r = |n: usize| Ratio::from_integer(n as i64);
+    let u = Matrix::<Ratio<i64>, 4, 4>::from_fn(|row, col| {
+        if col >= row {
+            r(row * 2 + col + 1)
+        } else {
+            r(0)
+        }
+    });
+    let x =
+        Matrix::<Ratio<i64>, 4, 3>::from_fn(|row, col| Ratio::new((row * 3 + col) as i64 - 4, 7));
+    assert_eq!(u.solve_upper_triangular(&u * &x), Ok(x));
+}
+
+#[test]
+fn other_triangle_ignored() {
+    // only the relevant triangle is read, so a packed LU factorization can be used as is
+    let lu = Matrix::from([[2.0, 1.0], [0.5, 3.0]]);
+    let b = Matrix::from([[4.0], [5.0]]);
+    let l = Matrix::from([[2.0, 0.0], [0.5, 3.0]]);
+    let u = Matrix::from([[2.0, 1.0], [0.0, 3.0]]);
+    assert_eq!(
+        lu.solve_lower_triangular(b.clone()),
+        l.solve_lower_triangular(b.clone())
+    );
+    assert_eq!(
+        lu.solve_upper_triangular(b.clone()),
+        u.solve_upper_triangular(b)
+    );
+}
+
+#[test]
+fn singular() {
+    let a = Matrix::from([[1.0, 0.0], [2.0, 0.0]]);
+    assert_eq!(
+        a.solve_lower_triangular(Matrix::from([[1.0], [1.0]])),
+        Err(Error::Singular)
+    );
+    assert_eq!(
+        a.solve_upper_triangular(Matrix::from([[1.0], [1.0]])),
+        Err(Error::Singular)
+    );
+}
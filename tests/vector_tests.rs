@@ -0,0 +1,76 @@
+use matrix::{ColVector, Error, Matrix, RowVector};
+
+#[test]
+fn dot() {
+    let u: ColVector<i32, 3> = [[1], [2], [3]].into();
+    let v: ColVector<i32, 3> = [[-1], [0], [2]].into();
+    assert_eq!(u.dot(&v), 5);
+    let u: RowVector<f64, 2> = [[0.5, 2.0]].into();
+    assert_eq!(u.dot(&u), 4.25);
+}
+
+#[test]
+fn norm() {
+    let v: ColVector<f64, 3> = [[2.0], [-3.0], [6.0]].into();
+    assert_eq!(v.norm(), 7.0);
+}
+
+#[test]
+fn index() {
+    let mut row = RowVector::from([[1, 2, 3]]);
+    let mut col = ColVector::from([[1], [2], [3]]);
+    row[(0, 0)] = 4;
+    col[0] = 4;
+    assert_eq!(row[(0, 0)] + row[(0, 2)], 7);
+    assert_eq!(col[0] + col[2], 7);
+    let mut scalar = Matrix::from([[1]]);
+    scalar[0] += 1;
+    assert_eq!(scalar[(0, 0)], 2);
+}
+
+#[test]
+#[should_panic]
+fn index_fail_bounds() {
+    let col = ColVector::from([[1], [2], [3]]);
+    let _ = col[3];
+}
+
+#[test]
+fn cross() {
+    let u: ColVector<i32, 3> = [[1], [2], [3]].into();
+    let v: ColVector<i32, 3> = [[4], [5], [6]].into();
+    let w = u.cross(&v);
+    assert_eq!(w, [[-3], [6], [-3]].into());
+    assert_eq!(w.dot(&u), 0);
+    assert_eq!(w.dot(&v), 0);
+    let u: RowVector<f64, 3> = [[1.0, 2.0, 3.0]].into();
+    let v: RowVector<f64, 3> = [[4.0, 5.0, 6.0]].into();
+    assert_eq!(v.cross(&u), [[3.0, -6.0, 3.0]].into());
+}
+
+#[test]
+fn outer() {
+    let u: ColVector<i32, 3> = [[1], [-2], [3]].into();
+    let v: RowVector<i32, 2> = [[4, 5]].into();
+    assert_eq!(u.outer(&v), [[4, 5], [-8, -10], [12, 15]].into());
+    assert_eq!(u.outer(&v), u * v);
+}
+
+#[test]
+fn normalize() {
+    let mut v: RowVector<f64, 2> = [[-5.0, 12.0]].into();
+    v.normalize().unwrap();
+    assert!(v.approx_eq(&[[-5.0 / 13.0, 12.0 / 13.0]].into(), 1e-15));
+    assert!((v.norm() - 1.0).abs() < 1e-15);
+    let mut nil: ColVector<f32, 2> = [[0.0], [0.0]].into();
+    assert_eq!(nil.normalize(), Err(Error::NilVector));
+    assert_eq!(nil, [[0.0], [0.0]].into());
+}
+
+#[test]
+fn normalize_rows() {
+    let mut m = Matrix::from([[1.0, 1.0], [0.0, 0.0], [-2.0, 0.0]]);
+    m.normalize_rows();
+    let half = 0.5f64.sqrt();
+    assert!(m.approx_eq(&[[half, half], [0.0, 0.0], [-1.0, 0.0]].into(), 1e-15));
+}
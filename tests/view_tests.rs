@@ -0,0 +1,126 @@
+use matrix::{Error, Matrix, MatrixView, MatrixViewMut};
+
+fn setup_3x4() -> Matrix<f64, 3, 4> {
+    Matrix::from([
+        [1.0, 2.0, 3.0, 4.0],
+        [5.0, -6.0, 7.0, 8.0],
+        [9.0, 10.0, 11.0, -12.0],
+    ])
+}
+
+#[test]
+fn view() {
+    let m = setup_3x4();
+    let v = m.view::<2, 3>(1, 1).unwrap();
+    assert_eq!(v.to_matrix(), m.submatrix::<2, 3>(1, 1).unwrap());
+    assert_eq!(v.get(1, 2), Some(&-12.0));
+    assert_eq!(v.get(2, 0), None);
+    assert_eq!(v.get_line(0), Some(&[-6.0, 7.0, 8.0][..]));
+    assert_eq!(v.iter().count(), 6);
+    assert_eq!(m.as_view().to_matrix(), m);
+    assert!(m.view::<0, 0>(3, 4).unwrap().iter().next().is_none());
+    assert!(matches!(m.view::<2, 2>(2, 0), Err(Error::OutOfBounds)));
+    assert!(matches!(m.view::<1, 1>(0, 4), Err(Error::OutOfBounds)));
+    assert!(matches!(
+        m.view::<1, 1>(usize::MAX, 0),
+        Err(Error::OutOfBounds)
+    ));
+    let mut m = m;
+    assert!(matches!(
+        m.view_mut::<1, 1>(0, usize::MAX),
+        Err(Error::OutOfBounds)
+    ));
+}
+
+#[test]
+fn view_norms() {
+    let m = setup_3x4();
+    let v = m.view::<2, 2>(1, 0).unwrap();
+    let block = v.to_matrix();
+    assert_eq!(v.norm_frobenius(), block.norm_frobenius());
+    assert_eq!(v.norm_one(), block.norm_one());
+    assert_eq!(v.norm_inf(), block.norm_inf());
+    assert_eq!(v.norm_max(), block.norm_max());
+
+    let nan = Matrix::from([[1.0, 2.0], [3.0, f64::NAN]]);
+    let v = nan.as_view();
+    assert!(v.norm_one().is_nan());
+    assert!(v.norm_inf().is_nan());
+    assert!(v.norm_max().is_nan());
+}
+
+#[test]
+fn view_mul() {
+    let m = setup_3x4();
+    let left = m.view::<2, 3>(0, 0).unwrap();
+    let right = m.view::<3, 2>(0, 2).unwrap();
+    assert_eq!(left * right, &left.to_matrix() * &right.to_matrix());
+}
+
+#[test]
+fn view_mut() {
+    let mut m = setup_3x4();
+    let other = Matrix::from([[1.0, 1.0], [2.0, 2.0]]);
+    {
+        let mut v = m.view_mut::<2, 2>(0, 2).unwrap();
+        v += other.as_view();
+        v[(0, 0)] = 0.0;
+        *v.get_mut(1, 1).unwrap() *= 2.0;
+        v.get_mut_line(1).unwrap()[0] = -1.0;
+        assert_eq!(v.get_mut(2, 0), None);
+    }
+    assert_eq!(
+        m,
+        [
+            [1.0, 2.0, 0.0, 5.0],
+            [5.0, -6.0, -1.0, 20.0],
+            [9.0, 10.0, 11.0, -12.0]
+        ]
+        .into()
+    );
+    let mut sum = other.clone();
+    sum += m.view::<2, 2>(1, 0).unwrap();
+    assert_eq!(sum, [[6.0, -5.0], [11.0, 12.0]].into());
+    m.view_mut::<1, 2>(2, 1)
+        .unwrap()
+        .copy_from(Matrix::from([[0.0, 0.5]]).as_view());
+    assert_eq!(m.get_line(2), Some(&[9.0, 0.0, 0.5, -12.0]));
+}
+
+#[test]
+fn from_slice() {
+    let buffer: Vec<u8> = (0..12).collect();
+    let v = MatrixView::<u8, 3, 2>::from_slice(&buffer[1..], 4).unwrap();
+    assert_eq!(v.to_matrix(), [[1, 2], [5, 6], [9, 10]].into());
+    // overlapping rows make sliding windows
+    let windows = MatrixView::<u8, 3, 4>::from_slice(&buffer, 1).unwrap();
+    assert_eq!(windows.get_line(2), Some(&[2, 3, 4, 5][..]));
+    assert!(matches!(
+        MatrixView::<u8, 3, 2>::from_slice(&buffer[3..], 4),
+        Err(Error::WrongLength)
+    ));
+    assert!(MatrixView::<u8, 0, 2>::from_slice(&[], 4).is_ok());
+    // the span of the rows overflows
+    assert!(matches!(
+        MatrixView::<i32, 3, 1>::from_slice(&[1, 2, 3], usize::MAX / 2 + 1),
+        Err(Error::WrongLength)
+    ));
+}
+
+#[test]
+fn from_slice_mut() {
+    let mut buffer = [0u8; 9];
+    {
+        let mut v = MatrixViewMut::<u8, 3, 1>::from_slice_mut(&mut buffer, 4).unwrap();
+        v.copy_from(Matrix::from([[1], [1], [1]]).as_view());
+    }
+    assert_eq!(buffer, [1, 0, 0, 0, 1, 0, 0, 0, 1]);
+    assert!(matches!(
+        MatrixViewMut::<u8, 3, 1>::from_slice_mut(&mut buffer[1..], 4),
+        Err(Error::WrongLength)
+    ));
+    assert!(matches!(
+        MatrixViewMut::<u8, 2, 2>::from_slice_mut(&mut buffer, usize::MAX),
+        Err(Error::WrongLength)
+    ));
+}